@@ -0,0 +1,297 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+//! # **`cursor-derive`**
+//!
+//! `#[derive(Extras)]` for the [`cursor`](https://docs.rs/cursor) crate,
+//! re-exported by it with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, ToTokens};
+use syn::{
+    meta::ParseNestedMeta, parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data,
+    DeriveInput, Error, Fields, Lit, Member, Path, Result, Token, Type,
+};
+
+/// implements `Extras<u8>` and `Extras<char>` out of `Default` and `Clone`,
+/// * `new` is `Default::default()`, `clone` is `Clone::clone`, `reset` sets it back to `Default::default()`,
+/// * `change` calls every handler, the fields' in order, then the type's.
+///
+/// on the type:
+/// * `#[extras(on_change = "Self::update")]` : *`fn(&mut Self, &Input, usize)`.*
+/// * `#[extras(stepwise)]` : *sets [`STEPWISE`](https://docs.rs/cursor/*/cursor/trait.Extras.html#associatedconstant.STEPWISE).*
+/// * `#[extras(crate = "my::cursor")]` : *where the `cursor` crate is when it's renamed or re-exported, `::cursor` by default.*
+///
+/// on a field:
+/// * `#[extras(on_change = "count")]` : *`fn(&mut Field, &Input, usize)`.*
+/// * `#[extras(nested)]` : *the field is an `Extras` itself, its `STEPWISE` counts as well.*
+///
+/// `input = "u8"` or `input = "char"` next to a handler keeps it to that input,
+/// once one handler has an `input`, only the inputs named are implemented.
+///
+/// ```
+/// use cursor::*;
+///
+/// #[derive(Debug, Default, Clone, Extras)]
+/// #[extras(input = "u8", on_change = "Self::even")]
+/// #[extras(input = "char", on_change = "Self::space")]
+/// struct Counter(usize);
+///
+/// impl Counter {
+///     fn even(&mut self, input: &u8, _pos: usize) {
+///         self.0 += input.is_multiple_of(2) as usize;
+///     }
+///     fn space(&mut self, input: &char, _pos: usize) {
+///         self.0 += (*input == ' ') as usize;
+///     }
+/// }
+///
+/// let mut cursor = Cursor::new_with_extras::<Counter>(&[1, 2, 3, 4, 5, 6]);
+/// cursor.next_to_last();
+/// assert_eq!(cursor.into_extras().0, 3);
+///
+/// let mut cursor = StrCursor::new_with_extras::<Counter>("a b c");
+/// cursor.next_to_last();
+/// assert_eq!(cursor.into_extras().0, 2);
+/// ```
+#[proc_macro_derive(Extras, attributes(extras))]
+pub fn derive_extras(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Input {
+    U8,
+    Char,
+}
+
+impl Input {
+    const ALL: [Input; 2] = [Input::U8, Input::Char];
+
+    fn parse(lit: &Lit) -> Result<Self> {
+        match lit {
+            Lit::Str(s) if s.value() == "u8" => Ok(Input::U8),
+            Lit::Str(s) if s.value() == "char" => Ok(Input::Char),
+            _ => Err(Error::new(lit.span(), "expected `\"u8\"` or `\"char\"`")),
+        }
+    }
+}
+
+impl ToTokens for Input {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            Input::U8 => quote!(u8),
+            Input::Char => quote!(char),
+        }
+        .to_tokens(tokens)
+    }
+}
+
+enum Call {
+    Path(Path),
+    /// the field's type.
+    Nested(Type),
+}
+
+struct Handler {
+    input: Option<Input>,
+    /// `None` for the type itself.
+    field: Option<Member>,
+    call: Call,
+}
+
+impl Handler {
+    fn applies_to(&self, input: Input) -> bool {
+        self.input.is_none_or(|only| only == input)
+    }
+    fn to_call(&self, input: Input, krate: &Path) -> TokenStream2 {
+        let target = match &self.field {
+            Some(member) => quote!(&mut self.#member),
+            None => quote!(self),
+        };
+        match &self.call {
+            Call::Path(path) => quote!(#path(#target, input, pos);),
+            Call::Nested(_) => quote!(#krate::Extras::<#input>::change(#target, input, pos);),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Attrs {
+    handlers: Vec<Handler>,
+    stepwise: bool,
+    /// `crate = ".."`.
+    krate: Option<Path>,
+}
+
+impl Attrs {
+    /// every `#[extras(..)]` of the type or of a field (`Some(field)`).
+    fn parse(&mut self, attrs: &[Attribute], field: Option<(Member, &Type)>) -> Result<()> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("extras")) {
+            let mut input = None;
+            let mut call = None;
+            attr.parse_nested_meta(|meta| {
+                let name = meta.path.get_ident().map(ToString::to_string);
+                let has_value = meta.input.peek(Token![=]);
+                match (name.as_deref(), has_value, &field) {
+                    (Some("input"), true, _) if input.is_none() => {
+                        input = Some(Input::parse(&meta.value()?.parse()?)?);
+                    }
+                    (Some("on_change"), true, _) if call.is_none() => {
+                        call = Some(Call::Path(parse_path(&meta)?));
+                    }
+                    (Some("nested"), false, Some((_, ty))) if call.is_none() => {
+                        call = Some(Call::Nested((*ty).clone()));
+                    }
+                    (Some("stepwise"), false, None) => self.stepwise = true,
+                    (Some("crate"), true, None) if self.krate.is_none() => {
+                        self.krate = Some(parse_path(&meta)?);
+                    }
+                    (Some("input" | "on_change" | "nested"), _, _)
+                        if call.is_some() || input.is_some() =>
+                    {
+                        return Err(meta.error("one handler per `#[extras(..)]`"));
+                    }
+                    _ => return Err(meta.error("unknown `extras` attribute")),
+                }
+                Ok(())
+            })?;
+            match call {
+                Some(call) => self.handlers.push(Handler {
+                    input,
+                    field: field.as_ref().map(|(member, _)| member.clone()),
+                    call,
+                }),
+                None if input.is_some() => {
+                    return Err(Error::new(
+                        attr.path().span(),
+                        "`input` goes with `on_change` or `nested`",
+                    ));
+                }
+                None => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// the named inputs, every one if none is.
+    fn inputs(&self) -> Vec<Input> {
+        let named: Vec<_> = Input::ALL
+            .into_iter()
+            .filter(|&input| self.handlers.iter().any(|h| h.input == Some(input)))
+            .collect();
+        if named.is_empty() {
+            Input::ALL.to_vec()
+        } else {
+            named
+        }
+    }
+}
+
+/// `name = "a::path"`.
+fn parse_path(meta: &ParseNestedMeta) -> Result<Path> {
+    match meta.value()?.parse()? {
+        Lit::Str(s) => s.parse(),
+        lit => Err(Error::new(lit.span(), "expected a path in a string")),
+    }
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let mut attrs = Attrs::default();
+    match &input.data {
+        Data::Struct(data) => {
+            for (i, field) in data.fields.iter().enumerate() {
+                let member = match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(i.into()),
+                };
+                attrs.parse(&field.attrs, Some((member, &field.ty)))?;
+            }
+        }
+        Data::Enum(data) => {
+            let fields = data
+                .variants
+                .iter()
+                .flat_map(|variant| match &variant.fields {
+                    Fields::Named(fields) => fields.named.iter().collect(),
+                    Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+                    Fields::Unit => vec![],
+                });
+            for field in fields {
+                if let Some(attr) = field
+                    .attrs
+                    .iter()
+                    .find(|attr| attr.path().is_ident("extras"))
+                {
+                    return Err(Error::new(attr.span(), "field handlers are for structs"));
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`Extras` cannot be derived for unions",
+            ));
+        }
+    }
+    // the type's own handler runs last, it sees the fields updated.
+    attrs.parse(&input.attrs, None)?;
+
+    if attrs.handlers.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "expected `#[extras(on_change = \"..\")]` on the type or on a field",
+        ));
+    }
+
+    let krate = attrs
+        .krate
+        .clone()
+        .unwrap_or_else(|| parse_quote!(::cursor));
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(Self: ::core::default::Default + ::core::clone::Clone));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let impls = attrs.inputs().into_iter().map(|input| {
+        let handlers = attrs.handlers.iter().filter(|h| h.applies_to(input));
+        let calls = handlers.clone().map(|h| h.to_call(input, &krate));
+        let stepwise = if attrs.stepwise {
+            quote!(true)
+        } else {
+            let nested = handlers.filter_map(|h| match &h.call {
+                Call::Nested(ty) => Some(quote!(|| <#ty as #krate::Extras<#input>>::STEPWISE)),
+                Call::Path(_) => None,
+            });
+            quote!(false #(#nested)*)
+        };
+        quote! {
+            impl #impl_generics #krate::Extras<#input> for #name #ty_generics #where_clause {
+                const STEPWISE: bool = #stepwise;
+
+                #[inline]
+                fn new() -> Self {
+                    ::core::default::Default::default()
+                }
+                #[inline]
+                fn clone(&self) -> Self {
+                    ::core::clone::Clone::clone(self)
+                }
+                #[inline]
+                fn change(&mut self, input: &#input, pos: usize) {
+                    #(#calls)*
+                }
+                #[inline]
+                fn reset(&mut self) {
+                    *self = ::core::default::Default::default();
+                }
+            }
+        }
+    });
+    Ok(quote!(#(#impls)*))
+}
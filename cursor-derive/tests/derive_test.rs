@@ -0,0 +1,160 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+fn stepwise<T, E: Extras<T>>() -> bool {
+    E::STEPWISE
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Extras)]
+#[extras(input = "u8", on_change = "Self::even")]
+#[extras(input = "char", on_change = "Self::space")]
+struct Counter(usize);
+
+impl Counter {
+    fn even(&mut self, input: &u8, _pos: usize) {
+        if input.is_multiple_of(2) {
+            self.0 += 1;
+        }
+    }
+    fn space(&mut self, input: &char, _pos: usize) {
+        if *input == ' ' {
+            self.0 += 1;
+        }
+    }
+}
+
+/// both inputs through one generic handler.
+#[derive(Debug, Default, Clone, Extras)]
+#[extras(on_change = "Self::last")]
+struct Last {
+    pos: Option<usize>,
+}
+
+impl Last {
+    fn last<T>(&mut self, _: &T, pos: usize) {
+        self.pos = Some(pos);
+    }
+}
+
+fn count<T>(n: &mut usize, _: &T, _: usize) {
+    *n += 1;
+}
+fn deepen(depth: &mut isize, input: &char, _: usize) {
+    match input {
+        '(' => *depth += 1,
+        ')' => *depth -= 1,
+        _ => {}
+    }
+}
+
+#[derive(Debug, Default, Clone, Extras)]
+#[extras(input = "char", on_change = "Self::deepest")]
+struct Parens {
+    #[extras(on_change = "count")]
+    seen: usize,
+    #[extras(on_change = "deepen")]
+    depth: isize,
+    max: isize,
+    #[extras(nested)]
+    line_col: LineColExtras,
+}
+
+impl Parens {
+    /// after the fields.
+    fn deepest(&mut self, _: &char, _: usize) {
+        self.max = self.max.max(self.depth);
+    }
+}
+
+#[derive(Debug, Default, Clone, Extras)]
+#[extras(stepwise, on_change = "Self::push")]
+struct Trail<T: From<char>>(Vec<T>);
+
+impl<T: From<char>> Trail<T> {
+    fn push<I: Copy + Into<char>>(&mut self, input: &I, _: usize) {
+        self.0.push(T::from((*input).into()));
+    }
+}
+
+#[test]
+fn it_works() {
+    let mut cursor = Cursor::new_with_extras::<Counter>(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    cursor.next_to_last();
+    assert_eq!(cursor.into_extras(), Counter(5));
+
+    let mut cursor = StrCursor::new_with_extras::<Counter>("this is test. 안녕하세요. ^^ thanks.");
+    cursor.next_to_last();
+    assert_eq!(cursor.to_extras(), Counter(5));
+    cursor.reset();
+    assert_eq!(cursor.extras(), &Counter(0));
+
+    assert!(!stepwise::<u8, Counter>());
+    assert!(!stepwise::<char, Last>());
+
+    let mut cursor = Cursor::new_with_extras::<Last>(b"abc");
+    cursor.jump(2);
+    assert_eq!(cursor.extras().pos, Some(2));
+    let mut cursor = StrCursor::new_with_extras::<Last>("abc");
+    cursor.next();
+    cursor.save();
+    cursor.next();
+    let range = cursor.to_range_extras().map_extras(|last| last.pos);
+    assert_eq!(range, Some(0)..Some(1));
+}
+
+#[test]
+fn field_works() {
+    assert!(stepwise::<char, Parens>());
+
+    let mut cursor = StrCursor::new_with_extras::<Parens>("f(g(x),\n(y))");
+    cursor.jump(9);
+    let extras = cursor.extras();
+    assert_eq!(extras.seen, 10);
+    assert_eq!((extras.depth, extras.max), (2, 2));
+    assert_eq!(extras.line_col.line_col(), (1, 1));
+
+    cursor.next_to_last();
+    assert_eq!((cursor.extras().depth, cursor.extras().seen), (0, 12));
+    cursor.reset();
+    assert_eq!(cursor.extras().line_col.line_col(), (0, 0));
+}
+
+#[test]
+fn stepwise_works() {
+    assert!(stepwise::<u8, Trail<char>>());
+    assert!(stepwise::<char, Trail<char>>());
+
+    let mut cursor = StrCursor::new_with_extras::<Trail<char>>("abcd");
+    cursor.jump(3);
+    assert_eq!(cursor.extras().0, ['a', 'b', 'c', 'd']);
+
+    let mut cursor = Cursor::new_with_extras::<Trail<String>>(b"abcd");
+    cursor.jump(2);
+    cursor.turnaround();
+    cursor.jump(0);
+    assert_eq!(cursor.into_extras().0.concat(), "abcba");
+}
+
+mod reexported {
+    pub use cursor as inner;
+}
+
+#[derive(Debug, Default, Clone, Extras)]
+#[extras(crate = "reexported::inner")]
+struct Renamed {
+    #[extras(input = "char", on_change = "count")]
+    seen: usize,
+    #[extras(nested)]
+    line_col: LineColExtras,
+}
+
+#[test]
+fn crate_works() {
+    assert!(stepwise::<char, Renamed>());
+
+    let mut cursor = StrCursor::new_with_extras::<Renamed>("a\nb");
+    cursor.next_to_last();
+    assert_eq!(cursor.extras().seen, 3);
+    assert_eq!(cursor.extras().line_col.line_col(), (1, 0));
+}
@@ -31,7 +31,7 @@ impl Extras<u8> for Counter {
         self._reset()
     }
     fn change(&mut self, input: &u8, _pos: usize) {
-        if input % 2 == 0 {
+        if input.is_multiple_of(2) {
             self.0 += 1;
         }
     }
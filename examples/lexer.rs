@@ -90,11 +90,12 @@ derive_debug_partials! {
 
 }
 
-impl From<Range<LexerExtras>> for Span {
-    fn from(v: Range<LexerExtras>) -> Self {
+impl From<Range<LineColExtras>> for Span {
+    fn from(v: Range<LineColExtras>) -> Self {
+        let offset = |e: &LineColExtras| Offset::new(e.byte_offset(), e.line(), e.column());
         Span {
-            start: v.start.offset,
-            end: v.end.offset,
+            start: offset(&v.start),
+            end: offset(&v.end),
         }
     }
 }
@@ -111,8 +112,8 @@ impl<'s> fmt::Debug for SourceChunk<'s> {
     }
 }
 
-impl<'s> From<&StrCursor<'s, LexerExtras>> for SourceChunk<'s> {
-    fn from(cursor: &StrCursor<'s, LexerExtras>) -> Self {
+impl<'s> From<&StrCursor<'s, LineColExtras>> for SourceChunk<'s> {
+    fn from(cursor: &StrCursor<'s, LineColExtras>) -> Self {
         SourceChunk {
             source: cursor.as_str(),
             span: Span::from(cursor.to_range_extras()),
@@ -136,55 +137,6 @@ struct Token<'s> {
 
 type Tokens<'s> = Vec<Token<'s>>;
 
-// cursor
-
-#[derive(Default, Debug)]
-struct LexerExtras {
-    prev_offset: Offset,
-    offset: Offset,
-}
-
-impl LexerExtras {
-    pub fn load(&mut self) {
-        self.offset = self.prev_offset;
-    }
-    pub fn save(&mut self) {
-        self.prev_offset = self.offset;
-    }
-}
-
-impl Extras<char> for LexerExtras {
-    fn new() -> Self {
-        LexerExtras::default()
-    }
-    fn clone(&self) -> Self {
-        LexerExtras {
-            prev_offset: self.prev_offset,
-            offset: self.offset,
-        }
-    }
-    fn reset(&mut self) {
-        let def = Offset::default();
-        self.prev_offset = def;
-        self.offset = def;
-    }
-    fn change(&mut self, input: &char, pos: usize) {
-        if pos < self.offset.pos {
-            self.load(); // == undo
-        } else {
-            self.save();
-            self.offset.pos = pos;
-            match *input {
-                '\n' => {
-                    self.offset.line += 1;
-                    self.offset.column = 0;
-                }
-                _ => self.offset.column += 1,
-            }
-        }
-    }
-}
-
 fn main() {
     example1();
     println!();
@@ -192,7 +144,7 @@ fn main() {
 
 #[inline]
 fn example1() {
-    let mut cursor = StrCursor::new_with_extras::<LexerExtras>(
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(
         r#"
             print 2 + 1;
             print "one";
@@ -222,7 +174,7 @@ fn example1() {
                 cursor.save();
                 cursor.next_to_until(|c| c == '"');
                 let s = cursor.as_str_loaded();
-                let literal = (&s[1..s.len().saturating_sub(1)]).to_string();
+                let literal = s[1..s.len().saturating_sub(1)].to_string();
                 tokens.push(Token::new(
                     TokenKind::String,
                     SourceChunk::from(&cursor),
@@ -232,7 +184,7 @@ fn example1() {
             '0'..='9' => {
                 // numbers
                 cursor.save();
-                cursor.next_to_while(|c| c.is_digit(10));
+                cursor.next_to_while(|c| c.is_ascii_digit());
                 cursor.prev();
                 let literal = i32::from_str(cursor.as_str_loaded()).unwrap();
                 tokens.push(Token::new(
@@ -0,0 +1,231 @@
+#!/usr/bin/env python3
+"""regenerates the segmentation tables from the Unicode Character Database.
+
+    python3 scripts/unicode.py [--ucd DIR]
+
+* writes `src/cmn/{grapheme,words,sentences}/tables.rs`,
+* writes `src/diagnostics/tables.rs`, the display widths,
+* copies the UAX #29 break tests to `tests/data/` for the conformance tests,
+  run them with `cargo test --test conformance_test -- --ignored` (CI does),
+* the UCD files are downloaded into DIR (`target/ucd` by default)
+  unless they are there already.
+"""
+
+import argparse
+import os
+import shutil
+import urllib.request
+
+UNICODE_VERSION = "17.0.0"
+UCD_URL = "https://www.unicode.org/Public/%s/ucd/" % UNICODE_VERSION
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+
+BREAK_TESTS = [
+    "auxiliary/GraphemeBreakTest.txt",
+    "auxiliary/WordBreakTest.txt",
+    "auxiliary/SentenceBreakTest.txt",
+]
+
+HEADER = """// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+%s
+
+use super::Cat::{self, *};
+
+pub(super) const CATS: &[(u32, u32, Cat)] = &[
+"""
+
+WIDTHS_HEADER = """// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+%s
+
+"""
+
+
+def fetch(ucd, name):
+    path = os.path.join(ucd, name)
+    if not os.path.exists(path):
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        print("downloading", name)
+        urllib.request.urlretrieve(UCD_URL + name, path)
+    return path
+
+
+def properties(ucd, name):
+    """(code points, fields after the first) of every line."""
+    with open(fetch(ucd, name), encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            lo, _, hi = fields[0].partition("..")
+            lo = int(lo, 16)
+            hi = int(hi, 16) if hi else lo
+            yield range(lo, hi + 1), fields[1:]
+
+
+def values(ucd, name, prop=None):
+    """code point -> value of a property, `prop` picks one out of a multi-property file."""
+    table = {}
+    for codes, fields in properties(ucd, name):
+        if prop is not None:
+            if fields[0] != prop:
+                continue
+            fields = fields[1:]
+        value = fields[0] if fields else True
+        for code in codes:
+            table[code] = value
+    return table
+
+
+def merge(cats):
+    """[lo, hi, cat] runs of consecutive code points with the same cat."""
+    ranges = []
+    for code in sorted(cats):
+        cat = cats[code]
+        if ranges and ranges[-1][1] == code - 1 and ranges[-1][2] == cat:
+            ranges[-1][1] = code
+        else:
+            ranges.append([code, code, cat])
+    return ranges
+
+
+def module_doc(doc):
+    version = ".".join(UNICODE_VERSION.split(".")[:2])
+    doc = "generated from the Unicode %s %s" % (version, doc)
+    return "\n".join("//! " + line for line in doc.split("\n"))
+
+
+def write(path, doc, cats):
+    ranges = merge(cats)
+    with open(os.path.join(ROOT, path), "w", encoding="utf-8") as f:
+        f.write(HEADER % module_doc(doc))
+        for lo, hi, cat in ranges:
+            f.write("    (0x%X, 0x%X, %s),\n" % (lo, hi, cat))
+        f.write("];\n")
+    print(path, len(ranges), "ranges")
+
+
+def rename(value):
+    """`Regional_Indicator` -> `RegionalIndicator`, `CR` -> `Cr`, ..."""
+    words = value.split("_")
+    return "".join(w.capitalize() if w.isupper() and len(w) > 1 else w for w in words)
+
+
+def grapheme(ucd):
+    gcb = values(ucd, "auxiliary/GraphemeBreakProperty.txt")
+    incb = values(ucd, "DerivedCoreProperties.txt", "InCB")
+    pictographic = values(ucd, "emoji/emoji-data.txt", "Extended_Pictographic")
+
+    cats = {}
+    for code, value in gcb.items():
+        # the Hangul syllables are computed.
+        if value in ("LV", "LVT"):
+            continue
+        if value == "Extend":
+            cats[code] = {"Linker": "Linker", "Extend": "ExtendInCB"}.get(incb.get(code), "Extend")
+        else:
+            cats[code] = rename(value)
+    for code, value in incb.items():
+        if value == "Consonant":
+            cats[code] = "Consonant"
+    for code in pictographic:
+        cats[code] = "ExtPict"
+    write(
+        "src/cmn/grapheme/tables.rs",
+        "`Grapheme_Cluster_Break`, `Indic_Conjunct_Break`\n"
+        "and `Extended_Pictographic` properties, the Hangul syllables are computed instead.",
+        cats,
+    )
+
+
+def words(ucd):
+    wb = values(ucd, "auxiliary/WordBreakProperty.txt")
+    cats = {code: rename(value) for code, value in wb.items()}
+    write("src/cmn/words/tables.rs", "`Word_Break` property.", cats)
+
+
+def sentences(ucd):
+    sb = values(ucd, "auxiliary/SentenceBreakProperty.txt")
+    cats = {code: rename(value) for code, value in sb.items()}
+    write("src/cmn/sentences/tables.rs", "`Sentence_Break` property.", cats)
+
+
+def widths(ucd):
+    """the widths `unicode-width` gives a lone char outside an East Asian context."""
+    eaw = values(ucd, "EastAsianWidth.txt")
+    hst = values(ucd, "HangulSyllableType.txt")
+    gcb = values(ucd, "auxiliary/GraphemeBreakProperty.txt")
+    concat = values(ucd, "PropList.txt", "Prepended_Concatenation_Mark")
+
+    cats = {}
+    for code, value in eaw.items():
+        if value in ("W", "F"):
+            cats[code] = "WIDE"
+    for code in values(ucd, "emoji/emoji-data.txt", "Emoji_Presentation"):
+        cats[code] = "WIDE"
+    # KHMER INDEPENDENT VOWEL QAA
+    cats[0x17A4] = "WIDE"
+
+    zero = set(values(ucd, "DerivedCoreProperties.txt", "Default_Ignorable_Code_Point"))
+    zero.update(values(ucd, "DerivedCoreProperties.txt", "Grapheme_Extend"))
+    # canonically decompose to two `Grapheme_Extend` chars but aren't.
+    zero.update([0x0CC0, 0x0CC7, 0x0CC8, 0x0CCA, 0x0CCB, 0x1B3B, 0x1B3D, 0x1B43])
+    # vowel and trailing jamo join the leading one, which takes the 2 columns.
+    zero.update(code for code, value in hst.items() if value in ("V", "T"))
+    # `Prepend` chars that are not shown on their own,
+    # of the `Prepended_Concatenation_Mark`s only the Syriac and a few Arabic ones.
+    zero.update(code for code, value in gcb.items() if value == "Prepend" and code not in concat)
+    zero.update([0x070F, 0x0605, 0x0890, 0x0891, 0x08E2])
+    # DEVANAGARI CARET
+    zero.add(0xA8FA)
+    # HANGUL CHOSEONG FILLER takes the 2 columns of the syllable it starts,
+    # TIFINAGH CONSONANT JOINER is only invisible between two consonants.
+    zero -= {0x115F, 0x2D7F}
+    for code in zero:
+        cats[code] = "ZERO_WIDTH"
+
+    path = "src/diagnostics/tables.rs"
+    with open(os.path.join(ROOT, path), "w", encoding="utf-8") as f:
+        doc = (
+            "`East_Asian_Width`, `Emoji_Presentation`, `Default_Ignorable_Code_Point`,\n"
+            "`Grapheme_Extend` and `Hangul_Syllable_Type` properties, the rules of `unicode-width`."
+        )
+        f.write(WIDTHS_HEADER % module_doc(doc))
+        for name, doc in [
+            ("ZERO_WIDTH", "chars that take no columns."),
+            ("WIDE", "chars that take 2 columns."),
+        ]:
+            ranges = [(lo, hi) for lo, hi, cat in merge(cats) if cat == name]
+            f.write("/// %s\npub(super) const %s: &[(u32, u32)] = &[\n" % (doc, name))
+            for lo, hi in ranges:
+                f.write("    (0x%X, 0x%X),\n" % (lo, hi))
+            f.write("];\n")
+            if name == "ZERO_WIDTH":
+                f.write("\n")
+            print(path, name, len(ranges), "ranges")
+
+
+def break_tests(ucd):
+    data = os.path.join(ROOT, "tests", "data")
+    os.makedirs(data, exist_ok=True)
+    for name in BREAK_TESTS:
+        shutil.copy(fetch(ucd, name), data)
+        print("tests/data/" + os.path.basename(name))
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n", 1)[0])
+    parser.add_argument("--ucd", default=os.path.join(ROOT, "target", "ucd"))
+    args = parser.parse_args()
+    grapheme(args.ucd)
+    words(args.ucd)
+    sentences(args.ucd)
+    widths(args.ucd)
+    break_tests(args.ucd)
+
+
+if __name__ == "__main__":
+    main()
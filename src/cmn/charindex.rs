@@ -0,0 +1,170 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+use alloc::{sync::Arc, vec::Vec};
+
+/// byte offset of every `stride`th char of a `&str`,
+/// so a char is found in O(stride) from the nearest sample, not from the cursor.
+/// * an ascii source keeps no samples, chars and bytes coincide,
+/// * [`StrCursor::char_index`](StrCursor::char_index) builds one for `jump`,
+/// * over a [`Text`](Text) the offsets are in units, see [`TextCursor::char_index`](TextCursor::char_index),
+/// * the clones share the samples.
+///
+/// ```
+/// use cursor::*;
+///
+/// let index = CharIndex::with_stride("안녕 하세요", 2);
+/// assert_eq!(index.len(), 6);
+/// assert_eq!(index.byte_pos(3), Some(7));
+/// assert_eq!(index.char_pos(10), Some(4));
+/// assert_eq!(index.byte_pos(6), None);
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct CharIndex<'s, H: Haystack + ?Sized = str> {
+    source: &'s H,
+    stride: usize,
+    /// byte of the char `i * stride`.
+    samples: Arc<[usize]>,
+    len: usize,
+}
+impl<H: Haystack + ?Sized> Clone for CharIndex<'_, H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        CharIndex {
+            source: self.source,
+            stride: self.stride,
+            samples: self.samples.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<'s> CharIndex<'s, str> {
+    pub const DEFAULT_STRIDE: usize = 64;
+
+    /// the samples alone, kept by [`OwnedStrCursor`](OwnedStrCursor) between lends.
+    #[inline]
+    pub(crate) fn detach(self) -> CharIndex<'static> {
+        CharIndex {
+            source: "",
+            stride: self.stride,
+            samples: self.samples,
+            len: self.len,
+        }
+    }
+    /// `source` has to be the one it was built from.
+    #[inline]
+    pub(crate) fn attach(self, source: &str) -> CharIndex<'_> {
+        CharIndex {
+            source,
+            stride: self.stride,
+            samples: self.samples,
+            len: self.len,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &'s str {
+        self.source
+    }
+}
+
+impl<'s, H: Haystack + ?Sized> CharIndex<'s, H> {
+    #[inline]
+    pub fn new(source: &'s H) -> Self {
+        CharIndex::with_stride(source, CharIndex::DEFAULT_STRIDE)
+    }
+    /// a sample every `stride` chars, `1` at least.
+    pub fn with_stride(source: &'s H, stride: usize) -> Self {
+        let stride = stride.max(1);
+        let units = source.as_units().len();
+        let mut samples = Vec::with_capacity(units / stride + 1);
+        let mut at = Some(0).filter(|_| units > 0);
+        while let Some(sample) = at {
+            samples.push(sample);
+            at = source
+                .slice(sample..units)
+                .nth_char_start(stride)
+                .map(|i| sample + i);
+        }
+        let last = samples
+            .last()
+            .map_or(0, |&sample| source.slice(sample..units).count_chars());
+        let len = samples.len().saturating_sub(1) * stride + last;
+        if len == units {
+            samples.clear();
+        }
+        CharIndex {
+            source,
+            stride,
+            samples: samples.into(),
+            len,
+        }
+    }
+
+    #[inline]
+    pub fn as_text(&self) -> &'s H {
+        self.source
+    }
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+    /// number of chars in the source.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// if every char is one unit, an ascii `str`.
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        self.len == self.source.as_units().len()
+    }
+
+    /// first byte of the char `pos`, `None` past the last char.
+    #[inline]
+    pub fn byte_pos(&self, pos: usize) -> Option<usize> {
+        if pos >= self.len {
+            return None;
+        }
+        if self.is_ascii() {
+            return Some(pos);
+        }
+        let sample = self.samples[pos / self.stride];
+        let units = self.source.as_units().len();
+        let skip = self
+            .source
+            .slice(sample..units)
+            .nth_char_start(pos % self.stride)?;
+        Some(sample + skip)
+    }
+    /// the char containing the byte `byte_pos`, the end of the source included.
+    #[inline]
+    pub fn char_pos(&self, byte_pos: usize) -> Option<usize> {
+        let units = self.source.as_units().len();
+        if byte_pos > units {
+            return None;
+        }
+        if self.is_ascii() {
+            return Some(byte_pos);
+        }
+        if byte_pos == units {
+            return Some(self.len);
+        }
+        let i = self.samples.partition_point(|&sample| sample <= byte_pos) - 1;
+        let (mut at, mut pos) = (self.samples[i], i * self.stride);
+        while let Some((_, next)) = self
+            .source
+            .char_at(at)
+            .filter(|&(_, next)| next <= byte_pos)
+        {
+            at = next;
+            pos += 1;
+        }
+        Some(pos)
+    }
+}
@@ -2,21 +2,25 @@
 
 #[cfg(feature = "std")]
 pub use std::{
+    array,
     cmp::Ordering,
+    collections::VecDeque,
     fmt,
     marker::PhantomData,
     mem,
-    ops::{Add, AddAssign, Range, Sub, SubAssign},
+    ops::{Add, AddAssign, Deref, DerefMut, Range, RangeInclusive, Sub, SubAssign},
     str,
+    sync::Arc,
 };
 
 #[cfg(not(feature = "std"))]
 pub use core::{
+    array,
     cmp::Ordering,
     fmt,
     marker::PhantomData,
     mem,
-    ops::{Add, AddAssign, Range, Sub, SubAssign},
+    ops::{Add, AddAssign, Deref, DerefMut, Range, RangeInclusive, Sub, SubAssign},
     str,
 };
 
@@ -0,0 +1,453 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! decoding one char at a time over code units, either way.
+//!
+//! every decoder is lossy, what can't be decoded is one U+FFFD,
+//! and a char is split the same way from the left and from the right.
+
+use super::*;
+
+/// how chars are laid in a slice of `Unit`s.
+pub trait Decoder {
+    type Unit: Copy + PartialEq + fmt::Debug + 'static;
+
+    /// (the char starting at `at`, its number of units), `None` at the end.
+    fn decode(units: &[Self::Unit], at: usize) -> Option<(char, usize)>;
+    /// (the char ending right before `end`, its number of units), `None` at the start.
+    fn decode_back(units: &[Self::Unit], end: usize) -> Option<(char, usize)>;
+
+    /// number of chars in `units`.
+    #[inline]
+    fn count_chars(units: &[Self::Unit]) -> usize {
+        let (mut at, mut n) = (0, 0);
+        while let Some((_, width)) = Self::decode(units, at) {
+            at += width;
+            n += 1;
+        }
+        n
+    }
+    /// the chars of `units`, in both directions.
+    #[inline]
+    fn chars(units: &[Self::Unit]) -> Chars<'_, Self>
+    where
+        Self: Sized,
+    {
+        Chars {
+            units,
+            front: 0,
+            back: units.len(),
+        }
+    }
+
+    /// decodes from the current unit of the `cursor`, the first one of a char,
+    /// and leaves it on the last one.
+    #[inline]
+    fn next_char<'a, C>(cursor: &mut C) -> Option<char>
+    where
+        Self::Unit: 'a,
+        C: CursorTrait<'a, Self::Unit>,
+    {
+        let (ch, width) = Self::decode(cursor.as_slice(), cursor.pos())?;
+        if width > 1 {
+            cursor.jump(cursor.pos() + width - 1)?;
+        }
+        Some(ch)
+    }
+    /// decodes from the current unit of the `cursor`, the last one of a char,
+    /// and leaves it on the first one.
+    #[inline]
+    fn next_back_char<'a, C>(cursor: &mut C) -> Option<char>
+    where
+        Self::Unit: 'a,
+        C: CursorTrait<'a, Self::Unit>,
+    {
+        let (ch, width) = Self::decode_back(cursor.as_slice(), cursor.pos() + 1)?;
+        if width > 1 {
+            cursor.jump(cursor.pos() + 1 - width)?;
+        }
+        Some(ch)
+    }
+}
+
+/// see [`Decoder::chars`](Decoder::chars).
+#[derive(Debug, Clone)]
+pub struct Chars<'s, D: Decoder> {
+    units: &'s [D::Unit],
+    front: usize,
+    back: usize,
+}
+impl<D: Decoder> Iterator for Chars<'_, D> {
+    type Item = char;
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (ch, width) = D::decode(&self.units[..self.back], self.front)?;
+        self.front += width;
+        Some(ch)
+    }
+}
+impl<D: Decoder> DoubleEndedIterator for Chars<'_, D> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (ch, width) = D::decode_back(&self.units[self.front..], self.back - self.front)?;
+        self.back -= width;
+        Some(ch)
+    }
+}
+
+/// the chars of some units, what a [`TextCursor`](TextCursor) reads,
+/// like a `str` for a [`StrCursor`](StrCursor), borrowed as a `&Text<D>`.
+#[repr(transparent)]
+pub struct Text<D: Decoder> {
+    _marker: PhantomData<D>,
+    units: [D::Unit],
+}
+impl<D: Decoder> fmt::Debug for Text<D> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.chars()).finish()
+    }
+}
+impl<D: Decoder> PartialEq for Text<D> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.units == other.units
+    }
+}
+impl<D: Decoder> Eq for Text<D> where D::Unit: Eq {}
+
+impl<D: Decoder> Text<D> {
+    #[inline]
+    pub fn new(units: &[D::Unit]) -> &Self {
+        // SAFETY: `Text<D>` is `repr(transparent)` over `[D::Unit]`.
+        unsafe { &*(units as *const [D::Unit] as *const Text<D>) }
+    }
+    #[inline]
+    pub fn as_units(&self) -> &[D::Unit] {
+        &self.units
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+    #[inline]
+    pub fn chars(&self) -> Chars<'_, D> {
+        D::chars(&self.units)
+    }
+}
+
+/// what a char cursor reads, a `str` or a [`Text`](Text),
+/// every position is in the units of its [`Decoder`](Decoder).
+pub trait Haystack {
+    type Decoder: Decoder;
+
+    fn as_units(&self) -> &[<Self::Decoder as Decoder>::Unit];
+    /// the units in `range`, out of bounds panics like indexing.
+    fn slice(&self, range: Range<usize>) -> &Self;
+
+    /// (the char starting at `at`, where the next one starts).
+    #[inline]
+    fn char_at(&self, at: usize) -> Option<(char, usize)> {
+        let (ch, width) = Self::Decoder::decode(self.as_units(), at)?;
+        Some((ch, at + width))
+    }
+    /// (the char ending right before `end`, where it starts).
+    #[inline]
+    fn char_before(&self, end: usize) -> Option<(char, usize)> {
+        let (ch, width) = Self::Decoder::decode_back(self.as_units(), end)?;
+        Some((ch, end - width))
+    }
+    #[inline]
+    fn chars(&self) -> Chars<'_, Self::Decoder> {
+        Self::Decoder::chars(self.as_units())
+    }
+    #[inline]
+    fn count_chars(&self) -> usize {
+        Self::Decoder::count_chars(self.as_units())
+    }
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.as_units().is_empty()
+    }
+    /// if a char can start at `at`, any unit up to the end by default.
+    #[inline]
+    fn is_char_boundary(&self, at: usize) -> bool {
+        at <= self.as_units().len()
+    }
+    /// first unit of the `n`th char, zero-based, `None` if there's no such char.
+    #[inline]
+    fn nth_char_start(&self, n: usize) -> Option<usize> {
+        let mut at = 0;
+        for _ in 0..n {
+            (_, at) = self.char_at(at)?;
+        }
+        self.char_at(at).map(|_| at)
+    }
+    /// (chars before, chars of) the nearest `needle`,
+    /// from the start if heading `right`, from the end otherwise.
+    fn find_str(&self, needle: &str, right: bool) -> Option<(usize, usize)>;
+}
+
+impl Haystack for str {
+    type Decoder = Utf8;
+    #[inline]
+    fn as_units(&self) -> &[u8] {
+        self.as_bytes()
+    }
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &str {
+        &self[range]
+    }
+    #[inline]
+    fn char_at(&self, at: usize) -> Option<(char, usize)> {
+        let ch = self[at..].chars().next()?;
+        Some((ch, at + ch.len_utf8()))
+    }
+    #[inline]
+    fn char_before(&self, end: usize) -> Option<(char, usize)> {
+        let ch = self[..end].chars().next_back()?;
+        Some((ch, end - ch.len_utf8()))
+    }
+    #[inline]
+    fn count_chars(&self) -> usize {
+        utf::count_chars(self.as_bytes())
+    }
+    #[inline]
+    fn is_char_boundary(&self, at: usize) -> bool {
+        str::is_char_boundary(self, at)
+    }
+    #[inline]
+    fn nth_char_start(&self, n: usize) -> Option<usize> {
+        utf::nth_char_start(self.as_bytes(), n)
+    }
+    #[inline]
+    fn find_str(&self, needle: &str, right: bool) -> Option<(usize, usize)> {
+        if needle.is_empty() {
+            return None;
+        }
+        let bytes = self.as_bytes();
+        let matched = utf::count_chars(needle.as_bytes());
+        if right {
+            let i = search::find(bytes, needle.as_bytes())?;
+            Some((utf::count_chars(&bytes[..i]), matched))
+        } else {
+            let i = search::rfind(bytes, needle.as_bytes())?;
+            Some((utf::count_chars(&bytes[i + needle.len()..]), matched))
+        }
+    }
+}
+impl<D: Decoder> Haystack for Text<D> {
+    type Decoder = D;
+    #[inline]
+    fn as_units(&self) -> &[D::Unit] {
+        &self.units
+    }
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &Self {
+        Text::new(&self.units[range])
+    }
+    #[inline]
+    fn find_str(&self, needle: &str, right: bool) -> Option<(usize, usize)> {
+        if needle.is_empty() {
+            return None;
+        }
+        find_pattern_in_text(self, right, &mut &*needle)
+    }
+}
+
+/// UTF-8 over bytes, checked,
+/// a maximal invalid subpart is one U+FFFD like `String::from_utf8_lossy`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Utf8;
+impl Decoder for Utf8 {
+    type Unit = u8;
+    #[inline]
+    fn decode(units: &[u8], at: usize) -> Option<(char, usize)> {
+        utf::decode_sequence(units.get(at..)?).map(lossy)
+    }
+    #[inline]
+    fn decode_back(units: &[u8], end: usize) -> Option<(char, usize)> {
+        utf::decode_back_sequence(units.get(..end)?).map(lossy)
+    }
+    #[inline]
+    fn count_chars(units: &[u8]) -> usize {
+        match str::from_utf8(units) {
+            Ok(_) => utf::count_chars(units),
+            Err(_) => Self::chars(units).count(),
+        }
+    }
+}
+#[inline]
+fn lossy(sequence: Result<(char, usize), usize>) -> (char, usize) {
+    sequence.unwrap_or_else(|width| (char::REPLACEMENT_CHARACTER, width))
+}
+
+/// UTF-16 over `u16`s, in the native order.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Utf16;
+impl Decoder for Utf16 {
+    type Unit = u16;
+    #[inline]
+    fn decode(units: &[u16], at: usize) -> Option<(char, usize)> {
+        let first = *units.get(at)?;
+        Some(utf16_decode(first, units.get(at + 1).copied()))
+    }
+    #[inline]
+    fn decode_back(units: &[u16], end: usize) -> Option<(char, usize)> {
+        let last = *units.get(end.checked_sub(1)?)?;
+        let before = end.checked_sub(2).map(|i| units[i]);
+        Some(utf16_decode_back(last, before))
+    }
+}
+
+/// UTF-16 little-endian over bytes, a trailing odd byte is one U+FFFD.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Utf16Le;
+impl Decoder for Utf16Le {
+    type Unit = u8;
+    #[inline]
+    fn decode(units: &[u8], at: usize) -> Option<(char, usize)> {
+        utf16_bytes_decode(units, at, u16::from_le_bytes)
+    }
+    #[inline]
+    fn decode_back(units: &[u8], end: usize) -> Option<(char, usize)> {
+        utf16_bytes_decode_back(units, end, u16::from_le_bytes)
+    }
+}
+
+/// UTF-16 big-endian over bytes, a trailing odd byte is one U+FFFD.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Utf16Be;
+impl Decoder for Utf16Be {
+    type Unit = u8;
+    #[inline]
+    fn decode(units: &[u8], at: usize) -> Option<(char, usize)> {
+        utf16_bytes_decode(units, at, u16::from_be_bytes)
+    }
+    #[inline]
+    fn decode_back(units: &[u8], end: usize) -> Option<(char, usize)> {
+        utf16_bytes_decode_back(units, end, u16::from_be_bytes)
+    }
+}
+
+/// ISO-8859-1, every byte is the char of the same code point.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Latin1;
+impl Decoder for Latin1 {
+    type Unit = u8;
+    #[inline]
+    fn decode(units: &[u8], at: usize) -> Option<(char, usize)> {
+        units.get(at).map(|&byte| (byte as char, 1))
+    }
+    #[inline]
+    fn decode_back(units: &[u8], end: usize) -> Option<(char, usize)> {
+        Self::decode(units, end.checked_sub(1)?)
+    }
+    #[inline]
+    fn count_chars(units: &[u8]) -> usize {
+        units.len()
+    }
+}
+
+/// 7-bit ASCII, a byte above `0x7f` is one U+FFFD.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Ascii;
+impl Decoder for Ascii {
+    type Unit = u8;
+    #[inline]
+    fn decode(units: &[u8], at: usize) -> Option<(char, usize)> {
+        units.get(at).map(|&byte| match byte {
+            0x00..=0x7f => (byte as char, 1),
+            _ => (char::REPLACEMENT_CHARACTER, 1),
+        })
+    }
+    #[inline]
+    fn decode_back(units: &[u8], end: usize) -> Option<(char, usize)> {
+        Self::decode(units, end.checked_sub(1)?)
+    }
+    #[inline]
+    fn count_chars(units: &[u8]) -> usize {
+        units.len()
+    }
+}
+
+// ------ UTF-16 ------
+
+#[inline]
+fn is_high_surrogate(unit: u16) -> bool {
+    (0xd800..0xdc00).contains(&unit)
+}
+#[inline]
+fn is_low_surrogate(unit: u16) -> bool {
+    (0xdc00..0xe000).contains(&unit)
+}
+#[inline]
+fn surrogate_pair(high: u16, low: u16) -> char {
+    let code = 0x10000 + (((high as u32) - 0xd800) << 10) + ((low as u32) - 0xdc00);
+    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+/// a lone surrogate is one U+FFFD.
+#[inline]
+fn lone(unit: u16) -> (char, usize) {
+    (
+        char::from_u32(unit as u32).unwrap_or(char::REPLACEMENT_CHARACTER),
+        1,
+    )
+}
+
+#[inline]
+fn utf16_decode(first: u16, second: Option<u16>) -> (char, usize) {
+    match second {
+        Some(low) if is_high_surrogate(first) && is_low_surrogate(low) => {
+            (surrogate_pair(first, low), 2)
+        }
+        _ => lone(first),
+    }
+}
+#[inline]
+fn utf16_decode_back(last: u16, before: Option<u16>) -> (char, usize) {
+    match before {
+        Some(high) if is_high_surrogate(high) && is_low_surrogate(last) => {
+            (surrogate_pair(high, last), 2)
+        }
+        _ => lone(last),
+    }
+}
+
+/// the unit of the two bytes at `at`.
+#[inline]
+fn read_unit(bytes: &[u8], at: usize, read: fn([u8; 2]) -> u16) -> Option<u16> {
+    let pair = bytes.get(at..at.checked_add(2)?)?;
+    Some(read([pair[0], pair[1]]))
+}
+
+#[inline]
+fn utf16_bytes_decode(bytes: &[u8], at: usize, read: fn([u8; 2]) -> u16) -> Option<(char, usize)> {
+    let Some(first) = read_unit(bytes, at, read) else {
+        return (at < bytes.len()).then_some((char::REPLACEMENT_CHARACTER, 1));
+    };
+    let (ch, units) = utf16_decode(first, read_unit(bytes, at + 2, read));
+    Some((ch, units * 2))
+}
+#[inline]
+fn utf16_bytes_decode_back(
+    bytes: &[u8],
+    end: usize,
+    read: fn([u8; 2]) -> u16,
+) -> Option<(char, usize)> {
+    if end == 0 || end > bytes.len() {
+        return None;
+    }
+    if end % 2 == 1 {
+        return Some((char::REPLACEMENT_CHARACTER, 1));
+    }
+    let last = read_unit(bytes, end - 2, read)?;
+    let before = end.checked_sub(4).and_then(|at| read_unit(bytes, at, read));
+    let (ch, units) = utf16_decode_back(last, before);
+    Some((ch, units * 2))
+}
@@ -0,0 +1,54 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// why a `try_*` move failed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CursorError {
+    /// the position is not in `0..len`.
+    OutOfBounds { requested: usize, len: usize },
+    /// the position doesn't fit in `usize`.
+    Overflow,
+    /// no more items in the heading direction.
+    EndOfInput,
+    /// the bytes at `byte_pos` are not UTF-8.
+    InvalidUtf8 { byte_pos: usize },
+    /// there is nothing to move on.
+    EmptyInput,
+    /// the item at `pos` doesn't match the expected pattern.
+    Unexpected { pos: usize },
+}
+impl fmt::Display for CursorError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CursorError::OutOfBounds { requested, len } => {
+                write!(f, "position {} is out of bounds (len: {})", requested, len)
+            }
+            CursorError::Overflow => write!(f, "position overflowed"),
+            CursorError::EndOfInput => write!(f, "end of input"),
+            CursorError::InvalidUtf8 { byte_pos } => {
+                write!(f, "invalid UTF-8 sequence at byte {}", byte_pos)
+            }
+            CursorError::EmptyInput => write!(f, "empty input"),
+            CursorError::Unexpected { pos } => write!(f, "unexpected item at position {}", pos),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for CursorError {}
+
+/// `pos` must be in `0..len`.
+#[inline]
+pub fn check_bounds(pos: usize, len: usize) -> Result<(), CursorError> {
+    if len == 0 {
+        Err(CursorError::EmptyInput)
+    } else if pos >= len {
+        Err(CursorError::OutOfBounds {
+            requested: pos,
+            len,
+        })
+    } else {
+        Ok(())
+    }
+}
@@ -18,3 +18,135 @@ impl<T> Extras<T> for NoneExtras<T> {
     #[inline]
     fn reset(&mut self) {}
 }
+
+/// positions passed over from `from` to `to`, both exclusive, in moving order,
+/// from `0` inclusive if `from` is `None` (nothing seen yet).
+/// * for [`STEPWISE`](Extras::STEPWISE) extras.
+#[inline]
+pub(crate) fn stepped(from: Option<usize>, to: usize) -> impl Iterator<Item = usize> {
+    let (start, end, rev) = match from {
+        None => (0, to, false),
+        Some(from) if from < to => (from + 1, to, false),
+        Some(from) => ((to + 1).min(from), from, true),
+    };
+    (start..end).map(move |i| if rev { end - 1 - (i - start) } else { i })
+}
+
+// ------ composed ------
+
+macro_rules! tuple_extras {
+    ($($member:ident . $i:tt),+) => {
+        /// every member sees every item, in order.
+        /// * [`STEPWISE`](Extras::STEPWISE) if any member is,
+        ///   the others then see the items on the way as well.
+        /// * `cursor.extras().0`, `cursor.to_range_extras().map_extras(|e| e.1)`.
+        impl<Input, $($member: Extras<Input>),+> Extras<Input> for ($($member,)+) {
+            const STEPWISE: bool = false $(|| $member::STEPWISE)+;
+
+            #[inline]
+            fn new() -> Self {
+                ($($member::new(),)+)
+            }
+            #[inline]
+            fn clone(&self) -> Self {
+                ($(Extras::clone(&self.$i),)+)
+            }
+            #[inline]
+            fn change(&mut self, input: &Input, pos: usize) {
+                $(self.$i.change(input, pos);)+
+            }
+            #[inline]
+            fn reset(&mut self) {
+                $(self.$i.reset();)+
+            }
+        }
+    };
+}
+tuple_extras!(A.0);
+tuple_extras!(A.0, B.1);
+tuple_extras!(A.0, B.1, C.2);
+tuple_extras!(A.0, B.1, C.2, D.3);
+tuple_extras!(A.0, B.1, C.2, D.3, E.4);
+tuple_extras!(A.0, B.1, C.2, D.3, E.4, F.5);
+tuple_extras!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+tuple_extras!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+
+/// starts as `Some`, set it to `None` through `extras_mut()` to stop tracking,
+/// `reset` keeps it `None`.
+impl<Input, E: Extras<Input>> Extras<Input> for Option<E> {
+    const STEPWISE: bool = E::STEPWISE;
+
+    #[inline]
+    fn new() -> Self {
+        Some(E::new())
+    }
+    #[inline]
+    fn clone(&self) -> Self {
+        self.as_ref().map(Extras::clone)
+    }
+    #[inline]
+    fn change(&mut self, input: &Input, pos: usize) {
+        if let Some(extras) = self {
+            extras.change(input, pos);
+        }
+    }
+    #[inline]
+    fn reset(&mut self) {
+        if let Some(extras) = self {
+            extras.reset();
+        }
+    }
+}
+
+/// `N` of the same tracker.
+impl<Input, E: Extras<Input>, const N: usize> Extras<Input> for [E; N] {
+    const STEPWISE: bool = E::STEPWISE;
+
+    #[inline]
+    fn new() -> Self {
+        core::array::from_fn(|_| E::new())
+    }
+    #[inline]
+    fn clone(&self) -> Self {
+        self.each_ref().map(Extras::clone)
+    }
+    #[inline]
+    fn change(&mut self, input: &Input, pos: usize) {
+        for extras in self {
+            extras.change(input, pos);
+        }
+    }
+    #[inline]
+    fn reset(&mut self) {
+        for extras in self {
+            extras.reset();
+        }
+    }
+}
+
+/// starts empty, push the trackers through `extras_mut()`.
+#[cfg(feature = "alloc")]
+impl<Input, E: Extras<Input>> Extras<Input> for alloc::vec::Vec<E> {
+    const STEPWISE: bool = E::STEPWISE;
+
+    #[inline]
+    fn new() -> Self {
+        alloc::vec::Vec::new()
+    }
+    #[inline]
+    fn clone(&self) -> Self {
+        self.iter().map(Extras::clone).collect()
+    }
+    #[inline]
+    fn change(&mut self, input: &Input, pos: usize) {
+        for extras in self {
+            extras.change(input, pos);
+        }
+    }
+    #[inline]
+    fn reset(&mut self) {
+        for extras in self {
+            extras.reset();
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! extended grapheme cluster boundaries of UAX #29, no allocations, `no_std`.
+//!
+//! every boundary is decided by the two chars around it plus a short look behind,
+//! so it's the same whichever way it's walked.
+
+use super::*;
+
+mod tables;
+
+/// `Grapheme_Cluster_Break` merged with `Indic_Conjunct_Break` and `Extended_Pictographic`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Cat {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    /// `Extend` without an `InCB`, only U+200C.
+    Extend,
+    /// `Extend`, `InCB=Extend`.
+    ExtendInCB,
+    /// `Extend`, `InCB=Linker`.
+    Linker,
+    /// `ZWJ`, `InCB=Extend` as well.
+    Zwj,
+    /// `InCB=Consonant`.
+    Consonant,
+    ExtPict,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+}
+use Cat::*;
+
+impl Cat {
+    #[inline]
+    fn of(c: char) -> Cat {
+        let code = c as u32;
+        if code < 0x7f {
+            return match code {
+                0x0a => Lf,
+                0x0d => Cr,
+                0x00..=0x1f => Control,
+                _ => Other,
+            };
+        }
+        if (0xac00..=0xd7a3).contains(&code) {
+            return if (code - 0xac00).is_multiple_of(28) {
+                Lv
+            } else {
+                Lvt
+            };
+        }
+        lookup_range(tables::CATS, c).unwrap_or(Other)
+    }
+    #[inline]
+    fn is_extend(self) -> bool {
+        matches!(self, Extend | ExtendInCB | Linker)
+    }
+    #[inline]
+    fn is_control(self) -> bool {
+        matches!(self, Cr | Lf | Control)
+    }
+}
+
+#[inline]
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    Cat::of(c) == ExtPict
+}
+
+#[inline]
+pub(crate) fn char_before(string: &str, at: usize) -> Option<(char, usize)> {
+    let ch = string[..at].chars().next_back()?;
+    Some((ch, at - ch.len_utf8()))
+}
+
+/// if a cluster starts or ends at the byte `at`.
+/// * `false` inside of a char or past the end.
+pub fn is_boundary(string: &str, at: usize) -> bool {
+    if at == 0 || at == string.len() {
+        return true;
+    }
+    if !string.is_char_boundary(at) {
+        return false;
+    }
+    let (a, a_at) = char_before(string, at).unwrap();
+    let b = string[at..].chars().next().unwrap();
+    let (a, b) = (Cat::of(a), Cat::of(b));
+    match (a, b) {
+        // GB3, GB4, GB5
+        (Cr, Lf) => false,
+        (a, b) if a.is_control() || b.is_control() => true,
+        // GB6, GB7, GB8
+        (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+        // GB9, GB9a, GB9b
+        (_, Zwj | SpacingMark) | (Prepend, _) => false,
+        (_, b) if b.is_extend() => false,
+        // GB9c
+        (ExtendInCB | Linker | Zwj, Consonant) => !conjunct_before(string, at),
+        // GB11
+        (Zwj, ExtPict) => !pictographic_before(string, a_at),
+        // GB12, GB13
+        (RegionalIndicator, RegionalIndicator) => {
+            regional_indicators_before(string, at).is_multiple_of(2)
+        }
+        // GB999
+        _ => true,
+    }
+}
+
+/// `InCB=Consonant [InCB=Extend InCB=Linker]*` with at least one linker, ending at `at`.
+#[inline]
+fn conjunct_before(string: &str, mut at: usize) -> bool {
+    let mut linked = false;
+    while let Some((ch, ch_at)) = char_before(string, at) {
+        match Cat::of(ch) {
+            Linker => linked = true,
+            ExtendInCB | Zwj => {}
+            Consonant => return linked,
+            _ => return false,
+        }
+        at = ch_at;
+    }
+    false
+}
+
+/// `ExtPict Extend*` ending at `at`.
+#[inline]
+fn pictographic_before(string: &str, mut at: usize) -> bool {
+    while let Some((ch, ch_at)) = char_before(string, at) {
+        match Cat::of(ch) {
+            cat if cat.is_extend() => {}
+            ExtPict => return true,
+            _ => return false,
+        }
+        at = ch_at;
+    }
+    false
+}
+
+#[inline]
+fn regional_indicators_before(string: &str, mut at: usize) -> usize {
+    let mut n = 0;
+    while let Some((ch, ch_at)) = char_before(string, at) {
+        if Cat::of(ch) != RegionalIndicator {
+            break;
+        }
+        n += 1;
+        at = ch_at;
+    }
+    n
+}
+
+/// end of the cluster starting at the byte `at`, `at` at the end.
+#[inline]
+pub fn next_boundary(string: &str, at: usize) -> usize {
+    let mut chars = string[at..].char_indices();
+    chars.next();
+    chars
+        .map(|(i, _)| at + i)
+        .find(|&i| is_boundary(string, i))
+        .unwrap_or(string.len())
+}
+
+/// start of the cluster ending at the byte `at`, `0` at the start.
+#[inline]
+pub fn prev_boundary(string: &str, at: usize) -> usize {
+    let mut chars = string[..at].char_indices().rev();
+    chars.next();
+    chars
+        .map(|(i, ch)| i + ch.len_utf8())
+        .find(|&i| is_boundary(string, i))
+        .unwrap_or(0)
+}
+
+/// the clusters of `string`, in both directions.
+#[inline]
+pub fn graphemes(string: &str) -> Graphemes<'_> {
+    Graphemes {
+        string,
+        front: 0,
+        back: string.len(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graphemes<'s> {
+    string: &'s str,
+    front: usize,
+    back: usize,
+}
+impl<'s> Iterator for Graphemes<'s> {
+    type Item = &'s str;
+    #[inline]
+    fn next(&mut self) -> Option<&'s str> {
+        if self.front >= self.back {
+            return None;
+        }
+        let end = next_boundary(self.string, self.front);
+        let out = &self.string[self.front..end];
+        self.front = end;
+        Some(out)
+    }
+}
+impl<'s> DoubleEndedIterator for Graphemes<'s> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'s str> {
+        if self.front >= self.back {
+            return None;
+        }
+        let start = prev_boundary(self.string, self.back);
+        let out = &self.string[start..self.back];
+        self.back = start;
+        Some(out)
+    }
+}
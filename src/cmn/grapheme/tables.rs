@@ -0,0 +1,842 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! generated from the Unicode 17.0 `Grapheme_Cluster_Break`, `Indic_Conjunct_Break`
+//! and `Extended_Pictographic` properties, the Hangul syllables are computed instead.
+
+use super::Cat::{self, *};
+
+pub(super) const CATS: &[(u32, u32, Cat)] = &[
+    (0x0, 0x9, Control),
+    (0xA, 0xA, Lf),
+    (0xB, 0xC, Control),
+    (0xD, 0xD, Cr),
+    (0xE, 0x1F, Control),
+    (0x7F, 0x9F, Control),
+    (0xA9, 0xA9, ExtPict),
+    (0xAD, 0xAD, Control),
+    (0xAE, 0xAE, ExtPict),
+    (0x300, 0x36F, ExtendInCB),
+    (0x483, 0x489, ExtendInCB),
+    (0x591, 0x5BD, ExtendInCB),
+    (0x5BF, 0x5BF, ExtendInCB),
+    (0x5C1, 0x5C2, ExtendInCB),
+    (0x5C4, 0x5C5, ExtendInCB),
+    (0x5C7, 0x5C7, ExtendInCB),
+    (0x600, 0x605, Prepend),
+    (0x610, 0x61A, ExtendInCB),
+    (0x61C, 0x61C, Control),
+    (0x64B, 0x65F, ExtendInCB),
+    (0x670, 0x670, ExtendInCB),
+    (0x6D6, 0x6DC, ExtendInCB),
+    (0x6DD, 0x6DD, Prepend),
+    (0x6DF, 0x6E4, ExtendInCB),
+    (0x6E7, 0x6E8, ExtendInCB),
+    (0x6EA, 0x6ED, ExtendInCB),
+    (0x70F, 0x70F, Prepend),
+    (0x711, 0x711, ExtendInCB),
+    (0x730, 0x74A, ExtendInCB),
+    (0x7A6, 0x7B0, ExtendInCB),
+    (0x7EB, 0x7F3, ExtendInCB),
+    (0x7FD, 0x7FD, ExtendInCB),
+    (0x816, 0x819, ExtendInCB),
+    (0x81B, 0x823, ExtendInCB),
+    (0x825, 0x827, ExtendInCB),
+    (0x829, 0x82D, ExtendInCB),
+    (0x859, 0x85B, ExtendInCB),
+    (0x890, 0x891, Prepend),
+    (0x897, 0x89F, ExtendInCB),
+    (0x8CA, 0x8E1, ExtendInCB),
+    (0x8E2, 0x8E2, Prepend),
+    (0x8E3, 0x902, ExtendInCB),
+    (0x903, 0x903, SpacingMark),
+    (0x915, 0x939, Consonant),
+    (0x93A, 0x93A, ExtendInCB),
+    (0x93B, 0x93B, SpacingMark),
+    (0x93C, 0x93C, ExtendInCB),
+    (0x93E, 0x940, SpacingMark),
+    (0x941, 0x948, ExtendInCB),
+    (0x949, 0x94C, SpacingMark),
+    (0x94D, 0x94D, Linker),
+    (0x94E, 0x94F, SpacingMark),
+    (0x951, 0x957, ExtendInCB),
+    (0x958, 0x95F, Consonant),
+    (0x962, 0x963, ExtendInCB),
+    (0x978, 0x97F, Consonant),
+    (0x981, 0x981, ExtendInCB),
+    (0x982, 0x983, SpacingMark),
+    (0x995, 0x9A8, Consonant),
+    (0x9AA, 0x9B0, Consonant),
+    (0x9B2, 0x9B2, Consonant),
+    (0x9B6, 0x9B9, Consonant),
+    (0x9BC, 0x9BC, ExtendInCB),
+    (0x9BE, 0x9BE, ExtendInCB),
+    (0x9BF, 0x9C0, SpacingMark),
+    (0x9C1, 0x9C4, ExtendInCB),
+    (0x9C7, 0x9C8, SpacingMark),
+    (0x9CB, 0x9CC, SpacingMark),
+    (0x9CD, 0x9CD, Linker),
+    (0x9D7, 0x9D7, ExtendInCB),
+    (0x9DC, 0x9DD, Consonant),
+    (0x9DF, 0x9DF, Consonant),
+    (0x9E2, 0x9E3, ExtendInCB),
+    (0x9F0, 0x9F1, Consonant),
+    (0x9FE, 0x9FE, ExtendInCB),
+    (0xA01, 0xA02, ExtendInCB),
+    (0xA03, 0xA03, SpacingMark),
+    (0xA3C, 0xA3C, ExtendInCB),
+    (0xA3E, 0xA40, SpacingMark),
+    (0xA41, 0xA42, ExtendInCB),
+    (0xA47, 0xA48, ExtendInCB),
+    (0xA4B, 0xA4D, ExtendInCB),
+    (0xA51, 0xA51, ExtendInCB),
+    (0xA70, 0xA71, ExtendInCB),
+    (0xA75, 0xA75, ExtendInCB),
+    (0xA81, 0xA82, ExtendInCB),
+    (0xA83, 0xA83, SpacingMark),
+    (0xA95, 0xAA8, Consonant),
+    (0xAAA, 0xAB0, Consonant),
+    (0xAB2, 0xAB3, Consonant),
+    (0xAB5, 0xAB9, Consonant),
+    (0xABC, 0xABC, ExtendInCB),
+    (0xABE, 0xAC0, SpacingMark),
+    (0xAC1, 0xAC5, ExtendInCB),
+    (0xAC7, 0xAC8, ExtendInCB),
+    (0xAC9, 0xAC9, SpacingMark),
+    (0xACB, 0xACC, SpacingMark),
+    (0xACD, 0xACD, Linker),
+    (0xAE2, 0xAE3, ExtendInCB),
+    (0xAF9, 0xAF9, Consonant),
+    (0xAFA, 0xAFF, ExtendInCB),
+    (0xB01, 0xB01, ExtendInCB),
+    (0xB02, 0xB03, SpacingMark),
+    (0xB15, 0xB28, Consonant),
+    (0xB2A, 0xB30, Consonant),
+    (0xB32, 0xB33, Consonant),
+    (0xB35, 0xB39, Consonant),
+    (0xB3C, 0xB3C, ExtendInCB),
+    (0xB3E, 0xB3F, ExtendInCB),
+    (0xB40, 0xB40, SpacingMark),
+    (0xB41, 0xB44, ExtendInCB),
+    (0xB47, 0xB48, SpacingMark),
+    (0xB4B, 0xB4C, SpacingMark),
+    (0xB4D, 0xB4D, Linker),
+    (0xB55, 0xB57, ExtendInCB),
+    (0xB5C, 0xB5D, Consonant),
+    (0xB5F, 0xB5F, Consonant),
+    (0xB62, 0xB63, ExtendInCB),
+    (0xB71, 0xB71, Consonant),
+    (0xB82, 0xB82, ExtendInCB),
+    (0xBBE, 0xBBE, ExtendInCB),
+    (0xBBF, 0xBBF, SpacingMark),
+    (0xBC0, 0xBC0, ExtendInCB),
+    (0xBC1, 0xBC2, SpacingMark),
+    (0xBC6, 0xBC8, SpacingMark),
+    (0xBCA, 0xBCC, SpacingMark),
+    (0xBCD, 0xBCD, ExtendInCB),
+    (0xBD7, 0xBD7, ExtendInCB),
+    (0xC00, 0xC00, ExtendInCB),
+    (0xC01, 0xC03, SpacingMark),
+    (0xC04, 0xC04, ExtendInCB),
+    (0xC15, 0xC28, Consonant),
+    (0xC2A, 0xC39, Consonant),
+    (0xC3C, 0xC3C, ExtendInCB),
+    (0xC3E, 0xC40, ExtendInCB),
+    (0xC41, 0xC44, SpacingMark),
+    (0xC46, 0xC48, ExtendInCB),
+    (0xC4A, 0xC4C, ExtendInCB),
+    (0xC4D, 0xC4D, Linker),
+    (0xC55, 0xC56, ExtendInCB),
+    (0xC58, 0xC5A, Consonant),
+    (0xC62, 0xC63, ExtendInCB),
+    (0xC81, 0xC81, ExtendInCB),
+    (0xC82, 0xC83, SpacingMark),
+    (0xCBC, 0xCBC, ExtendInCB),
+    (0xCBE, 0xCBE, SpacingMark),
+    (0xCBF, 0xCC0, ExtendInCB),
+    (0xCC1, 0xCC1, SpacingMark),
+    (0xCC2, 0xCC2, ExtendInCB),
+    (0xCC3, 0xCC4, SpacingMark),
+    (0xCC6, 0xCC8, ExtendInCB),
+    (0xCCA, 0xCCD, ExtendInCB),
+    (0xCD5, 0xCD6, ExtendInCB),
+    (0xCE2, 0xCE3, ExtendInCB),
+    (0xCF3, 0xCF3, SpacingMark),
+    (0xD00, 0xD01, ExtendInCB),
+    (0xD02, 0xD03, SpacingMark),
+    (0xD15, 0xD3A, Consonant),
+    (0xD3B, 0xD3C, ExtendInCB),
+    (0xD3E, 0xD3E, ExtendInCB),
+    (0xD3F, 0xD40, SpacingMark),
+    (0xD41, 0xD44, ExtendInCB),
+    (0xD46, 0xD48, SpacingMark),
+    (0xD4A, 0xD4C, SpacingMark),
+    (0xD4D, 0xD4D, Linker),
+    (0xD4E, 0xD4E, Prepend),
+    (0xD57, 0xD57, ExtendInCB),
+    (0xD62, 0xD63, ExtendInCB),
+    (0xD81, 0xD81, ExtendInCB),
+    (0xD82, 0xD83, SpacingMark),
+    (0xDCA, 0xDCA, ExtendInCB),
+    (0xDCF, 0xDCF, ExtendInCB),
+    (0xDD0, 0xDD1, SpacingMark),
+    (0xDD2, 0xDD4, ExtendInCB),
+    (0xDD6, 0xDD6, ExtendInCB),
+    (0xDD8, 0xDDE, SpacingMark),
+    (0xDDF, 0xDDF, ExtendInCB),
+    (0xDF2, 0xDF3, SpacingMark),
+    (0xE31, 0xE31, ExtendInCB),
+    (0xE33, 0xE33, SpacingMark),
+    (0xE34, 0xE3A, ExtendInCB),
+    (0xE47, 0xE4E, ExtendInCB),
+    (0xEB1, 0xEB1, ExtendInCB),
+    (0xEB3, 0xEB3, SpacingMark),
+    (0xEB4, 0xEBC, ExtendInCB),
+    (0xEC8, 0xECE, ExtendInCB),
+    (0xF18, 0xF19, ExtendInCB),
+    (0xF35, 0xF35, ExtendInCB),
+    (0xF37, 0xF37, ExtendInCB),
+    (0xF39, 0xF39, ExtendInCB),
+    (0xF3E, 0xF3F, SpacingMark),
+    (0xF71, 0xF7E, ExtendInCB),
+    (0xF7F, 0xF7F, SpacingMark),
+    (0xF80, 0xF84, ExtendInCB),
+    (0xF86, 0xF87, ExtendInCB),
+    (0xF8D, 0xF97, ExtendInCB),
+    (0xF99, 0xFBC, ExtendInCB),
+    (0xFC6, 0xFC6, ExtendInCB),
+    (0x1000, 0x102A, Consonant),
+    (0x102D, 0x1030, ExtendInCB),
+    (0x1031, 0x1031, SpacingMark),
+    (0x1032, 0x1037, ExtendInCB),
+    (0x1039, 0x1039, Linker),
+    (0x103A, 0x103A, ExtendInCB),
+    (0x103B, 0x103C, SpacingMark),
+    (0x103D, 0x103E, ExtendInCB),
+    (0x103F, 0x103F, Consonant),
+    (0x1050, 0x1055, Consonant),
+    (0x1056, 0x1057, SpacingMark),
+    (0x1058, 0x1059, ExtendInCB),
+    (0x105A, 0x105D, Consonant),
+    (0x105E, 0x1060, ExtendInCB),
+    (0x1061, 0x1061, Consonant),
+    (0x1065, 0x1066, Consonant),
+    (0x106E, 0x1070, Consonant),
+    (0x1071, 0x1074, ExtendInCB),
+    (0x1075, 0x1081, Consonant),
+    (0x1082, 0x1082, ExtendInCB),
+    (0x1084, 0x1084, SpacingMark),
+    (0x1085, 0x1086, ExtendInCB),
+    (0x108D, 0x108D, ExtendInCB),
+    (0x108E, 0x108E, Consonant),
+    (0x109D, 0x109D, ExtendInCB),
+    (0x1100, 0x115F, L),
+    (0x1160, 0x11A7, V),
+    (0x11A8, 0x11FF, T),
+    (0x135D, 0x135F, ExtendInCB),
+    (0x1712, 0x1715, ExtendInCB),
+    (0x1732, 0x1734, ExtendInCB),
+    (0x1752, 0x1753, ExtendInCB),
+    (0x1772, 0x1773, ExtendInCB),
+    (0x1780, 0x17B3, Consonant),
+    (0x17B4, 0x17B5, ExtendInCB),
+    (0x17B6, 0x17B6, SpacingMark),
+    (0x17B7, 0x17BD, ExtendInCB),
+    (0x17BE, 0x17C5, SpacingMark),
+    (0x17C6, 0x17C6, ExtendInCB),
+    (0x17C7, 0x17C8, SpacingMark),
+    (0x17C9, 0x17D1, ExtendInCB),
+    (0x17D2, 0x17D2, Linker),
+    (0x17D3, 0x17D3, ExtendInCB),
+    (0x17DD, 0x17DD, ExtendInCB),
+    (0x180B, 0x180D, ExtendInCB),
+    (0x180E, 0x180E, Control),
+    (0x180F, 0x180F, ExtendInCB),
+    (0x1885, 0x1886, ExtendInCB),
+    (0x18A9, 0x18A9, ExtendInCB),
+    (0x1920, 0x1922, ExtendInCB),
+    (0x1923, 0x1926, SpacingMark),
+    (0x1927, 0x1928, ExtendInCB),
+    (0x1929, 0x192B, SpacingMark),
+    (0x1930, 0x1931, SpacingMark),
+    (0x1932, 0x1932, ExtendInCB),
+    (0x1933, 0x1938, SpacingMark),
+    (0x1939, 0x193B, ExtendInCB),
+    (0x1A17, 0x1A18, ExtendInCB),
+    (0x1A19, 0x1A1A, SpacingMark),
+    (0x1A1B, 0x1A1B, ExtendInCB),
+    (0x1A20, 0x1A54, Consonant),
+    (0x1A55, 0x1A55, SpacingMark),
+    (0x1A56, 0x1A56, ExtendInCB),
+    (0x1A57, 0x1A57, SpacingMark),
+    (0x1A58, 0x1A5E, ExtendInCB),
+    (0x1A60, 0x1A60, Linker),
+    (0x1A62, 0x1A62, ExtendInCB),
+    (0x1A65, 0x1A6C, ExtendInCB),
+    (0x1A6D, 0x1A72, SpacingMark),
+    (0x1A73, 0x1A7C, ExtendInCB),
+    (0x1A7F, 0x1A7F, ExtendInCB),
+    (0x1AB0, 0x1ADD, ExtendInCB),
+    (0x1AE0, 0x1AEB, ExtendInCB),
+    (0x1B00, 0x1B03, ExtendInCB),
+    (0x1B04, 0x1B04, SpacingMark),
+    (0x1B0B, 0x1B0C, Consonant),
+    (0x1B13, 0x1B33, Consonant),
+    (0x1B34, 0x1B3D, ExtendInCB),
+    (0x1B3E, 0x1B41, SpacingMark),
+    (0x1B42, 0x1B43, ExtendInCB),
+    (0x1B44, 0x1B44, Linker),
+    (0x1B45, 0x1B4C, Consonant),
+    (0x1B6B, 0x1B73, ExtendInCB),
+    (0x1B80, 0x1B81, ExtendInCB),
+    (0x1B82, 0x1B82, SpacingMark),
+    (0x1B83, 0x1BA0, Consonant),
+    (0x1BA1, 0x1BA1, SpacingMark),
+    (0x1BA2, 0x1BA5, ExtendInCB),
+    (0x1BA6, 0x1BA7, SpacingMark),
+    (0x1BA8, 0x1BAA, ExtendInCB),
+    (0x1BAB, 0x1BAB, Linker),
+    (0x1BAC, 0x1BAD, ExtendInCB),
+    (0x1BAE, 0x1BAF, Consonant),
+    (0x1BBB, 0x1BBD, Consonant),
+    (0x1BE6, 0x1BE6, ExtendInCB),
+    (0x1BE7, 0x1BE7, SpacingMark),
+    (0x1BE8, 0x1BE9, ExtendInCB),
+    (0x1BEA, 0x1BEC, SpacingMark),
+    (0x1BED, 0x1BED, ExtendInCB),
+    (0x1BEE, 0x1BEE, SpacingMark),
+    (0x1BEF, 0x1BF3, ExtendInCB),
+    (0x1C24, 0x1C2B, SpacingMark),
+    (0x1C2C, 0x1C33, ExtendInCB),
+    (0x1C34, 0x1C35, SpacingMark),
+    (0x1C36, 0x1C37, ExtendInCB),
+    (0x1CD0, 0x1CD2, ExtendInCB),
+    (0x1CD4, 0x1CE0, ExtendInCB),
+    (0x1CE1, 0x1CE1, SpacingMark),
+    (0x1CE2, 0x1CE8, ExtendInCB),
+    (0x1CED, 0x1CED, ExtendInCB),
+    (0x1CF4, 0x1CF4, ExtendInCB),
+    (0x1CF7, 0x1CF7, SpacingMark),
+    (0x1CF8, 0x1CF9, ExtendInCB),
+    (0x1DC0, 0x1DFF, ExtendInCB),
+    (0x200B, 0x200B, Control),
+    (0x200C, 0x200C, Extend),
+    (0x200D, 0x200D, Zwj),
+    (0x200E, 0x200F, Control),
+    (0x2028, 0x202E, Control),
+    (0x203C, 0x203C, ExtPict),
+    (0x2049, 0x2049, ExtPict),
+    (0x2060, 0x206F, Control),
+    (0x20D0, 0x20F0, ExtendInCB),
+    (0x2122, 0x2122, ExtPict),
+    (0x2139, 0x2139, ExtPict),
+    (0x2194, 0x2199, ExtPict),
+    (0x21A9, 0x21AA, ExtPict),
+    (0x231A, 0x231B, ExtPict),
+    (0x2328, 0x2328, ExtPict),
+    (0x23CF, 0x23CF, ExtPict),
+    (0x23E9, 0x23F3, ExtPict),
+    (0x23F8, 0x23FA, ExtPict),
+    (0x24C2, 0x24C2, ExtPict),
+    (0x25AA, 0x25AB, ExtPict),
+    (0x25B6, 0x25B6, ExtPict),
+    (0x25C0, 0x25C0, ExtPict),
+    (0x25FB, 0x25FE, ExtPict),
+    (0x2600, 0x2604, ExtPict),
+    (0x260E, 0x260E, ExtPict),
+    (0x2611, 0x2611, ExtPict),
+    (0x2614, 0x2615, ExtPict),
+    (0x2618, 0x2618, ExtPict),
+    (0x261D, 0x261D, ExtPict),
+    (0x2620, 0x2620, ExtPict),
+    (0x2622, 0x2623, ExtPict),
+    (0x2626, 0x2626, ExtPict),
+    (0x262A, 0x262A, ExtPict),
+    (0x262E, 0x262F, ExtPict),
+    (0x2638, 0x263A, ExtPict),
+    (0x2640, 0x2640, ExtPict),
+    (0x2642, 0x2642, ExtPict),
+    (0x2648, 0x2653, ExtPict),
+    (0x265F, 0x2660, ExtPict),
+    (0x2663, 0x2663, ExtPict),
+    (0x2665, 0x2666, ExtPict),
+    (0x2668, 0x2668, ExtPict),
+    (0x267B, 0x267B, ExtPict),
+    (0x267E, 0x267F, ExtPict),
+    (0x2692, 0x2697, ExtPict),
+    (0x2699, 0x2699, ExtPict),
+    (0x269B, 0x269C, ExtPict),
+    (0x26A0, 0x26A1, ExtPict),
+    (0x26A7, 0x26A7, ExtPict),
+    (0x26AA, 0x26AB, ExtPict),
+    (0x26B0, 0x26B1, ExtPict),
+    (0x26BD, 0x26BE, ExtPict),
+    (0x26C4, 0x26C5, ExtPict),
+    (0x26C8, 0x26C8, ExtPict),
+    (0x26CE, 0x26CF, ExtPict),
+    (0x26D1, 0x26D1, ExtPict),
+    (0x26D3, 0x26D4, ExtPict),
+    (0x26E9, 0x26EA, ExtPict),
+    (0x26F0, 0x26F5, ExtPict),
+    (0x26F7, 0x26FA, ExtPict),
+    (0x26FD, 0x26FD, ExtPict),
+    (0x2702, 0x2702, ExtPict),
+    (0x2705, 0x2705, ExtPict),
+    (0x2708, 0x270D, ExtPict),
+    (0x270F, 0x270F, ExtPict),
+    (0x2712, 0x2712, ExtPict),
+    (0x2714, 0x2714, ExtPict),
+    (0x2716, 0x2716, ExtPict),
+    (0x271D, 0x271D, ExtPict),
+    (0x2721, 0x2721, ExtPict),
+    (0x2728, 0x2728, ExtPict),
+    (0x2733, 0x2734, ExtPict),
+    (0x2744, 0x2744, ExtPict),
+    (0x2747, 0x2747, ExtPict),
+    (0x274C, 0x274C, ExtPict),
+    (0x274E, 0x274E, ExtPict),
+    (0x2753, 0x2755, ExtPict),
+    (0x2757, 0x2757, ExtPict),
+    (0x2763, 0x2764, ExtPict),
+    (0x2795, 0x2797, ExtPict),
+    (0x27A1, 0x27A1, ExtPict),
+    (0x27B0, 0x27B0, ExtPict),
+    (0x27BF, 0x27BF, ExtPict),
+    (0x2934, 0x2935, ExtPict),
+    (0x2B05, 0x2B07, ExtPict),
+    (0x2B1B, 0x2B1C, ExtPict),
+    (0x2B50, 0x2B50, ExtPict),
+    (0x2B55, 0x2B55, ExtPict),
+    (0x2CEF, 0x2CF1, ExtendInCB),
+    (0x2D7F, 0x2D7F, ExtendInCB),
+    (0x2DE0, 0x2DFF, ExtendInCB),
+    (0x302A, 0x302F, ExtendInCB),
+    (0x3030, 0x3030, ExtPict),
+    (0x303D, 0x303D, ExtPict),
+    (0x3099, 0x309A, ExtendInCB),
+    (0x3297, 0x3297, ExtPict),
+    (0x3299, 0x3299, ExtPict),
+    (0xA66F, 0xA672, ExtendInCB),
+    (0xA674, 0xA67D, ExtendInCB),
+    (0xA69E, 0xA69F, ExtendInCB),
+    (0xA6F0, 0xA6F1, ExtendInCB),
+    (0xA802, 0xA802, ExtendInCB),
+    (0xA806, 0xA806, ExtendInCB),
+    (0xA80B, 0xA80B, ExtendInCB),
+    (0xA823, 0xA824, SpacingMark),
+    (0xA825, 0xA826, ExtendInCB),
+    (0xA827, 0xA827, SpacingMark),
+    (0xA82C, 0xA82C, ExtendInCB),
+    (0xA880, 0xA881, SpacingMark),
+    (0xA8B4, 0xA8C3, SpacingMark),
+    (0xA8C4, 0xA8C5, ExtendInCB),
+    (0xA8E0, 0xA8F1, ExtendInCB),
+    (0xA8FF, 0xA8FF, ExtendInCB),
+    (0xA926, 0xA92D, ExtendInCB),
+    (0xA947, 0xA951, ExtendInCB),
+    (0xA952, 0xA952, SpacingMark),
+    (0xA953, 0xA953, ExtendInCB),
+    (0xA960, 0xA97C, L),
+    (0xA980, 0xA982, ExtendInCB),
+    (0xA983, 0xA983, SpacingMark),
+    (0xA989, 0xA98B, Consonant),
+    (0xA98F, 0xA9B2, Consonant),
+    (0xA9B3, 0xA9B3, ExtendInCB),
+    (0xA9B4, 0xA9B5, SpacingMark),
+    (0xA9B6, 0xA9B9, ExtendInCB),
+    (0xA9BA, 0xA9BB, SpacingMark),
+    (0xA9BC, 0xA9BD, ExtendInCB),
+    (0xA9BE, 0xA9BF, SpacingMark),
+    (0xA9C0, 0xA9C0, Linker),
+    (0xA9E0, 0xA9E4, Consonant),
+    (0xA9E5, 0xA9E5, ExtendInCB),
+    (0xA9E7, 0xA9EF, Consonant),
+    (0xA9FA, 0xA9FE, Consonant),
+    (0xAA29, 0xAA2E, ExtendInCB),
+    (0xAA2F, 0xAA30, SpacingMark),
+    (0xAA31, 0xAA32, ExtendInCB),
+    (0xAA33, 0xAA34, SpacingMark),
+    (0xAA35, 0xAA36, ExtendInCB),
+    (0xAA43, 0xAA43, ExtendInCB),
+    (0xAA4C, 0xAA4C, ExtendInCB),
+    (0xAA4D, 0xAA4D, SpacingMark),
+    (0xAA60, 0xAA6F, Consonant),
+    (0xAA71, 0xAA73, Consonant),
+    (0xAA7A, 0xAA7A, Consonant),
+    (0xAA7C, 0xAA7C, ExtendInCB),
+    (0xAA7E, 0xAA7F, Consonant),
+    (0xAAB0, 0xAAB0, ExtendInCB),
+    (0xAAB2, 0xAAB4, ExtendInCB),
+    (0xAAB7, 0xAAB8, ExtendInCB),
+    (0xAABE, 0xAABF, ExtendInCB),
+    (0xAAC1, 0xAAC1, ExtendInCB),
+    (0xAAE0, 0xAAEA, Consonant),
+    (0xAAEB, 0xAAEB, SpacingMark),
+    (0xAAEC, 0xAAED, ExtendInCB),
+    (0xAAEE, 0xAAEF, SpacingMark),
+    (0xAAF5, 0xAAF5, SpacingMark),
+    (0xAAF6, 0xAAF6, Linker),
+    (0xABC0, 0xABDA, Consonant),
+    (0xABE3, 0xABE4, SpacingMark),
+    (0xABE5, 0xABE5, ExtendInCB),
+    (0xABE6, 0xABE7, SpacingMark),
+    (0xABE8, 0xABE8, ExtendInCB),
+    (0xABE9, 0xABEA, SpacingMark),
+    (0xABEC, 0xABEC, SpacingMark),
+    (0xABED, 0xABED, ExtendInCB),
+    (0xD7B0, 0xD7C6, V),
+    (0xD7CB, 0xD7FB, T),
+    (0xFB1E, 0xFB1E, ExtendInCB),
+    (0xFE00, 0xFE0F, ExtendInCB),
+    (0xFE20, 0xFE2F, ExtendInCB),
+    (0xFEFF, 0xFEFF, Control),
+    (0xFF9E, 0xFF9F, ExtendInCB),
+    (0xFFF0, 0xFFFB, Control),
+    (0x101FD, 0x101FD, ExtendInCB),
+    (0x102E0, 0x102E0, ExtendInCB),
+    (0x10376, 0x1037A, ExtendInCB),
+    (0x10A00, 0x10A00, Consonant),
+    (0x10A01, 0x10A03, ExtendInCB),
+    (0x10A05, 0x10A06, ExtendInCB),
+    (0x10A0C, 0x10A0F, ExtendInCB),
+    (0x10A10, 0x10A13, Consonant),
+    (0x10A15, 0x10A17, Consonant),
+    (0x10A19, 0x10A35, Consonant),
+    (0x10A38, 0x10A3A, ExtendInCB),
+    (0x10A3F, 0x10A3F, Linker),
+    (0x10AE5, 0x10AE6, ExtendInCB),
+    (0x10D24, 0x10D27, ExtendInCB),
+    (0x10D69, 0x10D6D, ExtendInCB),
+    (0x10EAB, 0x10EAC, ExtendInCB),
+    (0x10EFA, 0x10EFF, ExtendInCB),
+    (0x10F46, 0x10F50, ExtendInCB),
+    (0x10F82, 0x10F85, ExtendInCB),
+    (0x11000, 0x11000, SpacingMark),
+    (0x11001, 0x11001, ExtendInCB),
+    (0x11002, 0x11002, SpacingMark),
+    (0x11038, 0x11046, ExtendInCB),
+    (0x11070, 0x11070, ExtendInCB),
+    (0x11073, 0x11074, ExtendInCB),
+    (0x1107F, 0x11081, ExtendInCB),
+    (0x11082, 0x11082, SpacingMark),
+    (0x110B0, 0x110B2, SpacingMark),
+    (0x110B3, 0x110B6, ExtendInCB),
+    (0x110B7, 0x110B8, SpacingMark),
+    (0x110B9, 0x110BA, ExtendInCB),
+    (0x110BD, 0x110BD, Prepend),
+    (0x110C2, 0x110C2, ExtendInCB),
+    (0x110CD, 0x110CD, Prepend),
+    (0x11100, 0x11102, ExtendInCB),
+    (0x11103, 0x11126, Consonant),
+    (0x11127, 0x1112B, ExtendInCB),
+    (0x1112C, 0x1112C, SpacingMark),
+    (0x1112D, 0x11132, ExtendInCB),
+    (0x11133, 0x11133, Linker),
+    (0x11134, 0x11134, ExtendInCB),
+    (0x11144, 0x11144, Consonant),
+    (0x11145, 0x11146, SpacingMark),
+    (0x11147, 0x11147, Consonant),
+    (0x11173, 0x11173, ExtendInCB),
+    (0x11180, 0x11181, ExtendInCB),
+    (0x11182, 0x11182, SpacingMark),
+    (0x111B3, 0x111B5, SpacingMark),
+    (0x111B6, 0x111BE, ExtendInCB),
+    (0x111BF, 0x111BF, SpacingMark),
+    (0x111C0, 0x111C0, ExtendInCB),
+    (0x111C2, 0x111C3, Prepend),
+    (0x111C9, 0x111CC, ExtendInCB),
+    (0x111CE, 0x111CE, SpacingMark),
+    (0x111CF, 0x111CF, ExtendInCB),
+    (0x1122C, 0x1122E, SpacingMark),
+    (0x1122F, 0x11231, ExtendInCB),
+    (0x11232, 0x11233, SpacingMark),
+    (0x11234, 0x11237, ExtendInCB),
+    (0x1123E, 0x1123E, ExtendInCB),
+    (0x11241, 0x11241, ExtendInCB),
+    (0x112DF, 0x112DF, ExtendInCB),
+    (0x112E0, 0x112E2, SpacingMark),
+    (0x112E3, 0x112EA, ExtendInCB),
+    (0x11300, 0x11301, ExtendInCB),
+    (0x11302, 0x11303, SpacingMark),
+    (0x1133B, 0x1133C, ExtendInCB),
+    (0x1133E, 0x1133E, ExtendInCB),
+    (0x1133F, 0x1133F, SpacingMark),
+    (0x11340, 0x11340, ExtendInCB),
+    (0x11341, 0x11344, SpacingMark),
+    (0x11347, 0x11348, SpacingMark),
+    (0x1134B, 0x1134C, SpacingMark),
+    (0x1134D, 0x1134D, ExtendInCB),
+    (0x11357, 0x11357, ExtendInCB),
+    (0x11362, 0x11363, SpacingMark),
+    (0x11366, 0x1136C, ExtendInCB),
+    (0x11370, 0x11374, ExtendInCB),
+    (0x11380, 0x11389, Consonant),
+    (0x1138B, 0x1138B, Consonant),
+    (0x1138E, 0x1138E, Consonant),
+    (0x11390, 0x113B5, Consonant),
+    (0x113B8, 0x113B8, ExtendInCB),
+    (0x113B9, 0x113BA, SpacingMark),
+    (0x113BB, 0x113C0, ExtendInCB),
+    (0x113C2, 0x113C2, ExtendInCB),
+    (0x113C5, 0x113C5, ExtendInCB),
+    (0x113C7, 0x113C9, ExtendInCB),
+    (0x113CA, 0x113CA, SpacingMark),
+    (0x113CC, 0x113CD, SpacingMark),
+    (0x113CE, 0x113CF, ExtendInCB),
+    (0x113D0, 0x113D0, Linker),
+    (0x113D1, 0x113D1, Prepend),
+    (0x113D2, 0x113D2, ExtendInCB),
+    (0x113E1, 0x113E2, ExtendInCB),
+    (0x11435, 0x11437, SpacingMark),
+    (0x11438, 0x1143F, ExtendInCB),
+    (0x11440, 0x11441, SpacingMark),
+    (0x11442, 0x11444, ExtendInCB),
+    (0x11445, 0x11445, SpacingMark),
+    (0x11446, 0x11446, ExtendInCB),
+    (0x1145E, 0x1145E, ExtendInCB),
+    (0x114B0, 0x114B0, ExtendInCB),
+    (0x114B1, 0x114B2, SpacingMark),
+    (0x114B3, 0x114B8, ExtendInCB),
+    (0x114B9, 0x114B9, SpacingMark),
+    (0x114BA, 0x114BA, ExtendInCB),
+    (0x114BB, 0x114BC, SpacingMark),
+    (0x114BD, 0x114BD, ExtendInCB),
+    (0x114BE, 0x114BE, SpacingMark),
+    (0x114BF, 0x114C0, ExtendInCB),
+    (0x114C1, 0x114C1, SpacingMark),
+    (0x114C2, 0x114C3, ExtendInCB),
+    (0x115AF, 0x115AF, ExtendInCB),
+    (0x115B0, 0x115B1, SpacingMark),
+    (0x115B2, 0x115B5, ExtendInCB),
+    (0x115B8, 0x115BB, SpacingMark),
+    (0x115BC, 0x115BD, ExtendInCB),
+    (0x115BE, 0x115BE, SpacingMark),
+    (0x115BF, 0x115C0, ExtendInCB),
+    (0x115DC, 0x115DD, ExtendInCB),
+    (0x11630, 0x11632, SpacingMark),
+    (0x11633, 0x1163A, ExtendInCB),
+    (0x1163B, 0x1163C, SpacingMark),
+    (0x1163D, 0x1163D, ExtendInCB),
+    (0x1163E, 0x1163E, SpacingMark),
+    (0x1163F, 0x11640, ExtendInCB),
+    (0x116AB, 0x116AB, ExtendInCB),
+    (0x116AC, 0x116AC, SpacingMark),
+    (0x116AD, 0x116AD, ExtendInCB),
+    (0x116AE, 0x116AF, SpacingMark),
+    (0x116B0, 0x116B7, ExtendInCB),
+    (0x1171D, 0x1171D, ExtendInCB),
+    (0x1171E, 0x1171E, SpacingMark),
+    (0x1171F, 0x1171F, ExtendInCB),
+    (0x11722, 0x11725, ExtendInCB),
+    (0x11726, 0x11726, SpacingMark),
+    (0x11727, 0x1172B, ExtendInCB),
+    (0x1182C, 0x1182E, SpacingMark),
+    (0x1182F, 0x11837, ExtendInCB),
+    (0x11838, 0x11838, SpacingMark),
+    (0x11839, 0x1183A, ExtendInCB),
+    (0x11900, 0x11906, Consonant),
+    (0x11909, 0x11909, Consonant),
+    (0x1190C, 0x11913, Consonant),
+    (0x11915, 0x11916, Consonant),
+    (0x11918, 0x1192F, Consonant),
+    (0x11930, 0x11930, ExtendInCB),
+    (0x11931, 0x11935, SpacingMark),
+    (0x11937, 0x11938, SpacingMark),
+    (0x1193B, 0x1193D, ExtendInCB),
+    (0x1193E, 0x1193E, Linker),
+    (0x1193F, 0x1193F, Prepend),
+    (0x11940, 0x11940, SpacingMark),
+    (0x11941, 0x11941, Prepend),
+    (0x11942, 0x11942, SpacingMark),
+    (0x11943, 0x11943, ExtendInCB),
+    (0x119D1, 0x119D3, SpacingMark),
+    (0x119D4, 0x119D7, ExtendInCB),
+    (0x119DA, 0x119DB, ExtendInCB),
+    (0x119DC, 0x119DF, SpacingMark),
+    (0x119E0, 0x119E0, ExtendInCB),
+    (0x119E4, 0x119E4, SpacingMark),
+    (0x11A00, 0x11A00, Consonant),
+    (0x11A01, 0x11A0A, ExtendInCB),
+    (0x11A0B, 0x11A32, Consonant),
+    (0x11A33, 0x11A38, ExtendInCB),
+    (0x11A39, 0x11A39, SpacingMark),
+    (0x11A3B, 0x11A3E, ExtendInCB),
+    (0x11A47, 0x11A47, Linker),
+    (0x11A50, 0x11A50, Consonant),
+    (0x11A51, 0x11A56, ExtendInCB),
+    (0x11A57, 0x11A58, SpacingMark),
+    (0x11A59, 0x11A5B, ExtendInCB),
+    (0x11A5C, 0x11A83, Consonant),
+    (0x11A84, 0x11A89, Prepend),
+    (0x11A8A, 0x11A96, ExtendInCB),
+    (0x11A97, 0x11A97, SpacingMark),
+    (0x11A98, 0x11A98, ExtendInCB),
+    (0x11A99, 0x11A99, Linker),
+    (0x11B60, 0x11B60, ExtendInCB),
+    (0x11B61, 0x11B61, SpacingMark),
+    (0x11B62, 0x11B64, ExtendInCB),
+    (0x11B65, 0x11B65, SpacingMark),
+    (0x11B66, 0x11B66, ExtendInCB),
+    (0x11B67, 0x11B67, SpacingMark),
+    (0x11C2F, 0x11C2F, SpacingMark),
+    (0x11C30, 0x11C36, ExtendInCB),
+    (0x11C38, 0x11C3D, ExtendInCB),
+    (0x11C3E, 0x11C3E, SpacingMark),
+    (0x11C3F, 0x11C3F, ExtendInCB),
+    (0x11C92, 0x11CA7, ExtendInCB),
+    (0x11CA9, 0x11CA9, SpacingMark),
+    (0x11CAA, 0x11CB0, ExtendInCB),
+    (0x11CB1, 0x11CB1, SpacingMark),
+    (0x11CB2, 0x11CB3, ExtendInCB),
+    (0x11CB4, 0x11CB4, SpacingMark),
+    (0x11CB5, 0x11CB6, ExtendInCB),
+    (0x11D31, 0x11D36, ExtendInCB),
+    (0x11D3A, 0x11D3A, ExtendInCB),
+    (0x11D3C, 0x11D3D, ExtendInCB),
+    (0x11D3F, 0x11D45, ExtendInCB),
+    (0x11D46, 0x11D46, Prepend),
+    (0x11D47, 0x11D47, ExtendInCB),
+    (0x11D8A, 0x11D8E, SpacingMark),
+    (0x11D90, 0x11D91, ExtendInCB),
+    (0x11D93, 0x11D94, SpacingMark),
+    (0x11D95, 0x11D95, ExtendInCB),
+    (0x11D96, 0x11D96, SpacingMark),
+    (0x11D97, 0x11D97, ExtendInCB),
+    (0x11EF3, 0x11EF4, ExtendInCB),
+    (0x11EF5, 0x11EF6, SpacingMark),
+    (0x11F00, 0x11F01, ExtendInCB),
+    (0x11F02, 0x11F02, Prepend),
+    (0x11F03, 0x11F03, SpacingMark),
+    (0x11F04, 0x11F10, Consonant),
+    (0x11F12, 0x11F33, Consonant),
+    (0x11F34, 0x11F35, SpacingMark),
+    (0x11F36, 0x11F3A, ExtendInCB),
+    (0x11F3E, 0x11F3F, SpacingMark),
+    (0x11F40, 0x11F41, ExtendInCB),
+    (0x11F42, 0x11F42, Linker),
+    (0x11F5A, 0x11F5A, ExtendInCB),
+    (0x13430, 0x1343F, Control),
+    (0x13440, 0x13440, ExtendInCB),
+    (0x13447, 0x13455, ExtendInCB),
+    (0x1611E, 0x16129, ExtendInCB),
+    (0x1612A, 0x1612C, SpacingMark),
+    (0x1612D, 0x1612F, ExtendInCB),
+    (0x16AF0, 0x16AF4, ExtendInCB),
+    (0x16B30, 0x16B36, ExtendInCB),
+    (0x16D63, 0x16D63, V),
+    (0x16D67, 0x16D6A, V),
+    (0x16F4F, 0x16F4F, ExtendInCB),
+    (0x16F51, 0x16F87, SpacingMark),
+    (0x16F8F, 0x16F92, ExtendInCB),
+    (0x16FE4, 0x16FE4, ExtendInCB),
+    (0x16FF0, 0x16FF1, ExtendInCB),
+    (0x1BC9D, 0x1BC9E, ExtendInCB),
+    (0x1BCA0, 0x1BCA3, Control),
+    (0x1CF00, 0x1CF2D, ExtendInCB),
+    (0x1CF30, 0x1CF46, ExtendInCB),
+    (0x1D165, 0x1D169, ExtendInCB),
+    (0x1D16D, 0x1D172, ExtendInCB),
+    (0x1D173, 0x1D17A, Control),
+    (0x1D17B, 0x1D182, ExtendInCB),
+    (0x1D185, 0x1D18B, ExtendInCB),
+    (0x1D1AA, 0x1D1AD, ExtendInCB),
+    (0x1D242, 0x1D244, ExtendInCB),
+    (0x1DA00, 0x1DA36, ExtendInCB),
+    (0x1DA3B, 0x1DA6C, ExtendInCB),
+    (0x1DA75, 0x1DA75, ExtendInCB),
+    (0x1DA84, 0x1DA84, ExtendInCB),
+    (0x1DA9B, 0x1DA9F, ExtendInCB),
+    (0x1DAA1, 0x1DAAF, ExtendInCB),
+    (0x1E000, 0x1E006, ExtendInCB),
+    (0x1E008, 0x1E018, ExtendInCB),
+    (0x1E01B, 0x1E021, ExtendInCB),
+    (0x1E023, 0x1E024, ExtendInCB),
+    (0x1E026, 0x1E02A, ExtendInCB),
+    (0x1E08F, 0x1E08F, ExtendInCB),
+    (0x1E130, 0x1E136, ExtendInCB),
+    (0x1E2AE, 0x1E2AE, ExtendInCB),
+    (0x1E2EC, 0x1E2EF, ExtendInCB),
+    (0x1E4EC, 0x1E4EF, ExtendInCB),
+    (0x1E5EE, 0x1E5EF, ExtendInCB),
+    (0x1E6E3, 0x1E6E3, ExtendInCB),
+    (0x1E6E6, 0x1E6E6, ExtendInCB),
+    (0x1E6EE, 0x1E6EF, ExtendInCB),
+    (0x1E6F5, 0x1E6F5, ExtendInCB),
+    (0x1E8D0, 0x1E8D6, ExtendInCB),
+    (0x1E944, 0x1E94A, ExtendInCB),
+    (0x1F004, 0x1F004, ExtPict),
+    (0x1F02C, 0x1F02F, ExtPict),
+    (0x1F094, 0x1F09F, ExtPict),
+    (0x1F0AF, 0x1F0B0, ExtPict),
+    (0x1F0C0, 0x1F0C0, ExtPict),
+    (0x1F0CF, 0x1F0D0, ExtPict),
+    (0x1F0F6, 0x1F0FF, ExtPict),
+    (0x1F170, 0x1F171, ExtPict),
+    (0x1F17E, 0x1F17F, ExtPict),
+    (0x1F18E, 0x1F18E, ExtPict),
+    (0x1F191, 0x1F19A, ExtPict),
+    (0x1F1AE, 0x1F1E5, ExtPict),
+    (0x1F1E6, 0x1F1FF, RegionalIndicator),
+    (0x1F201, 0x1F20F, ExtPict),
+    (0x1F21A, 0x1F21A, ExtPict),
+    (0x1F22F, 0x1F22F, ExtPict),
+    (0x1F232, 0x1F23A, ExtPict),
+    (0x1F23C, 0x1F23F, ExtPict),
+    (0x1F249, 0x1F25F, ExtPict),
+    (0x1F266, 0x1F321, ExtPict),
+    (0x1F324, 0x1F393, ExtPict),
+    (0x1F396, 0x1F397, ExtPict),
+    (0x1F399, 0x1F39B, ExtPict),
+    (0x1F39E, 0x1F3F0, ExtPict),
+    (0x1F3F3, 0x1F3F5, ExtPict),
+    (0x1F3F7, 0x1F3FA, ExtPict),
+    (0x1F3FB, 0x1F3FF, ExtendInCB),
+    (0x1F400, 0x1F4FD, ExtPict),
+    (0x1F4FF, 0x1F53D, ExtPict),
+    (0x1F549, 0x1F54E, ExtPict),
+    (0x1F550, 0x1F567, ExtPict),
+    (0x1F56F, 0x1F570, ExtPict),
+    (0x1F573, 0x1F57A, ExtPict),
+    (0x1F587, 0x1F587, ExtPict),
+    (0x1F58A, 0x1F58D, ExtPict),
+    (0x1F590, 0x1F590, ExtPict),
+    (0x1F595, 0x1F596, ExtPict),
+    (0x1F5A4, 0x1F5A5, ExtPict),
+    (0x1F5A8, 0x1F5A8, ExtPict),
+    (0x1F5B1, 0x1F5B2, ExtPict),
+    (0x1F5BC, 0x1F5BC, ExtPict),
+    (0x1F5C2, 0x1F5C4, ExtPict),
+    (0x1F5D1, 0x1F5D3, ExtPict),
+    (0x1F5DC, 0x1F5DE, ExtPict),
+    (0x1F5E1, 0x1F5E1, ExtPict),
+    (0x1F5E3, 0x1F5E3, ExtPict),
+    (0x1F5E8, 0x1F5E8, ExtPict),
+    (0x1F5EF, 0x1F5EF, ExtPict),
+    (0x1F5F3, 0x1F5F3, ExtPict),
+    (0x1F5FA, 0x1F64F, ExtPict),
+    (0x1F680, 0x1F6C5, ExtPict),
+    (0x1F6CB, 0x1F6D2, ExtPict),
+    (0x1F6D5, 0x1F6E5, ExtPict),
+    (0x1F6E9, 0x1F6E9, ExtPict),
+    (0x1F6EB, 0x1F6F0, ExtPict),
+    (0x1F6F3, 0x1F6FF, ExtPict),
+    (0x1F7DA, 0x1F7FF, ExtPict),
+    (0x1F80C, 0x1F80F, ExtPict),
+    (0x1F848, 0x1F84F, ExtPict),
+    (0x1F85A, 0x1F85F, ExtPict),
+    (0x1F888, 0x1F88F, ExtPict),
+    (0x1F8AE, 0x1F8AF, ExtPict),
+    (0x1F8BC, 0x1F8BF, ExtPict),
+    (0x1F8C2, 0x1F8CF, ExtPict),
+    (0x1F8D9, 0x1F8FF, ExtPict),
+    (0x1F90C, 0x1F93A, ExtPict),
+    (0x1F93C, 0x1F945, ExtPict),
+    (0x1F947, 0x1F9FF, ExtPict),
+    (0x1FA58, 0x1FA5F, ExtPict),
+    (0x1FA6E, 0x1FAFF, ExtPict),
+    (0x1FC00, 0x1FFFD, ExtPict),
+    (0xE0000, 0xE001F, Control),
+    (0xE0020, 0xE007F, ExtendInCB),
+    (0xE0080, 0xE00FF, Control),
+    (0xE0100, 0xE01EF, ExtendInCB),
+    (0xE01F0, 0xE0FFF, Control),
+];
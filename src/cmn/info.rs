@@ -8,6 +8,9 @@ pub struct CursorInfo<T, E: Extras<T> = NoneExtras<T>> {
     pub backwards: bool,
     pub pos: usize,
     pub extras: E,
+    /// the last pos the extras saw a move to,
+    /// [`STEPWISE`](Extras::STEPWISE) ones catch up from there after `noeffects` moves.
+    pub extras_pos: Option<usize>,
     pub noeffects: bool,
     _marker: PhantomData<T>,
 }
@@ -19,6 +22,7 @@ impl<T, E: Extras<T>> Default for CursorInfo<T, E> {
             backwards: false,
             pos: 0,
             extras: Extras::new(),
+            extras_pos: None,
             noeffects: false,
             _marker: PhantomData,
         }
@@ -32,6 +36,7 @@ impl<T, E: Extras<T>> Clone for CursorInfo<T, E> {
             backwards: self.backwards,
             pos: self.pos,
             extras: self.extras.clone(),
+            extras_pos: self.extras_pos,
             noeffects: false,
             _marker: PhantomData,
         }
@@ -49,6 +54,7 @@ impl<T, E: Extras<T>> CursorInfo<T, E> {
         self.backwards = false;
         self.pos = 0;
         self.extras.reset();
+        self.extras_pos = None;
         self.noeffects = false;
     }
 }
@@ -60,6 +66,9 @@ pub struct StrCursorInfo<E: Extras<char> = NoneExtras<char>> {
     pub inner: CursorInfo<u8, NoneExtras<u8>>,
     pub pos: usize,
     pub extras: E,
+    /// (pos, first byte) of the last char the extras saw a move to,
+    /// [`STEPWISE`](Extras::STEPWISE) ones catch up from there after `noeffects` moves.
+    pub extras_pos: Option<(usize, usize)>,
     pub char_start_pos: usize,
     pub current: char,
     pub noeffects: bool,
@@ -71,6 +80,7 @@ impl<E: Extras<char>> Default for StrCursorInfo<E> {
             inner: CursorInfo::default(),
             pos: 0,
             extras: Extras::new(),
+            extras_pos: None,
             char_start_pos: 0,
             current: EOF_CHAR,
             noeffects: false,
@@ -84,6 +94,7 @@ impl<E: Extras<char>> Clone for StrCursorInfo<E> {
             inner: self.inner.clone(),
             pos: self.pos,
             extras: self.extras.clone(),
+            extras_pos: self.extras_pos,
             char_start_pos: self.char_start_pos,
             current: self.current,
             noeffects: self.noeffects,
@@ -101,8 +112,153 @@ impl<E: Extras<char>> StrCursorInfo<E> {
         self.inner.reset();
         self.pos = 0;
         self.extras.reset();
+        self.extras_pos = None;
         self.char_start_pos = 0;
         self.current = EOF_CHAR;
         self.noeffects = false;
     }
 }
+
+/// where a [`TextCursor`](TextCursor) is, in chars and in units.
+#[derive(PartialEq, Eq)]
+pub struct TextCursorInfo<E: Extras<char> = NoneExtras<char>> {
+    pub pos: usize,
+    /// units of the current char, empty before the first one.
+    pub unit_start: usize,
+    pub unit_end: usize,
+    pub backwards: bool,
+    pub extras: E,
+    /// (pos, first unit) of the last char the extras saw a move to,
+    /// [`STEPWISE`](Extras::STEPWISE) ones catch up from there after `noeffects` moves.
+    pub extras_pos: Option<(usize, usize)>,
+    pub current: char,
+    pub noeffects: bool,
+}
+impl<E: Extras<char>> Default for TextCursorInfo<E> {
+    #[inline]
+    fn default() -> Self {
+        TextCursorInfo {
+            pos: 0,
+            unit_start: 0,
+            unit_end: 0,
+            backwards: false,
+            extras: Extras::new(),
+            extras_pos: None,
+            current: EOF_CHAR,
+            noeffects: false,
+        }
+    }
+}
+impl<E: Extras<char>> Clone for TextCursorInfo<E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        TextCursorInfo {
+            pos: self.pos,
+            unit_start: self.unit_start,
+            unit_end: self.unit_end,
+            backwards: self.backwards,
+            extras: self.extras.clone(),
+            extras_pos: self.extras_pos,
+            current: self.current,
+            noeffects: self.noeffects,
+        }
+    }
+}
+
+impl<E: Extras<char>> TextCursorInfo<E> {
+    #[inline]
+    pub fn new() -> Self {
+        TextCursorInfo::default()
+    }
+    #[inline]
+    pub fn reset(&mut self) {
+        self.pos = 0;
+        self.unit_start = 0;
+        self.unit_end = 0;
+        self.backwards = false;
+        self.extras.reset();
+        self.extras_pos = None;
+        self.current = EOF_CHAR;
+        self.noeffects = false;
+    }
+}
+
+/// what `saved()` and the checkpoints of a [`TextCursorTrait`](TextCursorTrait) tell.
+pub trait CharInfo<E: Extras<char>> {
+    fn pos(&self) -> usize;
+    fn extras(&self) -> &E;
+    /// the char it was on, empty before the first one.
+    fn span(&self) -> Span;
+}
+impl<E: Extras<char>> CharInfo<E> for StrCursorInfo<E> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+    #[inline]
+    fn extras(&self) -> &E {
+        &self.extras
+    }
+    #[inline]
+    fn span(&self) -> Span {
+        char_span(
+            self.pos,
+            self.inner.pos,
+            self.char_start_pos,
+            self.current != EOF_CHAR,
+        )
+    }
+}
+impl<E: Extras<char>> CharInfo<E> for TextCursorInfo<E> {
+    #[inline]
+    fn pos(&self) -> usize {
+        self.pos
+    }
+    #[inline]
+    fn extras(&self) -> &E {
+        &self.extras
+    }
+    #[inline]
+    fn span(&self) -> Span {
+        if self.unit_end <= self.unit_start {
+            return Span::empty_at(self.pos, self.unit_start);
+        }
+        Span::new(self.pos..self.pos + 1, self.unit_start..self.unit_end)
+    }
+}
+
+/// the char at `pos`, its bytes between the two ends.
+#[inline]
+pub(crate) fn char_span(pos: usize, byte_pos: usize, char_start_pos: usize, init: bool) -> Span {
+    if !init {
+        return Span::empty_at(pos, byte_pos.min(char_start_pos));
+    }
+    let bytes = byte_pos.min(char_start_pos)..byte_pos.max(char_start_pos) + 1;
+    Span::new(pos..pos + 1, bytes)
+}
+
+// ------ checkpoint ------
+
+/// a snapshot taken by `push_checkpoint()`.
+/// * hand it back to `restore` to rewind, or to `commit` to keep the moves.
+/// * it's a plain value, no stack behind it, so any number of them can be held
+///   and restored in any order, `commit` only drops it.
+#[must_use]
+#[derive(PartialEq, Eq, Clone)]
+pub struct Checkpoint<Info>(Info);
+
+impl<Info> Checkpoint<Info> {
+    #[inline]
+    pub fn new(info: Info) -> Self {
+        Checkpoint(info)
+    }
+    /// the state at the checkpoint.
+    #[inline]
+    pub fn info(&self) -> &Info {
+        &self.0
+    }
+    #[inline]
+    pub fn into_info(self) -> Info {
+        self.0
+    }
+}
@@ -0,0 +1,235 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+use alloc::{sync::Arc, vec, vec::Vec};
+
+/// what [`LineColExtras`](LineColExtras) can count.
+pub trait LineColItem: Copy {
+    /// utf-8 bytes of the item.
+    fn byte_len(self) -> usize;
+    /// if the item is the first one of a char.
+    fn starts_char(self) -> bool;
+    fn is_lf(self) -> bool;
+    fn is_cr(self) -> bool;
+}
+impl LineColItem for char {
+    #[inline]
+    fn byte_len(self) -> usize {
+        self.len_utf8()
+    }
+    #[inline]
+    fn starts_char(self) -> bool {
+        true
+    }
+    #[inline]
+    fn is_lf(self) -> bool {
+        self == '\n'
+    }
+    #[inline]
+    fn is_cr(self) -> bool {
+        self == '\r'
+    }
+}
+impl LineColItem for u8 {
+    #[inline]
+    fn byte_len(self) -> usize {
+        1
+    }
+    #[inline]
+    fn starts_char(self) -> bool {
+        !utf::utf8_is_cont_byte(self)
+    }
+    #[inline]
+    fn is_lf(self) -> bool {
+        self == b'\n'
+    }
+    #[inline]
+    fn is_cr(self) -> bool {
+        self == b'\r'
+    }
+}
+
+/// line, column, byte offset and char offset of the current item.
+/// * `\n`, `\r\n` and a lone `\r` end a line,
+/// * all zero-based, columns count chars,
+/// * tracked in both directions and across jumps, it's [`STEPWISE`](Extras::STEPWISE),
+/// * so it has to see every move, the cursors catch it up after `noeffects` moves,
+///   the stream ones over what they still keep,
+/// * `LineColExtras<u8>` for the byte cursors.
+pub struct LineColExtras<T: LineColItem = char> {
+    line: usize,
+    column: usize,
+    byte_offset: usize,
+    char_offset: usize,
+    /// the last reported pos and item.
+    pos: usize,
+    current: Option<T>,
+    /// (char offset, byte offset) of every line start found so far,
+    /// shared with the clones until one of them finds a new line.
+    line_starts: Arc<Vec<(usize, usize)>>,
+}
+
+impl<T: LineColItem> LineColExtras<T> {
+    #[inline]
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    #[inline]
+    pub fn column(&self) -> usize {
+        self.column
+    }
+    /// (line, column)
+    #[inline]
+    pub fn line_col(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+    #[inline]
+    pub fn char_offset(&self) -> usize {
+        self.char_offset
+    }
+
+    // ------ private ------
+
+    #[inline]
+    fn step_forward(&mut self, prev: T, item: T) {
+        self.byte_offset += prev.byte_len();
+        self.char_offset += item.starts_char() as usize;
+        if prev.is_lf() || (prev.is_cr() && !item.is_lf()) {
+            self.line += 1;
+            if self.line_starts.len() == self.line {
+                Arc::make_mut(&mut self.line_starts).push((self.char_offset, self.byte_offset));
+            }
+        }
+        self.set_column();
+    }
+    #[inline]
+    fn step_backward(&mut self, prev: T, item: T) {
+        let prev_byte_offset = self.byte_offset;
+        self.byte_offset -= item.byte_len();
+        self.char_offset -= prev.starts_char() as usize;
+        if self.line > 0 && self.line_starts[self.line].1 == prev_byte_offset {
+            self.line -= 1;
+        }
+        self.set_column();
+    }
+    #[inline]
+    fn set_column(&mut self) {
+        self.column = self.char_offset - self.line_starts[self.line].0;
+    }
+}
+
+impl<T: LineColItem> Extras<T> for LineColExtras<T> {
+    const STEPWISE: bool = true;
+
+    #[inline]
+    fn new() -> Self {
+        LineColExtras {
+            line: 0,
+            column: 0,
+            byte_offset: 0,
+            char_offset: 0,
+            pos: 0,
+            current: None,
+            line_starts: Arc::new(vec![(0, 0)]),
+        }
+    }
+    #[inline]
+    fn clone(&self) -> Self {
+        LineColExtras {
+            current: self.current,
+            line_starts: Arc::clone(&self.line_starts),
+            ..*self
+        }
+    }
+    #[inline]
+    fn change(&mut self, input: &T, pos: usize) {
+        let item = *input;
+        match self.current {
+            Some(prev) if pos == self.pos + 1 => self.step_forward(prev, item),
+            Some(prev) if pos + 1 == self.pos => self.step_backward(prev, item),
+            Some(_) if pos == self.pos => {}
+            current => debug_assert!(
+                current.is_none() && pos == 0,
+                "`LineColExtras` missed the moves from {} to {}",
+                self.pos,
+                pos
+            ),
+        }
+        self.pos = pos;
+        self.current = Some(item);
+    }
+    /// the line starts found so far are kept.
+    #[inline]
+    fn reset(&mut self) {
+        let line_starts = Arc::clone(&self.line_starts);
+        *self = Self::new();
+        self.line_starts = line_starts;
+    }
+}
+
+/// same with [`Extras::new`](Extras::new).
+impl<T: LineColItem> Default for LineColExtras<T> {
+    #[inline]
+    fn default() -> Self {
+        Extras::new()
+    }
+}
+/// same with [`Extras::clone`](Extras::clone), the line starts are shared until either finds a new one.
+impl<T: LineColItem> Clone for LineColExtras<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Extras::clone(self)
+    }
+}
+
+impl<T: LineColItem> PartialEq for LineColExtras<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        (self.line, self.column, self.byte_offset, self.char_offset)
+            == (
+                other.line,
+                other.column,
+                other.byte_offset,
+                other.char_offset,
+            )
+    }
+}
+impl<T: LineColItem> Eq for LineColExtras<T> {}
+
+impl<T: LineColItem> fmt::Debug for LineColExtras<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LineColExtras")
+            .field("line", &self.line)
+            .field("column", &self.column)
+            .field("byte_offset", &self.byte_offset)
+            .field("char_offset", &self.char_offset)
+            .finish()
+    }
+}
+
+impl SpanExtras for LineColExtras<char> {
+    #[inline]
+    fn span(&self) -> Span {
+        let (pos, byte_pos) = (self.char_offset, self.byte_offset);
+        match self.current {
+            Some(ch) => Span::new(pos..pos + 1, byte_pos..byte_pos + ch.len_utf8()),
+            None => Span::empty_at(pos, byte_pos),
+        }
+    }
+}
+impl SpanExtras for LineColExtras<u8> {
+    /// in bytes, like the items of the byte cursors.
+    #[inline]
+    fn span(&self) -> Span {
+        let pos = self.byte_offset;
+        match self.current {
+            Some(_) => Span::of_items(pos..pos + 1),
+            None => Span::empty_at(pos, pos),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+use alloc::{sync::Arc, vec};
+
+/// line starts of a `&str`, built once, then every conversion is a binary search.
+/// * `\n`, `\r\n` and a lone `\r` end a line, like [`LineColExtras`](LineColExtras),
+/// * all zero-based, columns count chars,
+/// * a column is found in O(1) on an ascii line, by walking the line otherwise,
+/// * the clones share the line starts.
+///
+/// ```
+/// use cursor::*;
+///
+/// let index = LineIndex::new("ab\r\n안녕\nc");
+/// assert_eq!(index.line_count(), 3);
+/// assert_eq!(index.line_col(7), Some((1, 1)));
+/// assert_eq!(index.offset(1, 1), Some(7));
+/// assert_eq!(index.line_range(0), Some(0..2));
+/// assert_eq!(index.line_str(1), Some("안녕"));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LineIndex<'s> {
+    source: &'s str,
+    /// (byte, char) of every line start, and of the end.
+    starts: Arc<[(usize, usize)]>,
+}
+
+impl<'s> LineIndex<'s> {
+    pub fn new(source: &'s str) -> Self {
+        let bytes = source.as_bytes();
+        let mut starts = vec![(0, 0)];
+        let mut chars = 0;
+        for (i, &byte) in bytes.iter().enumerate() {
+            chars += !utf::utf8_is_cont_byte(byte) as usize;
+            if byte == b'\n' || (byte == b'\r' && bytes.get(i + 1) != Some(&b'\n')) {
+                starts.push((i + 1, chars));
+            }
+        }
+        starts.push((bytes.len(), chars));
+        LineIndex {
+            source,
+            starts: starts.into(),
+        }
+    }
+
+    /// the line starts alone, kept by [`OwnedStrCursor`](OwnedStrCursor) between lends.
+    #[inline]
+    pub(crate) fn detach(self) -> LineIndex<'static> {
+        LineIndex {
+            source: "",
+            starts: self.starts,
+        }
+    }
+    /// `source` has to be the one it was built from.
+    #[inline]
+    pub(crate) fn attach(self, source: &str) -> LineIndex<'_> {
+        LineIndex {
+            source,
+            starts: self.starts,
+        }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &'s str {
+        self.source
+    }
+    /// at least one, an empty source has one empty line.
+    #[inline]
+    pub fn line_count(&self) -> usize {
+        self.starts.len() - 1
+    }
+    /// number of chars in the source.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.starts[self.line_count()].1
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.source.is_empty()
+    }
+
+    /// the line containing the byte `byte_pos`, `None` past the end.
+    #[inline]
+    pub fn line_of(&self, byte_pos: usize) -> Option<usize> {
+        if byte_pos > self.source.len() {
+            return None;
+        }
+        let line = self.starts.partition_point(|&(byte, _)| byte <= byte_pos) - 1;
+        Some(line.min(self.line_count() - 1))
+    }
+    /// the line containing the char `pos`, `None` past the end.
+    #[inline]
+    pub fn line_of_char(&self, pos: usize) -> Option<usize> {
+        if pos > self.len() {
+            return None;
+        }
+        let line = self.starts.partition_point(|&(_, ch)| ch <= pos) - 1;
+        Some(line.min(self.line_count() - 1))
+    }
+    /// (line, column) of the byte `byte_pos`, the end of the source included.
+    /// * a byte inside of a char is in that char's column.
+    #[inline]
+    pub fn line_col(&self, byte_pos: usize) -> Option<(usize, usize)> {
+        let line = self.line_of(byte_pos)?;
+        let (start, _) = self.starts[line];
+        let column = if self.is_ascii_line(line) {
+            byte_pos - start
+        } else {
+            let mut end = byte_pos;
+            while !self.source.is_char_boundary(end) {
+                end -= 1;
+            }
+            utf::count_chars(&self.source.as_bytes()[start..end])
+        };
+        Some((line, column))
+    }
+    /// (line, column) of the char `pos`, the end of the source included.
+    #[inline]
+    pub fn line_col_of_char(&self, pos: usize) -> Option<(usize, usize)> {
+        let line = self.line_of_char(pos)?;
+        Some((line, pos - self.starts[line].1))
+    }
+    /// byte offset of (line, column).
+    /// * the column can be the line ending, or the end of the last line.
+    #[inline]
+    pub fn offset(&self, line: usize, column: usize) -> Option<usize> {
+        let (start, _) = *self.starts.get(line)?;
+        let (end, _) = *self.starts.get(line + 1)?;
+        if column > self.max_column(line) {
+            return None;
+        }
+        if self.is_ascii_line(line) {
+            return Some(start + column);
+        }
+        match self.source[start..end].char_indices().nth(column) {
+            Some((byte, _)) => Some(start + byte),
+            None => Some(end),
+        }
+    }
+    /// char position of (line, column), like [`offset`](LineIndex::offset).
+    #[inline]
+    pub fn char_pos(&self, line: usize, column: usize) -> Option<usize> {
+        let (_, start_char) = *self.starts.get(line)?;
+        (line < self.line_count() && column <= self.max_column(line)).then(|| start_char + column)
+    }
+    /// bytes of the line, without its line ending.
+    #[inline]
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let (start, _) = *self.starts.get(line)?;
+        let (end, _) = *self.starts.get(line + 1)?;
+        let text = self.source[start..end].trim_end_matches(['\n', '\r']);
+        Some(start..start + text.len())
+    }
+    /// the line, without its line ending.
+    #[inline]
+    pub fn line_str(&self, line: usize) -> Option<&'s str> {
+        self.line_range(line).map(|range| &self.source[range])
+    }
+
+    // ------ private ------
+
+    /// the last char of the line ending, or the end of the last line.
+    #[inline]
+    fn max_column(&self, line: usize) -> usize {
+        let chars = self.starts[line + 1].1 - self.starts[line].1;
+        chars - (line + 1 < self.line_count()) as usize
+    }
+
+    #[inline]
+    fn is_ascii_line(&self, line: usize) -> bool {
+        let (start, start_char) = self.starts[line];
+        let (end, end_char) = self.starts[line + 1];
+        end - start == end_char - start_char
+    }
+}
@@ -2,19 +2,55 @@
 
 use super::*;
 
+#[cfg(feature = "alloc")]
+mod charindex;
+#[cfg(feature = "alloc")]
+pub use charindex::*;
+
 mod consts;
 pub use consts::*;
 
+mod decoder;
+pub use decoder::*;
+
+mod error;
+pub use error::*;
+
 mod extras;
 pub use extras::*;
 
 mod info;
 pub use info::*;
 
+#[cfg(feature = "alloc")]
+mod linecol;
+#[cfg(feature = "alloc")]
+pub use linecol::*;
+
+#[cfg(feature = "alloc")]
+mod lineindex;
+#[cfg(feature = "alloc")]
+pub use lineindex::*;
+
 mod offset;
 pub use offset::*;
 
+mod pattern;
+pub use pattern::*;
+
+mod span;
+pub use span::*;
+
 mod utils;
 pub use utils::*;
 
 pub mod utf;
+
+pub mod grapheme;
+
+pub mod words;
+pub use words::WordMode;
+
+pub mod sentences;
+
+pub mod search;
@@ -0,0 +1,279 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// what the cursors match against the items ahead of them.
+/// * `H` is `str` for [`StrCursorTrait`](crate::StrCursorTrait),
+/// * `[T]` for [`CursorTrait`](crate::CursorTrait),
+/// * [`Text`](Text) for [`TextCursor`](crate::TextCursor), the `Text` of any [`TextCursorTrait`](crate::TextCursorTrait).
+/// * returns the number of matched items (chars or elements), not bytes.
+pub trait Pattern<H: ?Sized> {
+    /// matches at the start of `haystack`, for heading right.
+    fn match_prefix(&mut self, haystack: &H) -> Option<usize>;
+    /// matches at the end of `haystack`, for heading left.
+    fn match_suffix(&mut self, haystack: &H) -> Option<usize>;
+}
+
+/// a set of bytes, `contains` is one lookup.
+/// * on a `str`, matches the ascii chars in it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ByteSet([u64; 4]);
+impl ByteSet {
+    #[inline]
+    pub const fn new(bytes: &[u8]) -> Self {
+        let mut set = ByteSet([0; 4]);
+        let mut i = 0;
+        while i < bytes.len() {
+            set = set.with(bytes[i]);
+            i += 1;
+        }
+        set
+    }
+    #[inline]
+    pub const fn with(mut self, byte: u8) -> Self {
+        self.0[(byte >> 6) as usize] |= 1 << (byte & 63);
+        self
+    }
+    #[inline]
+    pub fn insert(&mut self, byte: u8) {
+        *self = self.with(byte);
+    }
+    #[inline]
+    pub const fn contains(&self, byte: u8) -> bool {
+        self.0[(byte >> 6) as usize] & (1 << (byte & 63)) != 0
+    }
+}
+impl From<&[u8]> for ByteSet {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        ByteSet::new(bytes)
+    }
+}
+
+#[inline]
+fn first_char_if<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next().filter(|&c| f(c)).map(|_| 1)
+}
+#[inline]
+fn last_char_if<F: FnMut(char) -> bool>(haystack: &str, mut f: F) -> Option<usize> {
+    haystack.chars().next_back().filter(|&c| f(c)).map(|_| 1)
+}
+#[inline]
+fn first_decoded_if<D: Decoder, F: FnMut(char) -> bool>(
+    haystack: &Text<D>,
+    mut f: F,
+) -> Option<usize> {
+    haystack.chars().next().filter(|&c| f(c)).map(|_| 1)
+}
+#[inline]
+fn last_decoded_if<D: Decoder, F: FnMut(char) -> bool>(
+    haystack: &Text<D>,
+    mut f: F,
+) -> Option<usize> {
+    haystack.chars().next_back().filter(|&c| f(c)).map(|_| 1)
+}
+#[inline]
+fn first_if<T, F: FnMut(&T) -> bool>(haystack: &[T], mut f: F) -> Option<usize> {
+    haystack.first().filter(|item| f(item)).map(|_| 1)
+}
+#[inline]
+fn last_if<T, F: FnMut(&T) -> bool>(haystack: &[T], mut f: F) -> Option<usize> {
+    haystack.last().filter(|item| f(item)).map(|_| 1)
+}
+
+// ------ str ------
+
+macro_rules! impl_char_pattern {
+    ($( [$($gen:tt)*] $ty:ty => |$this:ident, $c:ident| $is:expr; )*) => {$(
+        impl<$($gen)*> Pattern<str> for $ty {
+            #[inline]
+            fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+                let $this = self;
+                first_char_if(haystack, |$c| $is)
+            }
+            #[inline]
+            fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+                let $this = self;
+                last_char_if(haystack, |$c| $is)
+            }
+        }
+        impl<D: Decoder, $($gen)*> Pattern<Text<D>> for $ty {
+            #[inline]
+            fn match_prefix(&mut self, haystack: &Text<D>) -> Option<usize> {
+                let $this = self;
+                first_decoded_if(haystack, |$c| $is)
+            }
+            #[inline]
+            fn match_suffix(&mut self, haystack: &Text<D>) -> Option<usize> {
+                let $this = self;
+                last_decoded_if(haystack, |$c| $is)
+            }
+        }
+    )*};
+}
+impl_char_pattern! {
+    [] char => |this, c| c == *this;
+    [] &[char] => |this, c| this.contains(&c);
+    [const N: usize] [char; N] => |this, c| this.contains(&c);
+    [] Range<char> => |this, c| this.contains(&c);
+    [] RangeInclusive<char> => |this, c| this.contains(&c);
+    [] ByteSet => |this, c| c.is_ascii() && this.contains(c as u8);
+    [F: FnMut(char) -> bool] F => |this, c| this(c);
+}
+
+impl Pattern<str> for &str {
+    #[inline]
+    fn match_prefix(&mut self, haystack: &str) -> Option<usize> {
+        wrap(self.chars().count(), || haystack.starts_with(*self))
+    }
+    #[inline]
+    fn match_suffix(&mut self, haystack: &str) -> Option<usize> {
+        wrap(self.chars().count(), || haystack.ends_with(*self))
+    }
+}
+
+/// the chars of the `str`.
+impl<D: Decoder> Pattern<Text<D>> for &str {
+    #[inline]
+    fn match_prefix(&mut self, haystack: &Text<D>) -> Option<usize> {
+        let mut chars = haystack.chars();
+        wrap(self.chars().count(), || {
+            self.chars().all(|c| chars.next() == Some(c))
+        })
+    }
+    #[inline]
+    fn match_suffix(&mut self, haystack: &Text<D>) -> Option<usize> {
+        let mut chars = haystack.chars().rev();
+        wrap(self.chars().count(), || {
+            self.chars().rev().all(|c| chars.next() == Some(c))
+        })
+    }
+}
+
+// ------ slice ------
+
+macro_rules! impl_item_pattern {
+    ($( [$($gen:tt)*] $ty:ty, $item:ty => |$this:ident, $x:ident| $is:expr; )*) => {$(
+        impl<$($gen)*> Pattern<[$item]> for $ty {
+            #[inline]
+            fn match_prefix(&mut self, haystack: &[$item]) -> Option<usize> {
+                let $this = self;
+                first_if(haystack, |$x| $is)
+            }
+            #[inline]
+            fn match_suffix(&mut self, haystack: &[$item]) -> Option<usize> {
+                let $this = self;
+                last_if(haystack, |$x| $is)
+            }
+        }
+    )*};
+}
+impl_item_pattern! {
+    [] u8, u8 => |this, x| x == this;
+    [] ByteSet, u8 => |this, x| this.contains(*x);
+    [T: PartialOrd] Range<T>, T => |this, x| this.contains(x);
+    [T: PartialOrd] RangeInclusive<T>, T => |this, x| this.contains(x);
+    [T, F: FnMut(&T) -> bool] F, T => |this, x| this(x);
+}
+
+impl<T: PartialEq> Pattern<[T]> for &[T] {
+    #[inline]
+    fn match_prefix(&mut self, haystack: &[T]) -> Option<usize> {
+        wrap(self.len(), || haystack.starts_with(self))
+    }
+    #[inline]
+    fn match_suffix(&mut self, haystack: &[T]) -> Option<usize> {
+        wrap(self.len(), || haystack.ends_with(self))
+    }
+}
+impl<T: PartialEq, const N: usize> Pattern<[T]> for &[T; N] {
+    #[inline]
+    fn match_prefix(&mut self, haystack: &[T]) -> Option<usize> {
+        self.as_slice().match_prefix(haystack)
+    }
+    #[inline]
+    fn match_suffix(&mut self, haystack: &[T]) -> Option<usize> {
+        self.as_slice().match_suffix(haystack)
+    }
+}
+/// the bytes of the `str`.
+impl Pattern<[u8]> for &str {
+    #[inline]
+    fn match_prefix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().match_prefix(haystack)
+    }
+    #[inline]
+    fn match_suffix(&mut self, haystack: &[u8]) -> Option<usize> {
+        self.as_bytes().match_suffix(haystack)
+    }
+}
+
+// ------ search ------
+
+/// (items before the match, matched items) in `haystack`,
+/// the nearest from the start if heading `right`, from the end otherwise.
+#[inline]
+pub fn find_pattern_in_str<P: Pattern<str> + ?Sized>(
+    haystack: &str,
+    right: bool,
+    pattern: &mut P,
+) -> Option<(usize, usize)> {
+    if right {
+        let starts = haystack.char_indices().map(|(i, _)| i);
+        starts
+            .chain(Some(haystack.len()))
+            .enumerate()
+            .find_map(|(before, i)| Some((before, pattern.match_prefix(&haystack[i..])?)))
+    } else {
+        let ends = haystack.char_indices().rev().map(|(i, _)| i);
+        Some(haystack.len())
+            .into_iter()
+            .chain(ends)
+            .enumerate()
+            .find_map(|(before, i)| Some((before, pattern.match_suffix(&haystack[..i])?)))
+    }
+}
+/// same with [`find_pattern_in_str`](find_pattern_in_str) over a slice.
+#[inline]
+pub fn find_pattern_in_slice<T, P: Pattern<[T]> + ?Sized>(
+    haystack: &[T],
+    right: bool,
+    pattern: &mut P,
+) -> Option<(usize, usize)> {
+    let len = haystack.len();
+    (0..=len).find_map(|before| {
+        let matched = if right {
+            pattern.match_prefix(&haystack[before..])
+        } else {
+            pattern.match_suffix(&haystack[..len - before])
+        };
+        Some((before, matched?))
+    })
+}
+/// same with [`find_pattern_in_str`](find_pattern_in_str) over any [`Haystack`](Haystack),
+/// a [`Text`](Text) or a `str`.
+#[inline]
+pub fn find_pattern_in_text<H: Haystack + ?Sized, P: Pattern<H> + ?Sized>(
+    haystack: &H,
+    right: bool,
+    pattern: &mut P,
+) -> Option<(usize, usize)> {
+    let len = haystack.as_units().len();
+    let (mut at, mut before) = (if right { 0 } else { len }, 0);
+    loop {
+        let matched = if right {
+            pattern.match_prefix(haystack.slice(at..len))
+        } else {
+            pattern.match_suffix(haystack.slice(0..at))
+        };
+        if let Some(matched) = matched {
+            return Some((before, matched));
+        }
+        (_, at) = if right {
+            haystack.char_at(at)?
+        } else {
+            haystack.char_before(at)?
+        };
+        before += 1;
+    }
+}
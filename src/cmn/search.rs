@@ -0,0 +1,151 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! Two-Way string matching (Crochemore-Perrin),
+//! linear time and constant space, over any `Ord` items.
+
+use super::*;
+
+/// read-only view of the items, forwards or reversed.
+trait Items<T> {
+    fn len(&self) -> usize;
+    fn at(&self, i: usize) -> &T;
+}
+impl<T> Items<T> for [T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+    #[inline]
+    fn at(&self, i: usize) -> &T {
+        &self[i]
+    }
+}
+struct Rev<'a, T>(&'a [T]);
+impl<T> Items<T> for Rev<'_, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+    #[inline]
+    fn at(&self, i: usize) -> &T {
+        &self.0[self.0.len() - 1 - i]
+    }
+}
+
+/// (start of the maximal suffix - 1, its period).
+#[inline]
+fn maximal_suffix<T: Ord, N: Items<T> + ?Sized>(needle: &N, reversed: bool) -> (isize, usize) {
+    let n = needle.len();
+    let (mut ms, mut j, mut k, mut p) = (-1isize, 0usize, 1usize, 1usize);
+    while j + k < n {
+        let a = needle.at(j + k);
+        let b = needle.at((ms + k as isize) as usize);
+        let ord = if reversed { b.cmp(a) } else { a.cmp(b) };
+        match ord {
+            Ordering::Less => {
+                j += k;
+                k = 1;
+                p = (j as isize - ms) as usize;
+            }
+            Ordering::Equal => {
+                if k != p {
+                    k += 1;
+                } else {
+                    j += p;
+                    k = 1;
+                }
+            }
+            Ordering::Greater => {
+                ms = j as isize;
+                j = ms as usize + 1;
+                k = 1;
+                p = 1;
+            }
+        }
+    }
+    (ms, p)
+}
+
+#[inline]
+fn two_way<T, H, N>(haystack: &H, needle: &N) -> Option<usize>
+where
+    T: Ord,
+    H: Items<T> + ?Sized,
+    N: Items<T> + ?Sized,
+{
+    let (n, m) = (haystack.len(), needle.len());
+    if m == 0 {
+        return Some(0);
+    }
+    if m > n {
+        return None;
+    }
+
+    // critical factorization
+    let (ms1, p1) = maximal_suffix(needle, false);
+    let (ms2, p2) = maximal_suffix(needle, true);
+    let (ell, mut per) = if ms1 > ms2 { (ms1, p1) } else { (ms2, p2) };
+
+    let eq = |i: usize, j: usize| needle.at(i) == haystack.at(i + j);
+    let periodic = per as isize + ell < m as isize
+        && (0..=ell).all(|i| needle.at(i as usize) == needle.at(i as usize + per));
+
+    let mut j = 0;
+    if periodic {
+        let mut memory = -1isize;
+        while j <= n - m {
+            let mut i = (ell.max(memory) + 1) as usize;
+            while i < m && eq(i, j) {
+                i += 1;
+            }
+            if i >= m {
+                let mut i = ell;
+                while i > memory && eq(i as usize, j) {
+                    i -= 1;
+                }
+                if i <= memory {
+                    return Some(j);
+                }
+                j += per;
+                memory = (m - per) as isize - 1;
+            } else {
+                j += (i as isize - ell) as usize;
+                memory = -1;
+            }
+        }
+    } else {
+        per = (ell + 1).max(m as isize - ell - 1) as usize + 1;
+        while j <= n - m {
+            let mut i = (ell + 1) as usize;
+            while i < m && eq(i, j) {
+                i += 1;
+            }
+            if i >= m {
+                let mut i = ell;
+                while i >= 0 && eq(i as usize, j) {
+                    i -= 1;
+                }
+                if i < 0 {
+                    return Some(j);
+                }
+                j += per;
+            } else {
+                j += (i as isize - ell) as usize;
+            }
+        }
+    }
+    None
+}
+
+/// index of the first `needle` in `haystack`.
+#[inline]
+pub fn find<T: Ord>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    two_way(haystack, needle)
+}
+
+/// index of the last `needle` in `haystack`.
+#[inline]
+pub fn rfind<T: Ord>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    let j = two_way(&Rev(haystack), &Rev(needle))?;
+    Some(haystack.len() - j - needle.len())
+}
@@ -0,0 +1,202 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! sentence boundaries of UAX #29, no allocations, `no_std`.
+//!
+//! a sentence keeps its trailing spaces and line ending, like the standard says.
+
+use super::*;
+
+mod tables;
+
+/// `Sentence_Break`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Cat {
+    Other,
+    Cr,
+    Lf,
+    Extend,
+    Sep,
+    Format,
+    Sp,
+    Lower,
+    Upper,
+    OLetter,
+    Numeric,
+    ATerm,
+    SContinue,
+    STerm,
+    Close,
+}
+use Cat::*;
+
+impl Cat {
+    #[inline]
+    fn of(c: char) -> Cat {
+        lookup_range(tables::CATS, c).unwrap_or(Other)
+    }
+    #[inline]
+    fn is_ignorable(self) -> bool {
+        matches!(self, Extend | Format)
+    }
+    #[inline]
+    fn is_para_sep(self) -> bool {
+        matches!(self, Sep | Cr | Lf)
+    }
+    #[inline]
+    fn is_term(self) -> bool {
+        matches!(self, ATerm | STerm)
+    }
+}
+
+/// the chars before `at`, the `Extend` and `Format` ones skipped (SB5).
+#[inline]
+fn effective_cats_before(string: &str, at: usize) -> impl Iterator<Item = Cat> + '_ {
+    string[..at]
+        .chars()
+        .rev()
+        .map(Cat::of)
+        .filter(|cat| !cat.is_ignorable())
+}
+
+/// `SATerm Close* Sp*` ending at `at`.
+struct Terminated {
+    term: Cat,
+    closes: usize,
+    spaces: usize,
+    /// before the terminator.
+    before: Option<Cat>,
+}
+
+#[inline]
+fn terminated_before(string: &str, at: usize) -> Option<Terminated> {
+    let mut cats = effective_cats_before(string, at).peekable();
+    let mut spaces = 0;
+    while cats.next_if_eq(&Sp).is_some() {
+        spaces += 1;
+    }
+    let mut closes = 0;
+    while cats.next_if_eq(&Close).is_some() {
+        closes += 1;
+    }
+    let term = cats.next().filter(|cat| cat.is_term())?;
+    Some(Terminated {
+        term,
+        closes,
+        spaces,
+        before: cats.next(),
+    })
+}
+
+/// if a sentence starts or ends at the byte `at`.
+/// * `false` inside of a char or past the end.
+pub fn is_boundary(string: &str, at: usize) -> bool {
+    if at == 0 || at == string.len() {
+        return true;
+    }
+    if !string.is_char_boundary(at) {
+        return false;
+    }
+    let raw_a = Cat::of(grapheme::char_before(string, at).unwrap().0);
+    let b = Cat::of(string[at..].chars().next().unwrap());
+    match (raw_a, b) {
+        // SB3, SB4
+        (Cr, Lf) => return false,
+        (a, _) if a.is_para_sep() => return true,
+        // SB5
+        (_, b) if b.is_ignorable() => return false,
+        _ => {}
+    }
+    let Some(t) = terminated_before(string, at) else {
+        // SB998
+        return false;
+    };
+    let bare = t.closes == 0 && t.spaces == 0;
+    let joined = match b {
+        // SB6, SB7
+        Numeric if bare && t.term == ATerm => true,
+        Upper if bare && t.term == ATerm && matches!(t.before, Some(Upper | Lower)) => true,
+        // SB8
+        _ if t.term == ATerm && lower_ahead(string, at) => true,
+        // SB8a
+        SContinue | ATerm | STerm => true,
+        // SB9
+        Close if t.spaces == 0 => true,
+        // SB9, SB10
+        Sp | Sep | Cr | Lf => true,
+        // SB11
+        _ => false,
+    };
+    !joined
+}
+
+/// `(¬(OLetter | Upper | Lower | ParaSep | SATerm))* Lower` from `at`.
+#[inline]
+fn lower_ahead(string: &str, at: usize) -> bool {
+    string[at..]
+        .chars()
+        .map(Cat::of)
+        .find(|&cat| matches!(cat, OLetter | Upper | Lower) || cat.is_para_sep() || cat.is_term())
+        == Some(Lower)
+}
+
+/// end of the sentence starting at the byte `at`, `at` at the end.
+#[inline]
+pub fn next_boundary(string: &str, at: usize) -> usize {
+    let mut chars = string[at..].char_indices();
+    chars.next();
+    chars
+        .map(|(i, _)| at + i)
+        .find(|&i| is_boundary(string, i))
+        .unwrap_or(string.len())
+}
+
+/// start of the sentence ending at the byte `at`, `0` at the start.
+#[inline]
+pub fn prev_boundary(string: &str, at: usize) -> usize {
+    let mut chars = string[..at].char_indices().rev();
+    chars.next();
+    chars
+        .map(|(i, ch)| i + ch.len_utf8())
+        .find(|&i| is_boundary(string, i))
+        .unwrap_or(0)
+}
+
+/// (byte offset, sentence) of every sentence, in both directions.
+#[inline]
+pub fn segments(string: &str) -> Segments<'_> {
+    Segments {
+        string,
+        front: 0,
+        back: string.len(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Segments<'s> {
+    string: &'s str,
+    front: usize,
+    back: usize,
+}
+impl<'s> Iterator for Segments<'s> {
+    type Item = (usize, &'s str);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (start, end) = (self.front, next_boundary(self.string, self.front));
+        self.front = end;
+        Some((start, &self.string[start..end]))
+    }
+}
+impl<'s> DoubleEndedIterator for Segments<'s> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (start, end) = (prev_boundary(self.string, self.back), self.back);
+        self.back = start;
+        Some((start, &self.string[start..end]))
+    }
+}
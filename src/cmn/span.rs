@@ -0,0 +1,159 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// a half-open part of a source, in chars and in bytes.
+/// * for a [`Cursor`](crate::Cursor) both are item positions.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub byte_start: usize,
+    pub byte_end: usize,
+}
+
+impl Span {
+    #[inline]
+    pub fn new(chars: Range<usize>, bytes: Range<usize>) -> Self {
+        Span {
+            start: chars.start,
+            end: chars.end,
+            byte_start: bytes.start,
+            byte_end: bytes.end,
+        }
+    }
+    /// same positions in chars and bytes, like the items of a slice.
+    #[inline]
+    pub fn of_items(items: Range<usize>) -> Self {
+        Span::new(items.clone(), items)
+    }
+    /// empty, at the char `pos` and the byte `byte_pos`.
+    #[inline]
+    pub fn empty_at(pos: usize, byte_pos: usize) -> Self {
+        Span::new(pos..pos, byte_pos..byte_pos)
+    }
+
+    #[inline]
+    pub fn chars(&self) -> Range<usize> {
+        self.start..self.end
+    }
+    #[inline]
+    pub fn bytes(&self) -> Range<usize> {
+        self.byte_start..self.byte_end
+    }
+    /// in chars.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+    #[inline]
+    pub fn len_as_bytes(&self) -> usize {
+        self.byte_end - self.byte_start
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// the smallest span covering both.
+    #[inline]
+    pub fn join(self, other: Span) -> Span {
+        Span {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+            byte_start: self.byte_start.min(other.byte_start),
+            byte_end: self.byte_end.max(other.byte_end),
+        }
+    }
+    /// if `other` is inside of this one.
+    #[inline]
+    pub fn contains(&self, other: &Span) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+    /// if the char `pos` is inside of this one.
+    #[inline]
+    pub fn contains_pos(&self, pos: usize) -> bool {
+        self.chars().contains(&pos)
+    }
+    /// if both share at least one char.
+    #[inline]
+    pub fn intersects(&self, other: &Span) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+    /// without the first `front` and the last `back` chars of `source`.
+    /// * `None` if there aren't that many.
+    #[inline]
+    pub fn shrink<S: SpanSource + ?Sized>(
+        self,
+        source: &S,
+        front: usize,
+        back: usize,
+    ) -> Option<Span> {
+        if front.checked_add(back)? > self.len() {
+            return None;
+        }
+        let (front_bytes, back_bytes) = source.edge_bytes(&self, front, back);
+        Some(Span {
+            start: self.start + front,
+            end: self.end - back,
+            byte_start: self.byte_start + front_bytes,
+            byte_end: self.byte_end - back_bytes,
+        })
+    }
+    /// the part of `source`, a `str` by bytes or a `[T]` by items.
+    #[inline]
+    pub fn slice<'a, S: SpanSource + ?Sized>(&self, source: &'a S) -> &'a S {
+        source.slice_span(self)
+    }
+}
+
+impl From<Range<usize>> for Span {
+    /// item positions.
+    #[inline]
+    fn from(items: Range<usize>) -> Self {
+        Span::of_items(items)
+    }
+}
+
+/// what a [`Span`](Span) can slice.
+pub trait SpanSource {
+    fn slice_span(&self, span: &Span) -> &Self;
+    /// bytes of the first `front` and the last `back` chars of `span`.
+    fn edge_bytes(&self, span: &Span, front: usize, back: usize) -> (usize, usize);
+}
+impl SpanSource for str {
+    #[inline]
+    fn slice_span(&self, span: &Span) -> &str {
+        &self[span.bytes()]
+    }
+    #[inline]
+    fn edge_bytes(&self, span: &Span, front: usize, back: usize) -> (usize, usize) {
+        let s = self.slice_span(span);
+        let front_bytes = s.chars().take(front).map(char::len_utf8).sum();
+        let back_bytes = s.chars().rev().take(back).map(char::len_utf8).sum();
+        (front_bytes, back_bytes)
+    }
+}
+impl<T> SpanSource for [T] {
+    #[inline]
+    fn slice_span(&self, span: &Span) -> &[T] {
+        &self[span.chars()]
+    }
+    #[inline]
+    fn edge_bytes(&self, _: &Span, front: usize, back: usize) -> (usize, usize) {
+        (front, back)
+    }
+}
+
+/// extras that know where the current item is,
+/// so a `Range<E>` like `to_range_extras()` becomes a [`Span`](Span).
+pub trait SpanExtras {
+    /// of the current item, empty before the first one.
+    fn span(&self) -> Span;
+}
+impl<E: SpanExtras> From<Range<E>> for Span {
+    #[inline]
+    fn from(extras: Range<E>) -> Self {
+        extras.start.span().join(extras.end.span())
+    }
+}
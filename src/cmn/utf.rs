@@ -2,155 +2,320 @@
 
 use super::*;
 
-type Utf8MaxSize = u32;
-
-#[inline]
-fn unchecked_utf8_to_char(u: Utf8MaxSize) -> char {
-    unsafe { char::from_u32_unchecked(u) }
-}
-
 #[inline]
 pub fn from_utf8_unchecked(bytes: &[u8]) -> &str {
     unsafe { str::from_utf8_unchecked(bytes) }
 }
 
+/// decodes the char led by the current byte of the `cursor`, checked,
+/// and bumps it to the last byte of the char.
+/// * `None` and no moves if there's no valid UTF-8 sequence there.
 #[inline]
 pub fn next_char<'a, C: CursorTrait<'a, u8>>(cursor: &mut C) -> Option<char> {
-    // decode UTF-8
-    let x = *cursor.current();
+    let (ch, width) = decode_char(cursor.as_slice().get(cursor.pos()..)?)?;
+    bump_by(cursor, width - 1, true);
+    Some(ch)
+}
 
-    // byte1: width = 1
-    // x < 128 (1000 0000) => x <= 0111 1111
-    if x < 128 {
-        return Some(unchecked_utf8_to_char(x as Utf8MaxSize));
-    }
+/// decodes the char ending at the current byte of the `cursor`, checked,
+/// and bumps it to the first byte of the char.
+/// * `None` and no moves if there's no valid UTF-8 sequence there.
+#[inline]
+pub fn next_back_char<'a, C: CursorTrait<'a, u8>>(cursor: &mut C) -> Option<char> {
+    let end = cursor.pos() + 1;
+    let bytes = cursor.as_slice().get(..end)?;
+    let (ch, width) = decode_back_sequence(bytes)?.ok()?;
+    bump_by(cursor, width - 1, false);
+    Some(ch)
+}
 
-    let backwards = cursor.backwards();
-    if backwards {
+/// `n` bytes on the `right` or the left, keeping the heading.
+#[inline]
+fn bump_by<'a, C: CursorTrait<'a, u8>>(cursor: &mut C, n: usize, right: bool) {
+    if n == 0 {
+        return;
+    }
+    let turn = cursor.backwards() == right;
+    if turn {
         cursor.turnaround();
     }
+    for _ in 0..n {
+        cursor.next();
+    }
+    if turn {
+        cursor.turnaround();
+    }
+}
 
-    // byte2: width = 2
-    let mut x_body = utf8_first_byte_body(x, 2);
-    // 110(0 0000)
-
-    let y = unwrap_or_0(cursor.next());
-    let y_body = utf8_cont_byte_body(y);
-
-    // concat bits
-    let mut ch: Utf8MaxSize = x_body << 6 | y_body;
-
-    // byte3: width = 3
-    x_body = utf8_first_byte_body(x, 3);
-    // x >= 1110 (0000)
-    if x >= 0xE0 {
-        let z = unwrap_or_0(cursor.next());
-
-        let z_body = utf8_cont_byte_body(z);
-
-        // concat bits
-        ch = x_body << 12 | y_body << 6 | z_body;
+/// checks whether the byte is a UTF-8 continuation byte (i.e., starts with the
+/// bits `10`).
+#[inline]
+pub fn utf8_is_cont_byte(byte: u8) -> bool {
+    (byte as i8) < -64
+}
 
-        // byte4: width = 4
-        x_body = utf8_first_byte_body(x, 4);
-        // x >= 1111 0(000)
-        if x >= 0xF0 {
-            let w = unwrap_or_0(cursor.next());
+/// width of the UTF-8 sequence led by `first`, `0` if it can't lead one.
+#[inline]
+pub fn utf8_char_width(first: u8) -> usize {
+    match first {
+        0x00..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => 0,
+    }
+}
 
-            let w_body = utf8_cont_byte_body(w);
+/// decodes the first char of `bytes`, checked.
+/// * returns (`char`, `width`),
+/// * `None` if the sequence is invalid or not complete in `bytes`.
+#[inline]
+pub fn decode_char(bytes: &[u8]) -> Option<(char, usize)> {
+    let width = utf8_char_width(*bytes.first()?);
+    let seq = bytes.get(..width)?;
+    let ch = str::from_utf8(seq).ok()?.chars().next()?;
+    Some((ch, width))
+}
 
-            // concat bits
-            ch = x_body << 18 | y_body << 12 | z_body << 6 | w_body;
+/// the first sequence of `bytes`, (`char`, `width`),
+/// or `Err(width)` of its maximal invalid subpart, like the WHATWG decoder does.
+/// * `None` if `bytes` is empty.
+#[inline]
+pub fn decode_sequence(bytes: &[u8]) -> Option<Result<(char, usize), usize>> {
+    let first = *bytes.first()?;
+    let width = utf8_char_width(first);
+    if width < 2 {
+        return Some(if width == 1 {
+            Ok((first as char, 1))
+        } else {
+            Err(1)
+        });
+    }
+    // the second byte narrows the overlongs, the surrogates and past U+10FFFF out.
+    let second = match first {
+        0xe0 => 0xa0..=0xbf,
+        0xed => 0x80..=0x9f,
+        0xf0 => 0x90..=0xbf,
+        0xf4 => 0x80..=0x8f,
+        _ => 0x80..=0xbf,
+    };
+    for n in 1..width {
+        let valid = match bytes.get(n) {
+            Some(byte) if n == 1 => second.contains(byte),
+            Some(&byte) => utf8_is_cont_byte(byte),
+            None => false,
+        };
+        if !valid {
+            return Some(Err(n));
         }
     }
+    Some(decode_char(bytes).ok_or(width))
+}
 
-    if backwards {
-        cursor.turnaround();
+/// the last sequence of `bytes`, split the same way [`decode_sequence`](decode_sequence)
+/// would from the left.
+/// * `None` if `bytes` is empty.
+#[inline]
+pub fn decode_back_sequence(bytes: &[u8]) -> Option<Result<(char, usize), usize>> {
+    let end = bytes.len();
+    if end == 0 {
+        return None;
     }
-    Some(unchecked_utf8_to_char(ch))
+    // every byte but a continuation one starts a sequence.
+    let lead = (end.saturating_sub(4)..end)
+        .rev()
+        .find(|&i| !utf8_is_cont_byte(bytes[i]));
+    let Some(lead) = lead else {
+        return Some(Err(1));
+    };
+    Some(match decode_sequence(&bytes[lead..])? {
+        Ok((ch, width)) if lead + width == end => Ok((ch, width)),
+        Err(width) if lead + width == end => Err(width),
+        _ => Err(1),
+    })
 }
 
+// ------ counting ------
+//
+// 16 bytes at a time with SSE2, then 8 at a time in a `u64` (SWAR),
+// then one by one, the results are the same as the one by one ones.
+
+/// number of chars in `bytes`, counting the lead bytes.
 #[inline]
-fn utf8_first_byte_body(byte: u8, width: u8) -> Utf8MaxSize {
-    utf8_filter_body_side(byte, width + 1) as Utf8MaxSize
+pub fn count_chars(bytes: &[u8]) -> usize {
+    bytes.len() - count_cont_bytes(bytes)
 }
 
+/// number of UTF-8 continuation bytes in `bytes`.
 #[inline]
-fn utf8_cont_byte_body(byte: u8) -> Utf8MaxSize {
-    utf8_filter_body_side(byte, 2) as Utf8MaxSize
+pub fn count_cont_bytes(bytes: &[u8]) -> usize {
+    count_class(bytes, Class::Cont)
 }
 
-/// WARNING: head_length must be less than 5, manually.
+/// number of `\n`s in `bytes`.
 #[inline]
-fn utf8_filter_body_side(byte: u8, head_length: u8) -> u8 {
-    byte & (u8::MAX >> head_length)
+pub fn count_newlines(bytes: &[u8]) -> usize {
+    count_class(bytes, Class::Byte(b'\n'))
 }
 
 #[inline]
-fn unwrap_or_0(opt: Option<&u8>) -> u8 {
-    match opt {
-        Some(&byte) => byte,
-        None => 0,
+pub fn is_ascii(bytes: &[u8]) -> bool {
+    let mut rest = bytes;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        let chunks = rest.chunks_exact(16);
+        rest = chunks.remainder();
+        for chunk in chunks {
+            if sse2::mask(chunk, Class::NonAscii) != 0 {
+                return false;
+            }
+        }
+    }
+    let words = rest.chunks_exact(8);
+    rest = words.remainder();
+    for word in words {
+        if Class::NonAscii.word(to_word(word)) != 0 {
+            return false;
+        }
     }
+    rest.is_ascii()
 }
 
-/// reads the last code point out of a byte iterator (assuming a
-/// UTF-8-like encoding).
+/// first byte of the `n`th char of `bytes`, zero-based, `None` if there's no such char.
+/// * skips whole words that can't hold it.
 #[inline]
-pub fn next_back_char<'a, C: CursorTrait<'a, u8>>(cursor: &mut C) -> Option<char> {
-    let x = *cursor.current();
-
-    // decode UTF-8
-    let w = match x {
-        // byte1: width = 1
-        // x < 128 (1000 0000) => x <= 0111 1111
-        next_byte if next_byte < 128 => {
-            return Some(unchecked_utf8_to_char(next_byte as Utf8MaxSize));
+pub fn nth_char_start(bytes: &[u8], mut n: usize) -> Option<usize> {
+    let mut at = 0;
+    for word in bytes.chunks_exact(8) {
+        let leads = 8 - Class::Cont.word(to_word(word)).count_ones() as usize;
+        if leads > n {
+            break;
         }
-        // or back
-        back_byte => back_byte,
-    };
-
-    let backwards = cursor.backwards();
-    if !backwards {
-        cursor.turnaround();
+        n -= leads;
+        at += 8;
     }
+    let (offset, _) = bytes[at..]
+        .iter()
+        .enumerate()
+        .filter(|(_, &byte)| !utf8_is_cont_byte(byte))
+        .nth(n)?;
+    Some(at + offset)
+}
 
-    // decode from a byte combination out of: [x [y [z w]]]
-    let mut ch;
-    let z = unwrap_or_0(cursor.next());
-    ch = utf8_first_byte_body(z, 2);
+/// first byte of the `n`th char of `bytes` from the end, zero-based,
+/// `None` if there's no such char.
+#[inline]
+pub fn nth_char_start_back(bytes: &[u8], mut n: usize) -> Option<usize> {
+    let mut end = bytes.len();
+    for word in bytes.rchunks_exact(8) {
+        let leads = 8 - Class::Cont.word(to_word(word)).count_ones() as usize;
+        if leads > n {
+            break;
+        }
+        n -= leads;
+        end -= 8;
+    }
+    let (at, _) = bytes[..end]
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, &byte)| !utf8_is_cont_byte(byte))
+        .nth(n)?;
+    Some(at)
+}
 
-    if utf8_is_cont_byte(z) {
-        let y = unwrap_or_0(cursor.next());
+#[derive(Debug, Clone, Copy)]
+enum Class {
+    Cont,
+    NonAscii,
+    Byte(u8),
+}
 
-        ch = utf8_first_byte_body(y, 3);
-        if utf8_is_cont_byte(y) {
-            let x = unwrap_or_0(cursor.next());
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
 
-            ch = utf8_first_byte_body(x, 4);
-            ch = utf8_acc_cont_byte(ch, y);
+impl Class {
+    #[inline]
+    fn byte(self, byte: u8) -> bool {
+        match self {
+            Class::Cont => utf8_is_cont_byte(byte),
+            Class::NonAscii => !byte.is_ascii(),
+            Class::Byte(b) => byte == b,
         }
-        ch = utf8_acc_cont_byte(ch, z);
     }
-    ch = utf8_acc_cont_byte(ch, w);
-
-    if !backwards {
-        cursor.turnaround();
+    /// the high bit of every byte of the class in `word`.
+    #[inline]
+    fn word(self, word: u64) -> u64 {
+        match self {
+            // `10xx_xxxx`, the bit 6 shifted onto the bit 7.
+            Class::Cont => word & !(word << 1) & HIGH_BITS,
+            Class::NonAscii => word & HIGH_BITS,
+            // the zero bytes of `word ^ b`, exact, no borrow into the next byte.
+            Class::Byte(b) => {
+                let x = word ^ (LOW_BITS * b as u64);
+                !(((x & !HIGH_BITS) + !HIGH_BITS) | x) & HIGH_BITS
+            }
+        }
     }
-
-    Some(unchecked_utf8_to_char(ch))
 }
 
-/// returns the value of `ch` updated with continuation byte `byte`.
 #[inline]
-fn utf8_acc_cont_byte(ch: Utf8MaxSize, byte: u8) -> Utf8MaxSize {
-    (ch << 6) | (byte & 0b0011_1111) as Utf8MaxSize
+fn to_word(bytes: &[u8]) -> u64 {
+    let mut word = [0; 8];
+    word.copy_from_slice(bytes);
+    u64::from_le_bytes(word)
 }
 
-/// checks whether the byte is a UTF-8 continuation byte (i.e., starts with the
-/// bits `10`).
 #[inline]
-pub fn utf8_is_cont_byte(byte: u8) -> bool {
-    (byte as i8) < -64
+fn count_class(bytes: &[u8], class: Class) -> usize {
+    let mut n = 0;
+    let mut rest = bytes;
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ))]
+    {
+        let chunks = rest.chunks_exact(16);
+        rest = chunks.remainder();
+        for chunk in chunks {
+            n += sse2::mask(chunk, class).count_ones() as usize;
+        }
+    }
+    let words = rest.chunks_exact(8);
+    rest = words.remainder();
+    for word in words {
+        n += class.word(to_word(word)).count_ones() as usize;
+    }
+    n + rest.iter().filter(|&&byte| class.byte(byte)).count()
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod sse2 {
+    use super::Class;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    /// a bit for every byte of the class in the 16 bytes of `chunk`.
+    #[inline]
+    pub(super) fn mask(chunk: &[u8], class: Class) -> u32 {
+        assert_eq!(chunk.len(), 16);
+        // SAFETY: 16 bytes to read, unaligned, and SSE2 is enabled.
+        unsafe {
+            let v = _mm_loadu_si128(chunk.as_ptr() as *const __m128i);
+            let hits = match class {
+                Class::Cont => _mm_cmplt_epi8(v, _mm_set1_epi8(-64)),
+                Class::NonAscii => v,
+                Class::Byte(b) => _mm_cmpeq_epi8(v, _mm_set1_epi8(b as i8)),
+            };
+            _mm_movemask_epi8(hits) as u32
+        }
+    }
 }
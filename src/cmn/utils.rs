@@ -2,10 +2,12 @@
 
 #![allow(non_snake_case)]
 
+use super::*;
+
 /// returns (`abs`, `signum`)
 #[inline]
 pub fn isize_to_usize(i: isize) -> (usize, i8) {
-    (i.abs() as usize, i.signum() as i8)
+    (i.unsigned_abs(), i.signum() as i8)
 }
 
 #[inline]
@@ -16,3 +18,21 @@ pub fn wrap<T, F: FnOnce() -> bool>(i: T, condition: F) -> Option<T> {
         None
     }
 }
+
+/// the value of the `(first, last, value)` range holding `c`, the ranges sorted.
+#[inline]
+pub(crate) fn lookup_range<V: Copy>(table: &[(u32, u32, V)], c: char) -> Option<V> {
+    let c = c as u32;
+    table
+        .binary_search_by(|&(lo, hi, _)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|i| table[i].2)
+}
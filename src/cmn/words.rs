@@ -0,0 +1,270 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! word boundaries of UAX #29, no allocations, `no_std`.
+//!
+//! [`WordMode::Programmer`](WordMode::Programmer) breaks inside of identifiers as well,
+//! `snake_case`, `camelCase`, `HTTPServer` and `a.b` become their parts.
+
+use super::*;
+
+mod tables;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum WordMode {
+    /// the Unicode rules as they are.
+    #[default]
+    Unicode,
+    /// sub-words, breaks around `_`, between a lowercase or a digit and an uppercase,
+    /// before the last uppercase of a run followed by a lowercase,
+    /// and around the `.` `:` `'` between letters, `3.14` stays.
+    Programmer,
+}
+
+/// `Word_Break`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Cat {
+    Other,
+    Cr,
+    Lf,
+    Newline,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Format,
+    Katakana,
+    HebrewLetter,
+    ALetter,
+    SingleQuote,
+    DoubleQuote,
+    MidNumLet,
+    MidLetter,
+    MidNum,
+    Numeric,
+    ExtendNumLet,
+    WSegSpace,
+}
+use Cat::*;
+
+impl Cat {
+    #[inline]
+    fn of(c: char) -> Cat {
+        lookup_range(tables::CATS, c).unwrap_or(Other)
+    }
+    #[inline]
+    fn is_ignorable(self) -> bool {
+        matches!(self, Extend | Format | Zwj)
+    }
+    #[inline]
+    fn is_newline(self) -> bool {
+        matches!(self, Cr | Lf | Newline)
+    }
+    #[inline]
+    fn is_ah_letter(self) -> bool {
+        matches!(self, ALetter | HebrewLetter)
+    }
+}
+
+/// the char before `at`, the `Extend`, `Format` and `ZWJ` ones skipped (WB4).
+#[inline]
+fn effective_before(string: &str, at: usize) -> Option<(char, usize)> {
+    let nearest = grapheme::char_before(string, at)?;
+    let mut cursor = nearest;
+    loop {
+        let cat = Cat::of(cursor.0);
+        if !cat.is_ignorable() {
+            // they don't stick to a line ending.
+            return Some(if cat.is_newline() { nearest } else { cursor });
+        }
+        match grapheme::char_before(string, cursor.1) {
+            Some(before) => cursor = before,
+            None => return Some(nearest),
+        }
+    }
+}
+
+/// the char after the one at `at`, the `Extend`, `Format` and `ZWJ` ones skipped.
+#[inline]
+fn effective_after(string: &str, at: usize) -> Option<char> {
+    let mut chars = string[at..].chars();
+    chars.next();
+    chars.find(|&c| !Cat::of(c).is_ignorable())
+}
+
+#[inline]
+fn cat_before(string: &str, at: usize) -> Cat {
+    effective_before(string, at).map_or(Other, |(c, _)| Cat::of(c))
+}
+#[inline]
+fn cat_after(string: &str, at: usize) -> Cat {
+    effective_after(string, at).map_or(Other, Cat::of)
+}
+
+/// if a word, a space run or a punctuation starts or ends at the byte `at`.
+/// * `false` inside of a char or past the end.
+pub fn is_boundary(string: &str, at: usize, mode: WordMode) -> bool {
+    if at == 0 || at == string.len() {
+        return true;
+    }
+    if !string.is_char_boundary(at) {
+        return false;
+    }
+    let (raw_a, _) = grapheme::char_before(string, at).unwrap();
+    let raw_b = string[at..].chars().next().unwrap();
+    match (Cat::of(raw_a), Cat::of(raw_b)) {
+        // WB3, WB3a, WB3b
+        (Cr, Lf) => return false,
+        (a, b) if a.is_newline() || b.is_newline() => return true,
+        // WB3c, WB3d
+        (Zwj, _) if grapheme::is_extended_pictographic(raw_b) => return false,
+        (WSegSpace, WSegSpace) => return false,
+        // WB4
+        (_, b) if b.is_ignorable() => return false,
+        _ => {}
+    }
+    let (char_a, a_at) = effective_before(string, at).unwrap();
+    let (a, b) = (Cat::of(char_a), Cat::of(raw_b));
+    let mid_letters = mode == WordMode::Unicode;
+    let joined = match (a, b) {
+        // WB5
+        (a, b) if a.is_ah_letter() && b.is_ah_letter() => true,
+        // WB6, WB7
+        (a, MidLetter | MidNumLet | SingleQuote) if mid_letters && a.is_ah_letter() => {
+            cat_after(string, at).is_ah_letter() || (a, b) == (HebrewLetter, SingleQuote)
+        }
+        (MidLetter | MidNumLet | SingleQuote, b) if mid_letters && b.is_ah_letter() => {
+            cat_before(string, a_at).is_ah_letter()
+        }
+        // WB7a, WB7b, WB7c
+        (HebrewLetter, SingleQuote) => true,
+        (HebrewLetter, DoubleQuote) => cat_after(string, at) == HebrewLetter,
+        (DoubleQuote, HebrewLetter) => cat_before(string, a_at) == HebrewLetter,
+        // WB8, WB9, WB10
+        (Numeric, Numeric) => true,
+        (a, Numeric) if a.is_ah_letter() => true,
+        (Numeric, b) if b.is_ah_letter() => true,
+        // WB11, WB12
+        (MidNum | MidNumLet | SingleQuote, Numeric) => cat_before(string, a_at) == Numeric,
+        (Numeric, MidNum | MidNumLet | SingleQuote) => cat_after(string, at) == Numeric,
+        // WB13, WB13a, WB13b
+        (Katakana, Katakana) => true,
+        (ALetter | HebrewLetter | Numeric | Katakana | ExtendNumLet, ExtendNumLet) => true,
+        (ExtendNumLet, ALetter | HebrewLetter | Numeric | Katakana) => true,
+        // WB15, WB16
+        (RegionalIndicator, RegionalIndicator) => {
+            !regional_indicators_before(string, at).is_multiple_of(2)
+        }
+        // WB999
+        _ => false,
+    };
+    if !joined {
+        return true;
+    }
+    mode == WordMode::Programmer && is_sub_word_boundary(string, at, char_a, raw_b)
+}
+
+#[inline]
+fn is_sub_word_boundary(string: &str, at: usize, a: char, b: char) -> bool {
+    if Cat::of(a) == ExtendNumLet || Cat::of(b) == ExtendNumLet {
+        return true;
+    }
+    if b.is_uppercase() && (a.is_lowercase() || a.is_numeric()) {
+        return true;
+    }
+    // `HTTP|Server`
+    a.is_uppercase()
+        && b.is_uppercase()
+        && effective_after(string, at).is_some_and(char::is_lowercase)
+}
+
+#[inline]
+fn regional_indicators_before(string: &str, mut at: usize) -> usize {
+    let mut n = 0;
+    while let Some((ch, ch_at)) = effective_before(string, at) {
+        if Cat::of(ch) != RegionalIndicator {
+            break;
+        }
+        n += 1;
+        at = ch_at;
+    }
+    n
+}
+
+/// end of the segment starting at the byte `at`, `at` at the end.
+#[inline]
+pub fn next_boundary(string: &str, at: usize, mode: WordMode) -> usize {
+    let mut chars = string[at..].char_indices();
+    chars.next();
+    chars
+        .map(|(i, _)| at + i)
+        .find(|&i| is_boundary(string, i, mode))
+        .unwrap_or(string.len())
+}
+
+/// start of the segment ending at the byte `at`, `0` at the start.
+#[inline]
+pub fn prev_boundary(string: &str, at: usize, mode: WordMode) -> usize {
+    let mut chars = string[..at].char_indices().rev();
+    chars.next();
+    chars
+        .map(|(i, ch)| i + ch.len_utf8())
+        .find(|&i| is_boundary(string, i, mode))
+        .unwrap_or(0)
+}
+
+/// if the segment is a word, not spaces, punctuations or symbols.
+#[inline]
+pub fn is_word(segment: &str, mode: WordMode) -> bool {
+    segment.chars().any(|c| {
+        c.is_alphanumeric()
+            || match Cat::of(c) {
+                ALetter | HebrewLetter | Numeric | Katakana => true,
+                ExtendNumLet => mode == WordMode::Unicode,
+                _ => false,
+            }
+    })
+}
+
+/// (byte offset, segment) of every word, space run and punctuation, in both directions.
+#[inline]
+pub fn segments(string: &str, mode: WordMode) -> Segments<'_> {
+    Segments {
+        string,
+        mode,
+        front: 0,
+        back: string.len(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Segments<'s> {
+    string: &'s str,
+    mode: WordMode,
+    front: usize,
+    back: usize,
+}
+impl<'s> Iterator for Segments<'s> {
+    type Item = (usize, &'s str);
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (start, end) = (
+            self.front,
+            next_boundary(self.string, self.front, self.mode),
+        );
+        self.front = end;
+        Some((start, &self.string[start..end]))
+    }
+}
+impl<'s> DoubleEndedIterator for Segments<'s> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let (start, end) = (prev_boundary(self.string, self.back, self.mode), self.back);
+        self.back = start;
+        Some((start, &self.string[start..end]))
+    }
+}
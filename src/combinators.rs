@@ -0,0 +1,535 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! parser combinators over [`StrCursor`](crate::StrCursor) and [`Cursor`](crate::Cursor).
+//!
+//! * a parser is any `FnMut(&mut C) -> PResult<O>`,
+//! * parsing goes to the right, a failed parser rewinds to where it started,
+//! * unless it was [`cut`](cut), then the whole parse fails there.
+//!
+//! ```
+//! use cursor::{combinators::*, *};
+//!
+//! let mut cursor = StrCursor::new("[1, 22, 333]");
+//! let number = take_while(|c: char| c.is_ascii_digit());
+//! let mut list = seq((char('['), separated_list(tag(", "), number), char(']')));
+//! let (_, numbers, _) = list(&mut cursor).unwrap();
+//! assert_eq!(numbers, ["1", "22", "333"]);
+//! ```
+
+use super::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// why a parser failed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseError {
+    /// recoverable, `alt` and `opt` try something else.
+    Backtrack(CursorError),
+    /// after a [`cut`](cut), no more alternatives.
+    Cut(CursorError),
+}
+impl ParseError {
+    #[inline]
+    pub fn error(&self) -> CursorError {
+        match *self {
+            ParseError::Backtrack(e) | ParseError::Cut(e) => e,
+        }
+    }
+    #[inline]
+    pub fn is_cut(&self) -> bool {
+        matches!(self, ParseError::Cut(_))
+    }
+}
+impl fmt::Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error().fmt(f)
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+pub type PResult<O> = Result<O, ParseError>;
+
+/// output of [`recognize`](recognize).
+pub type Recognized<'s, C> = (&'s <C as Input<'s>>::Slice, Range<<C as Input<'s>>::Extras>);
+
+/// where a parser started, to rewind or to slice since.
+#[must_use]
+pub struct Mark<Info> {
+    checkpoint: Checkpoint<Info>,
+    offset: usize,
+}
+
+/// what the combinators need from a cursor.
+/// * `Slice` is `str` for [`StrCursor`](crate::StrCursor), `[T]` for [`Cursor`](crate::Cursor),
+/// * offsets index the whole `Slice` (bytes for `str`).
+pub trait Input<'s> {
+    type Item: Copy;
+    type Slice: ?Sized + 's;
+    type Extras;
+    type Info;
+
+    /// what hasn't been consumed yet.
+    fn rest(&self) -> &'s Self::Slice;
+    /// how much has been consumed.
+    fn offset(&self) -> usize;
+    /// the whole input in `range`.
+    fn slice(&self, range: Range<usize>) -> &'s Self::Slice;
+    /// the first item of `rest`.
+    fn first(&self) -> Option<Self::Item>;
+    /// pos of the first item of `rest`.
+    fn next_pos(&self) -> usize;
+    /// consumes `n` items, heading right.
+    fn bump(&mut self, n: usize);
+    /// (items before, matched items) of the first match in `rest`.
+    fn find<P: Pattern<Self::Slice>>(&self, pattern: &mut P) -> Option<(usize, usize)>;
+    fn mark(&mut self) -> Mark<Self::Info>;
+    fn rewind(&mut self, mark: Mark<Self::Info>);
+    /// extras at the mark to the current extras.
+    fn extras_since(&self, mark: &Mark<Self::Info>) -> Range<Self::Extras>;
+
+    /// what has been consumed since the mark.
+    #[inline]
+    fn consumed_since(&self, mark: &Mark<Self::Info>) -> &'s Self::Slice {
+        self.slice(mark.offset..self.offset())
+    }
+    #[inline]
+    fn unexpected(&self) -> ParseError {
+        ParseError::Backtrack(if self.first().is_none() {
+            CursorError::EndOfInput
+        } else {
+            CursorError::Unexpected {
+                pos: self.next_pos(),
+            }
+        })
+    }
+}
+
+impl<'s, E: Extras<char>> Input<'s> for StrCursor<'s, E> {
+    type Item = char;
+    type Slice = str;
+    type Extras = E;
+    type Info = StrCursorInfo<E>;
+
+    #[inline]
+    fn rest(&self) -> &'s str {
+        upcoming_text(self, true)
+    }
+    #[inline]
+    fn offset(&self) -> usize {
+        self.as_str().len() - self.rest().len()
+    }
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &'s str {
+        &self.as_str()[range]
+    }
+    #[inline]
+    fn first(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+    #[inline]
+    fn next_pos(&self) -> usize {
+        upcoming_pos(self.pos(), self.is_init(), true)
+    }
+    #[inline]
+    fn bump(&mut self, n: usize) {
+        self.head_to_right();
+        for _ in 0..n {
+            self.next();
+        }
+    }
+    #[inline]
+    fn find<P: Pattern<str>>(&self, pattern: &mut P) -> Option<(usize, usize)> {
+        find_pattern_in_str(self.rest(), true, pattern)
+    }
+    #[inline]
+    fn mark(&mut self) -> Mark<StrCursorInfo<E>> {
+        Mark {
+            offset: self.offset(),
+            checkpoint: self.push_checkpoint(),
+        }
+    }
+    #[inline]
+    fn rewind(&mut self, mark: Mark<StrCursorInfo<E>>) {
+        self.restore(mark.checkpoint);
+    }
+    #[inline]
+    fn extras_since(&self, mark: &Mark<StrCursorInfo<E>>) -> Range<E> {
+        mark.checkpoint.info().extras.clone()..self.extras().clone()
+    }
+}
+
+impl<'s, T: 's, E: Extras<T>> Input<'s> for Cursor<'s, T, E> {
+    type Item = &'s T;
+    type Slice = [T];
+    type Extras = E;
+    type Info = CursorInfo<T, E>;
+
+    #[inline]
+    fn rest(&self) -> &'s [T] {
+        upcoming_slice(self, true)
+    }
+    #[inline]
+    fn offset(&self) -> usize {
+        self.as_slice().len() - self.rest().len()
+    }
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &'s [T] {
+        &self.as_slice()[range]
+    }
+    #[inline]
+    fn first(&self) -> Option<&'s T> {
+        self.rest().first()
+    }
+    #[inline]
+    fn next_pos(&self) -> usize {
+        upcoming_pos(self.pos(), self.is_init(), true)
+    }
+    #[inline]
+    fn bump(&mut self, n: usize) {
+        self.head_to_right();
+        for _ in 0..n {
+            self.next();
+        }
+    }
+    #[inline]
+    fn find<P: Pattern<[T]>>(&self, pattern: &mut P) -> Option<(usize, usize)> {
+        find_pattern_in_slice(self.rest(), true, pattern)
+    }
+    #[inline]
+    fn mark(&mut self) -> Mark<CursorInfo<T, E>> {
+        Mark {
+            offset: self.offset(),
+            checkpoint: self.push_checkpoint(),
+        }
+    }
+    #[inline]
+    fn rewind(&mut self, mark: Mark<CursorInfo<T, E>>) {
+        self.restore(mark.checkpoint);
+    }
+    #[inline]
+    fn extras_since(&self, mark: &Mark<CursorInfo<T, E>>) -> Range<E> {
+        mark.checkpoint.info().extras.clone()..self.extras().clone()
+    }
+}
+
+pub trait Parser<C, O> {
+    fn parse(&mut self, cursor: &mut C) -> PResult<O>;
+}
+impl<C, O, F: FnMut(&mut C) -> PResult<O>> Parser<C, O> for F {
+    #[inline]
+    fn parse(&mut self, cursor: &mut C) -> PResult<O> {
+        self(cursor)
+    }
+}
+
+/// runs `parser`, rewinds if it backtracks.
+#[inline]
+fn attempt<'s, C: Input<'s>, O, P: Parser<C, O>>(cursor: &mut C, parser: &mut P) -> PResult<O> {
+    let mark = cursor.mark();
+    let res = parser.parse(cursor);
+    if let Err(ParseError::Backtrack(_)) = res {
+        cursor.rewind(mark);
+    }
+    res
+}
+
+// ------ leaves ------
+
+/// the items matching `pattern`.
+#[inline]
+pub fn tag<'s, C, P>(mut pattern: P) -> impl FnMut(&mut C) -> PResult<&'s C::Slice>
+where
+    C: Input<'s>,
+    P: Pattern<C::Slice>,
+{
+    move |cursor| {
+        let n = pattern
+            .match_prefix(cursor.rest())
+            .ok_or_else(|| cursor.unexpected())?;
+        let from = cursor.offset();
+        cursor.bump(n);
+        Ok(cursor.slice(from..cursor.offset()))
+    }
+}
+
+/// exactly `c`.
+#[inline]
+pub fn char<'s, C>(c: char) -> impl FnMut(&mut C) -> PResult<char>
+where
+    C: Input<'s, Item = char>,
+{
+    move |cursor| match cursor.first() {
+        Some(first) if first == c => {
+            cursor.bump(1);
+            Ok(c)
+        }
+        _ => Err(cursor.unexpected()),
+    }
+}
+
+/// one item matching `set`, like `&['+', '-'][..]`, `'0'..='9'` or a `ByteSet`.
+#[inline]
+pub fn one_of<'s, C, P>(mut set: P) -> impl FnMut(&mut C) -> PResult<C::Item>
+where
+    C: Input<'s>,
+    P: Pattern<C::Slice>,
+{
+    move |cursor| match (cursor.first(), set.match_prefix(cursor.rest())) {
+        (Some(item), Some(1)) => {
+            cursor.bump(1);
+            Ok(item)
+        }
+        _ => Err(cursor.unexpected()),
+    }
+}
+
+/// the longest run matching `pattern`, may be empty.
+#[inline]
+pub fn take_while<'s, C, P>(mut pattern: P) -> impl FnMut(&mut C) -> PResult<&'s C::Slice>
+where
+    C: Input<'s>,
+    P: Pattern<C::Slice>,
+{
+    move |cursor| {
+        let from = cursor.offset();
+        while let Some(n @ 1..) = pattern.match_prefix(cursor.rest()) {
+            cursor.bump(n);
+        }
+        Ok(cursor.slice(from..cursor.offset()))
+    }
+}
+
+/// everything before the first match of `pattern`, which is not consumed.
+#[inline]
+pub fn take_until<'s, C, P>(mut pattern: P) -> impl FnMut(&mut C) -> PResult<&'s C::Slice>
+where
+    C: Input<'s>,
+    P: Pattern<C::Slice>,
+{
+    move |cursor| {
+        let (before, _) = cursor
+            .find(&mut pattern)
+            .ok_or(ParseError::Backtrack(CursorError::EndOfInput))?;
+        let from = cursor.offset();
+        cursor.bump(before);
+        Ok(cursor.slice(from..cursor.offset()))
+    }
+}
+
+// ------ branches ------
+
+/// tuples of parsers with the same output, for [`alt`](alt).
+pub trait Alt<'s, C: Input<'s>, O> {
+    fn choice(&mut self, cursor: &mut C) -> PResult<O>;
+}
+/// tuples of parsers, for [`seq`](seq).
+pub trait Seq<'s, C: Input<'s>, O> {
+    fn sequence(&mut self, cursor: &mut C) -> PResult<O>;
+}
+
+macro_rules! impl_tuples {
+    ($( ($($p:ident $o:ident $i:tt),+) )*) => {$(
+        impl<'s, C: Input<'s>, O, $($p: Parser<C, O>),+> Alt<'s, C, O> for ($($p,)+) {
+            #[inline]
+            fn choice(&mut self, cursor: &mut C) -> PResult<O> {
+                #[allow(unused_mut)] // a 1-tuple assigns once
+                let mut res;
+                $(
+                    res = attempt(cursor, &mut self.$i);
+                    if !matches!(res, Err(ParseError::Backtrack(_))) {
+                        return res;
+                    }
+                )+
+                // the last error
+                res
+            }
+        }
+        impl<'s, C: Input<'s>, $($o,)+ $($p: Parser<C, $o>),+> Seq<'s, C, ($($o,)+)> for ($($p,)+) {
+            #[inline]
+            fn sequence(&mut self, cursor: &mut C) -> PResult<($($o,)+)> {
+                Ok(($(self.$i.parse(cursor)?,)+))
+            }
+        }
+    )*};
+}
+impl_tuples! {
+    (A OA 0)
+    (A OA 0, B OB 1)
+    (A OA 0, B OB 1, C2 OC 2)
+    (A OA 0, B OB 1, C2 OC 2, D OD 3)
+    (A OA 0, B OB 1, C2 OC 2, D OD 3, E OE 4)
+    (A OA 0, B OB 1, C2 OC 2, D OD 3, E OE 4, F OF 5)
+    (A OA 0, B OB 1, C2 OC 2, D OD 3, E OE 4, F OF 5, G OG 6)
+    (A OA 0, B OB 1, C2 OC 2, D OD 3, E OE 4, F OF 5, G OG 6, H OH 7)
+}
+
+/// the first of `parsers` that doesn't backtrack.
+#[inline]
+pub fn alt<'s, C, O, L>(mut parsers: L) -> impl FnMut(&mut C) -> PResult<O>
+where
+    C: Input<'s>,
+    L: Alt<'s, C, O>,
+{
+    move |cursor| parsers.choice(cursor)
+}
+
+/// all of `parsers` in order, rewinds all of them if one backtracks.
+#[inline]
+pub fn seq<'s, C, O, L>(mut parsers: L) -> impl FnMut(&mut C) -> PResult<O>
+where
+    C: Input<'s>,
+    L: Seq<'s, C, O>,
+{
+    move |cursor| attempt(cursor, &mut |c: &mut C| parsers.sequence(c))
+}
+
+/// `None` if `parser` backtracks.
+#[inline]
+pub fn opt<'s, C, O, P>(mut parser: P) -> impl FnMut(&mut C) -> PResult<Option<O>>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+{
+    move |cursor| match attempt(cursor, &mut parser) {
+        Ok(o) => Ok(Some(o)),
+        Err(ParseError::Backtrack(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// backtracks of `parser` become [`ParseError::Cut`](ParseError::Cut).
+#[inline]
+pub fn cut<'s, C, O, P>(mut parser: P) -> impl FnMut(&mut C) -> PResult<O>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+{
+    move |cursor| match parser.parse(cursor) {
+        Err(ParseError::Backtrack(e)) => Err(ParseError::Cut(e)),
+        res => res,
+    }
+}
+
+// ------ outputs ------
+
+#[inline]
+pub fn map<'s, C, O, U, P, F>(mut parser: P, mut f: F) -> impl FnMut(&mut C) -> PResult<U>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+    F: FnMut(O) -> U,
+{
+    move |cursor| parser.parse(cursor).map(&mut f)
+}
+
+/// what `parser` consumed, and the extras from its start to its end.
+#[inline]
+pub fn recognize<'s, C, O, P>(mut parser: P) -> impl FnMut(&mut C) -> PResult<Recognized<'s, C>>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+{
+    move |cursor| {
+        let mark = cursor.mark();
+        match parser.parse(cursor) {
+            Ok(_) => Ok((cursor.consumed_since(&mark), cursor.extras_since(&mark))),
+            Err(e) => {
+                if !e.is_cut() {
+                    cursor.rewind(mark);
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+// ------ repetition ------
+
+/// `parser` until it backtracks or stops consuming.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn many0<'s, C, O, P>(mut parser: P) -> impl FnMut(&mut C) -> PResult<Vec<O>>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+{
+    move |cursor| {
+        let mut out = Vec::new();
+        loop {
+            let from = cursor.offset();
+            match attempt(cursor, &mut parser) {
+                Ok(o) => out.push(o),
+                Err(ParseError::Backtrack(_)) => return Ok(out),
+                Err(e) => return Err(e),
+            }
+            if cursor.offset() == from {
+                return Ok(out);
+            }
+        }
+    }
+}
+
+/// same with [`many0`](many0), but at least once.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn many1<'s, C, O, P>(parser: P) -> impl FnMut(&mut C) -> PResult<Vec<O>>
+where
+    C: Input<'s>,
+    P: Parser<C, O>,
+{
+    let mut many = many0(parser);
+    move |cursor| {
+        let out = many(cursor)?;
+        if out.is_empty() {
+            return Err(cursor.unexpected());
+        }
+        Ok(out)
+    }
+}
+
+/// zero or more `parser` separated by `separator`, a trailing one is left.
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn separated_list<'s, C, O, S, OS, P>(
+    mut separator: S,
+    mut parser: P,
+) -> impl FnMut(&mut C) -> PResult<Vec<O>>
+where
+    C: Input<'s>,
+    S: Parser<C, OS>,
+    P: Parser<C, O>,
+{
+    move |cursor| {
+        let mut out = Vec::new();
+        match attempt(cursor, &mut parser) {
+            Ok(o) => out.push(o),
+            Err(ParseError::Backtrack(_)) => return Ok(out),
+            Err(e) => return Err(e),
+        }
+        loop {
+            let mark = cursor.mark();
+            let from = cursor.offset();
+            match separator.parse(cursor) {
+                Ok(_) => {}
+                Err(ParseError::Backtrack(_)) => {
+                    cursor.rewind(mark);
+                    return Ok(out);
+                }
+                Err(e) => return Err(e),
+            }
+            match parser.parse(cursor) {
+                Ok(o) => out.push(o),
+                Err(ParseError::Backtrack(_)) => {
+                    cursor.rewind(mark);
+                    return Ok(out);
+                }
+                Err(e) => return Err(e),
+            }
+            if cursor.offset() == from {
+                return Ok(out);
+            }
+        }
+    }
+}
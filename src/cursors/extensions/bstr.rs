@@ -0,0 +1,158 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// a char cursor over bytes that may not be UTF-8, logs, captures, ...
+/// * every maximal invalid subpart is one U+FFFD, in both directions,
+/// * [`strict`](BStrCursor::strict) / `next_strict()` tell them apart with an `Err`,
+/// * the byte views are named like [`StrCursor`](StrCursor)'s (`as_bytes`, `current_bytes`, ...), by whole chars,
+///   it's a [`TextCursorTrait`](TextCursorTrait), not a [`StrCursorTrait`](StrCursorTrait),
+///   whose views are `&str`s and these bytes may not be.
+///
+/// ```
+/// use cursor::*;
+///
+/// let mut cursor = BStrCursor::new(b"a\xe2\x82!");
+/// assert_eq!(cursor.next(), Some('a'));
+/// assert_eq!(cursor.next(), Some('\u{fffd}'));
+/// assert_eq!(cursor.current_bytes(), b"\xe2\x82");
+/// assert!(cursor.is_invalid());
+///
+/// cursor.jump_to_first();
+/// let strict: Vec<_> = cursor.strict().collect();
+/// assert_eq!(strict, [Err(CursorError::InvalidUtf8 { byte_pos: 1 }), Ok('!')]);
+/// ```
+pub type BStrCursor<'s, E = NoneExtras<char>> = TextCursor<'s, Utf8, E>;
+
+impl<'s, E: Extras<char>> TextCursor<'s, Utf8, E> {
+    #[inline]
+    fn checked(&self, ch: char) -> Result<char, CursorError> {
+        if self.is_invalid() {
+            Err(CursorError::InvalidUtf8 {
+                byte_pos: self.pos_as_bytes(),
+            })
+        } else {
+            Ok(ch)
+        }
+    }
+
+    /// if the current char stands for invalid bytes, not a U+FFFD of the input.
+    #[inline]
+    pub fn is_invalid(&self) -> bool {
+        self.current() == char::REPLACEMENT_CHARACTER
+            && self.current_bytes() != "\u{fffd}".as_bytes()
+    }
+    /// same with `next`, invalid bytes are an `Err`.
+    /// * the extras still see a U+FFFD.
+    #[inline]
+    pub fn next_strict(&mut self) -> Option<Result<char, CursorError>> {
+        let ch = self.next()?;
+        Some(self.checked(ch))
+    }
+    /// same with `prev`, invalid bytes are an `Err`.
+    #[inline]
+    pub fn prev_strict(&mut self) -> Option<Result<char, CursorError>> {
+        let ch = self.prev()?;
+        Some(self.checked(ch))
+    }
+    /// `next_strict()` as an iterator, the cursor moves along.
+    #[inline]
+    pub fn strict(&mut self) -> StrictChars<'_, 's, E> {
+        StrictChars { cursor: self }
+    }
+
+    // ------ BYTES ------
+
+    #[inline]
+    pub fn as_bytes(&self) -> &'s [u8] {
+        self.as_units()
+    }
+    /// the first byte of the current char.
+    #[inline]
+    pub fn pos_as_bytes(&self) -> usize {
+        self.pos_as_units()
+    }
+    #[inline]
+    pub fn len_as_bytes(&self) -> usize {
+        self.len_as_units()
+    }
+    /// the bytes of the current char, empty before the first one.
+    #[inline]
+    pub fn current_bytes(&self) -> &'s [u8] {
+        self.current_units()
+    }
+    /// saved char to the current char, both included.
+    #[inline]
+    pub fn as_bytes_loaded(&self) -> &'s [u8] {
+        self.as_units_loaded()
+    }
+    /// checkpoint char to the current char, both included.
+    #[inline]
+    pub fn bytes_since(&self, checkpoint: &Checkpoint<TextCursorInfo<E>>) -> &'s [u8] {
+        self.units_since(checkpoint)
+    }
+    #[inline]
+    pub fn as_preserved_bytes(&self) -> &'s [u8] {
+        self.as_preserved_units()
+    }
+    #[inline]
+    pub fn as_preserved_bytes_include_current(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_right_side_bytes_include_current()
+        } else {
+            self.as_left_side_bytes_include_current()
+        }
+    }
+    #[inline]
+    pub fn as_remaining_bytes(&self) -> &'s [u8] {
+        self.as_remaining_units()
+    }
+    #[inline]
+    pub fn as_remaining_bytes_include_current(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_left_side_bytes_include_current()
+        } else {
+            self.as_right_side_bytes_include_current()
+        }
+    }
+    #[inline]
+    pub fn as_left_side_bytes(&self) -> &'s [u8] {
+        self.as_left_side_units()
+    }
+    #[inline]
+    pub fn as_right_side_bytes(&self) -> &'s [u8] {
+        self.as_right_side_units()
+    }
+    #[inline]
+    pub fn as_left_side_bytes_include_current(&self) -> &'s [u8] {
+        let end = self.pos_as_bytes() + self.current_bytes().len();
+        &self.as_bytes()[..end]
+    }
+    #[inline]
+    pub fn as_right_side_bytes_include_current(&self) -> &'s [u8] {
+        &self.as_bytes()[self.pos_as_bytes()..]
+    }
+
+    /// the bytes loaded, if they are all UTF-8.
+    #[inline]
+    pub fn as_str_loaded(&self) -> Result<&'s str, CursorError> {
+        let span = self.span_since_save();
+        let bytes = self.as_bytes_loaded();
+        str::from_utf8(bytes).map_err(|err| CursorError::InvalidUtf8 {
+            byte_pos: span.bytes().start + err.valid_up_to(),
+        })
+    }
+}
+
+/// see [`BStrCursor::strict`](BStrCursor::strict).
+#[derive(Debug)]
+pub struct StrictChars<'c, 's, E: Extras<char> = NoneExtras<char>> {
+    cursor: &'c mut BStrCursor<'s, E>,
+}
+impl<E: Extras<char>> Iterator for StrictChars<'_, '_, E> {
+    type Item = Result<char, CursorError>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cursor.next_strict()
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// where a [`GraphemeCursor`](GraphemeCursor) is.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct GraphemeCursorInfo {
+    /// index of the current cluster.
+    pub pos: usize,
+    /// char position of its first char.
+    pub char_pos: usize,
+    /// its bytes, empty before the first one.
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub init: bool,
+}
+
+/// moves by extended grapheme clusters of UAX #29,
+/// so `"e\u{301}"`, a flag or a Hangul jamo sequence is one item.
+/// * `pos()` counts clusters, `char_pos()` and `pos_as_bytes()` follow along,
+/// * made from scratch or from a [`StrCursor`](StrCursor) with `.graphemes()`.
+///
+/// ```
+/// use cursor::*;
+///
+/// let mut cursor = GraphemeCursor::new("e\u{301}🇰🇷!");
+/// assert_eq!(cursor.next(), Some("e\u{301}"));
+/// assert_eq!(cursor.next(), Some("🇰🇷"));
+/// assert_eq!((cursor.pos(), cursor.char_pos(), cursor.pos_as_bytes()), (1, 2, 3));
+/// assert_eq!(cursor.prev(), Some("e\u{301}"));
+/// ```
+#[derive(PartialEq, Eq, Clone)]
+pub struct GraphemeCursor<'s> {
+    string: &'s str,
+    backwards: bool,
+    info: GraphemeCursorInfo,
+    saved_info: GraphemeCursorInfo,
+}
+
+impl fmt::Debug for GraphemeCursor<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("GraphemeCursor")
+            .field(&self.as_preserved_str())
+            .field(&self.current())
+            .field(&self.as_remaining_str())
+            .finish()
+    }
+}
+
+impl<'s> GraphemeCursor<'s> {
+    #[inline]
+    pub fn new(string: &'s str) -> Self {
+        GraphemeCursor {
+            string,
+            backwards: false,
+            info: GraphemeCursorInfo::default(),
+            saved_info: GraphemeCursorInfo::default(),
+        }
+    }
+
+    // ------ private ------
+
+    /// to the cluster starting at `start`, the next or the previous one.
+    #[inline]
+    fn step_to(&mut self, start: usize, end: usize, forwards: bool) -> &'s str {
+        let info = &mut self.info;
+        if info.init {
+            if forwards {
+                info.pos += 1;
+                info.char_pos += utf::count_chars(&self.string.as_bytes()[info.byte_start..start]);
+            } else {
+                info.pos -= 1;
+                info.char_pos -= utf::count_chars(&self.string.as_bytes()[start..info.byte_start]);
+            }
+        }
+        info.byte_start = start;
+        info.byte_end = end;
+        info.init = true;
+        &self.string[start..end]
+    }
+    #[inline]
+    fn step_right(&mut self) -> Option<&'s str> {
+        if !self.info.init {
+            return self.step_first();
+        }
+        let start = self.info.byte_end;
+        if start == self.string.len() {
+            return None;
+        }
+        let end = grapheme::next_boundary(self.string, start);
+        Some(self.step_to(start, end, true))
+    }
+    #[inline]
+    fn step_left(&mut self) -> Option<&'s str> {
+        if !self.info.init {
+            return self.step_first();
+        }
+        let end = self.info.byte_start;
+        if end == 0 {
+            return None;
+        }
+        let start = grapheme::prev_boundary(self.string, end);
+        Some(self.step_to(start, end, false))
+    }
+    #[inline]
+    fn step_first(&mut self) -> Option<&'s str> {
+        if self.string.is_empty() {
+            return None;
+        }
+        let end = grapheme::next_boundary(self.string, 0);
+        Some(self.step_to(0, end, true))
+    }
+
+    // ------ public ------
+
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.info.init
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.backwards
+    }
+    #[inline]
+    pub fn set_backwards(&mut self, backwards: bool) {
+        self.backwards = backwards;
+    }
+    #[inline]
+    pub fn turnaround(&mut self) {
+        self.backwards = !self.backwards;
+    }
+    /// cluster index.
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    /// char position of the first char of the current cluster.
+    #[inline]
+    pub fn char_pos(&self) -> usize {
+        self.info.char_pos
+    }
+    /// byte position of the current cluster.
+    #[inline]
+    pub fn pos_as_bytes(&self) -> usize {
+        self.info.byte_start
+    }
+    #[inline]
+    pub fn info(&self) -> &GraphemeCursorInfo {
+        &self.info
+    }
+    /// the current cluster, empty before the first one.
+    #[inline]
+    pub fn current(&self) -> &'s str {
+        &self.string[self.info.byte_start..self.info.byte_end]
+    }
+    #[inline]
+    pub fn span_of_current(&self) -> Span {
+        let chars = self.current().chars().count();
+        Span::new(
+            self.info.char_pos..self.info.char_pos + chars,
+            self.info.byte_start..self.info.byte_end,
+        )
+    }
+    #[inline]
+    pub fn as_str(&self) -> &'s str {
+        self.string
+    }
+    /// before the current cluster.
+    #[inline]
+    pub fn as_preserved_str(&self) -> &'s str {
+        &self.string[..self.info.byte_start]
+    }
+    /// after the current cluster.
+    #[inline]
+    pub fn as_remaining_str(&self) -> &'s str {
+        &self.string[self.info.byte_end..]
+    }
+    /// number of clusters, counted every time.
+    #[inline]
+    pub fn len(&self) -> usize {
+        grapheme::graphemes(self.string).count()
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.string.is_empty()
+    }
+
+    /// the other way around, keeps heading.
+    #[inline]
+    pub fn prev(&mut self) -> Option<&'s str> {
+        if self.backwards {
+            self.step_right()
+        } else {
+            self.step_left()
+        }
+    }
+    /// walks from the current cluster.
+    pub fn jump(&mut self, pos: usize) -> Option<&'s str> {
+        let checkpoint = self.info;
+        if !self.info.init {
+            self.step_first()?;
+        }
+        while self.info.pos < pos {
+            if self.step_right().is_none() {
+                self.info = checkpoint;
+                return None;
+            }
+        }
+        while self.info.pos > pos {
+            self.step_left();
+        }
+        Some(self.current())
+    }
+    #[inline]
+    pub fn jump_to_first(&mut self) -> Option<&'s str> {
+        self.info = GraphemeCursorInfo::default();
+        self.step_first()
+    }
+    #[inline]
+    pub fn jump_to_last(&mut self) -> Option<&'s str> {
+        if !self.info.init {
+            self.step_first()?;
+        }
+        while self.step_right().is_some() {}
+        Some(self.current())
+    }
+    /// to the cluster containing the char `char_pos`.
+    pub fn jump_to_char(&mut self, char_pos: usize) -> Option<&'s str> {
+        let checkpoint = self.info;
+        if !self.info.init {
+            self.step_first()?;
+        }
+        while self.info.char_pos > char_pos {
+            self.step_left();
+        }
+        while char_pos >= self.info.char_pos + self.current().chars().count() {
+            if self.step_right().is_none() {
+                self.info = checkpoint;
+                return None;
+            }
+        }
+        Some(self.current())
+    }
+
+    #[inline]
+    pub fn reset(&mut self) {
+        self.info = GraphemeCursorInfo::default();
+    }
+    #[inline]
+    pub fn save(&mut self) {
+        self.saved_info = self.info;
+    }
+    #[inline]
+    pub fn saved(&self) -> &GraphemeCursorInfo {
+        &self.saved_info
+    }
+    #[inline]
+    pub fn load(&mut self) {
+        self.info = self.saved_info;
+    }
+
+    /// a [`StrCursor`](StrCursor) on the first char of the current cluster.
+    #[inline]
+    pub fn to_str_cursor(&self) -> StrCursor<'s> {
+        let mut cursor = StrCursor::new(self.string);
+        if self.info.init {
+            cursor.jump(self.info.char_pos);
+        }
+        cursor
+    }
+}
+
+impl<'s> Iterator for GraphemeCursor<'s> {
+    type Item = &'s str;
+    #[inline]
+    fn next(&mut self) -> Option<&'s str> {
+        if self.backwards {
+            self.step_left()
+        } else {
+            self.step_right()
+        }
+    }
+}
+
+impl<'s, E: Extras<char>> StrCursor<'s, E> {
+    /// a [`GraphemeCursor`](GraphemeCursor) on the cluster containing the current char,
+    /// heading the same way.
+    #[inline]
+    pub fn graphemes(&self) -> GraphemeCursor<'s> {
+        let mut cursor = GraphemeCursor::new(self.as_str());
+        if self.is_init() {
+            cursor.jump_to_char(self.pos());
+        }
+        cursor.set_backwards(self.backwards());
+        cursor
+    }
+}
@@ -3,3 +3,11 @@
 use super::*;
 
 pub mod string;
+
+pub mod graphemes;
+
+pub mod text;
+
+pub mod bstr;
+
+pub mod navigation;
@@ -0,0 +1,207 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// segments of a string, walked from any char.
+trait Segmenter {
+    /// end of the segment starting at `at`.
+    fn next(&self, string: &str, at: usize) -> usize;
+    /// start of the segment ending at `at`.
+    fn prev(&self, string: &str, at: usize) -> usize;
+    /// bytes of what counts in the segment, `None` if nothing.
+    fn unit(&self, segment: &str) -> Option<Range<usize>>;
+}
+
+impl Segmenter for WordMode {
+    #[inline]
+    fn next(&self, string: &str, at: usize) -> usize {
+        words::next_boundary(string, at, *self)
+    }
+    #[inline]
+    fn prev(&self, string: &str, at: usize) -> usize {
+        words::prev_boundary(string, at, *self)
+    }
+    #[inline]
+    fn unit(&self, segment: &str) -> Option<Range<usize>> {
+        words::is_word(segment, *self).then_some(0..segment.len())
+    }
+}
+
+/// trailing and leading spaces aren't a part of a sentence.
+struct Sentences;
+impl Segmenter for Sentences {
+    #[inline]
+    fn next(&self, string: &str, at: usize) -> usize {
+        sentences::next_boundary(string, at)
+    }
+    #[inline]
+    fn prev(&self, string: &str, at: usize) -> usize {
+        sentences::prev_boundary(string, at)
+    }
+    #[inline]
+    fn unit(&self, segment: &str) -> Option<Range<usize>> {
+        let trimmed = segment.trim_start();
+        let start = segment.len() - trimmed.len();
+        let trimmed = trimmed.trim_end();
+        (!trimmed.is_empty()).then(|| start..start + trimmed.len())
+    }
+}
+
+/// lead bytes of the chars where a unit starts, or ends if `ends`,
+/// going away from `lead`, `None` for before the first char.
+#[inline]
+fn find_edge<S: Segmenter>(
+    string: &str,
+    segmenter: &S,
+    lead: Option<usize>,
+    forwards: bool,
+    ends: bool,
+) -> Option<usize> {
+    if string.is_empty() {
+        return None;
+    }
+    let edge = |start: usize, end: usize| {
+        let unit = segmenter.unit(&string[start..end])?;
+        Some(if ends {
+            let last = string[..start + unit.end].chars().next_back()?;
+            start + unit.end - last.len_utf8()
+        } else {
+            start + unit.start
+        })
+    };
+    let mut start = match lead {
+        Some(lead) => segmenter.prev(string, segmenter.next(string, lead)),
+        None if forwards => 0,
+        None => return None,
+    };
+    loop {
+        let end = segmenter.next(string, start);
+        if let Some(edge) = edge(start, end) {
+            let beyond = match lead {
+                None => true,
+                Some(lead) if forwards => edge > lead,
+                Some(lead) => edge < lead,
+            };
+            if beyond {
+                return Some(edge);
+            }
+        }
+        if forwards {
+            if end == string.len() {
+                return None;
+            }
+            start = end;
+        } else {
+            if start == 0 {
+                return None;
+            }
+            start = segmenter.prev(string, start);
+        }
+    }
+}
+
+/// ## word and sentence navigation
+/// * `next_*` heads the same way as the cursor, `prev_*` the other way,
+///   the direction doesn't change,
+/// * the cursor stays put if there's nothing that way.
+impl<'s, E: Extras<char>> StrCursor<'s, E> {
+    // ------ private ------
+
+    #[inline]
+    fn lead(&self) -> Option<usize> {
+        self.is_init().then(|| self.char_lead_pos())
+    }
+    #[inline]
+    fn jump_to_lead(&mut self, lead: usize) -> Option<char> {
+        let bytes = self.as_bytes();
+        let pos = match self.lead() {
+            None => utf::count_chars(&bytes[..lead]),
+            Some(from) if from <= lead => self.pos() + utf::count_chars(&bytes[from..lead]),
+            Some(from) => self.pos() - utf::count_chars(&bytes[lead..from]),
+        };
+        self.jump(pos)
+    }
+    #[inline]
+    fn jump_to_edge<S: Segmenter>(
+        &mut self,
+        segmenter: &S,
+        forwards: bool,
+        ends: bool,
+    ) -> Option<char> {
+        let lead = find_edge(self.as_str(), segmenter, self.lead(), forwards, ends)?;
+        self.jump_to_lead(lead)
+    }
+    #[inline]
+    fn select_at_cursor<S: Segmenter>(&self, segmenter: &S) -> Option<Span> {
+        let (string, lead) = (self.as_str(), self.lead()?);
+        let end = segmenter.next(string, lead);
+        let start = segmenter.prev(string, end);
+        let unit = segmenter.unit(&string[start..end])?;
+        let bytes = start + unit.start..start + unit.end;
+        if !bytes.contains(&lead) {
+            return None;
+        }
+        let chars_before = |byte: usize| match byte <= lead {
+            true => self.pos() - utf::count_chars(&string.as_bytes()[byte..lead]),
+            false => self.pos() + utf::count_chars(&string.as_bytes()[lead..byte]),
+        };
+        Some(Span::new(
+            chars_before(bytes.start)..chars_before(bytes.end),
+            bytes,
+        ))
+    }
+
+    // ------ public ------
+
+    /// to the first char of the next word.
+    #[inline]
+    pub fn next_word_start(&mut self, mode: WordMode) -> Option<char> {
+        self.jump_to_edge(&mode, !self.backwards(), false)
+    }
+    /// to the first char of the previous word.
+    #[inline]
+    pub fn prev_word_start(&mut self, mode: WordMode) -> Option<char> {
+        self.jump_to_edge(&mode, self.backwards(), false)
+    }
+    /// to the last char of the next word.
+    #[inline]
+    pub fn next_word_end(&mut self, mode: WordMode) -> Option<char> {
+        self.jump_to_edge(&mode, !self.backwards(), true)
+    }
+    /// to the last char of the previous word.
+    #[inline]
+    pub fn prev_word_end(&mut self, mode: WordMode) -> Option<char> {
+        self.jump_to_edge(&mode, self.backwards(), true)
+    }
+    /// the word the current char is in, `None` on a space or a punctuation.
+    #[inline]
+    pub fn select_word_at_cursor(&self, mode: WordMode) -> Option<Span> {
+        self.select_at_cursor(&mode)
+    }
+
+    /// to the first char of the next sentence.
+    #[inline]
+    pub fn next_sentence_start(&mut self) -> Option<char> {
+        self.jump_to_edge(&Sentences, !self.backwards(), false)
+    }
+    /// to the first char of the previous sentence.
+    #[inline]
+    pub fn prev_sentence_start(&mut self) -> Option<char> {
+        self.jump_to_edge(&Sentences, self.backwards(), false)
+    }
+    /// to the last char of the next sentence, its terminator usually.
+    #[inline]
+    pub fn next_sentence_end(&mut self) -> Option<char> {
+        self.jump_to_edge(&Sentences, !self.backwards(), true)
+    }
+    /// to the last char of the previous sentence.
+    #[inline]
+    pub fn prev_sentence_end(&mut self) -> Option<char> {
+        self.jump_to_edge(&Sentences, self.backwards(), true)
+    }
+    /// the sentence the current char is in, without the spaces around it.
+    #[inline]
+    pub fn select_sentence_at_cursor(&self) -> Option<Span> {
+        self.select_at_cursor(&Sentences)
+    }
+}
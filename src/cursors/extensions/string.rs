@@ -2,14 +2,29 @@
 
 use super::*;
 
-#[derive(PartialEq, Eq, Clone)]
+#[derive(Clone)]
 pub struct StrCursor<'s, E: Extras<char> = NoneExtras<char>> {
-    cursor: Cursor<'s, u8, NoneExtras<u8>>,
-    len: Option<usize>,
-    info: StrCursorInfo<E>,
-    saved_info: StrCursorInfo<E>,
+    pub(crate) cursor: Cursor<'s, u8, NoneExtras<u8>>,
+    pub(crate) len: Option<usize>,
+    #[cfg(feature = "alloc")]
+    pub(crate) line_index: Option<LineIndex<'s>>,
+    #[cfg(feature = "alloc")]
+    pub(crate) char_index: Option<CharIndex<'s>>,
+    pub(crate) info: StrCursorInfo<E>,
+    pub(crate) saved_info: StrCursorInfo<E>,
 }
 
+/// the cached length and indexes are left out.
+impl<E: Extras<char> + PartialEq> PartialEq for StrCursor<'_, E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cursor == other.cursor
+            && self.info == other.info
+            && self.saved_info == other.saved_info
+    }
+}
+impl<E: Extras<char> + Eq> Eq for StrCursor<'_, E> {}
+
 impl<E: Extras<char>> fmt::Debug for StrCursor<'_, E> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -54,10 +69,14 @@ impl<'s, E: Extras<char>> AsMut<Self> for StrCursor<'s, E> {
 }
 
 #[inline]
-fn str_cursor_new<EXTRAS: Extras<char>>(string: &str) -> StrCursor<EXTRAS> {
+fn str_cursor_new<EXTRAS: Extras<char>>(string: &str) -> StrCursor<'_, EXTRAS> {
     StrCursor {
         cursor: Cursor::new(string.as_bytes()),
         len: None,
+        #[cfg(feature = "alloc")]
+        line_index: None,
+        #[cfg(feature = "alloc")]
+        char_index: None,
         info: StrCursorInfo::new(),
         saved_info: StrCursorInfo::new(),
     }
@@ -69,7 +88,7 @@ impl<'s> StrCursor<'s, NoneExtras<char>> {
         str_cursor_new(string)
     }
     #[inline]
-    pub fn new_with_extras<EXTRAS: Extras<char>>(string: &str) -> StrCursor<EXTRAS> {
+    pub fn new_with_extras<EXTRAS: Extras<char>>(string: &str) -> StrCursor<'_, EXTRAS> {
         str_cursor_new(string)
     }
 }
@@ -96,11 +115,14 @@ impl<'s, E: Extras<char>> StrCursor<'s, E> {
     fn set_char_start_pos(&mut self, val: usize) {
         self.info.char_start_pos = val;
     }
-    /// load code point
+    /// load code point.
+    /// * swaps the two ends of the current char, so turning twice is a no-op.
     #[inline]
     fn load_char_start_pos(&mut self) {
-        if self.pos_as_bytes() != self.char_start_pos() {
+        let byte_pos = self.pos_as_bytes();
+        if byte_pos != self.char_start_pos() {
             self.cursor.unsafe_set_pos(self.char_start_pos());
+            self.set_char_start_pos(byte_pos);
         }
     }
     /// purely sets position + blushes extras. returns current().
@@ -118,13 +140,69 @@ impl<'s, E: Extras<char>> StrCursor<'s, E> {
     fn unsafe_set_pos_as_bytes(&mut self, new_pos: usize) {
         self.cursor.unsafe_set_pos(new_pos);
     }
+    /// [`STEPWISE`](Extras::STEPWISE) extras see the chars since the last one they saw first.
     #[inline]
     fn blush_extras(&mut self) {
-        if !self.noeffects() {
-            self.info.extras.change(&self.current(), self.pos());
+        if self.noeffects() {
+            return;
+        }
+        if E::STEPWISE {
+            self.step_extras(self.info.extras_pos);
+        }
+        self.info.extras_pos = Some((self.pos(), self.char_lead_pos()));
+        self.info.extras.change(&self.current(), self.pos());
+    }
+    /// the first byte of the current char, whichever way it's heading.
+    #[inline]
+    pub(crate) fn char_lead_pos(&self) -> usize {
+        self.pos_as_bytes().min(self.char_start_pos())
+    }
+    /// reports the chars between `from` (pos, lead byte) and the current char
+    /// to [`STEPWISE`](Extras::STEPWISE) extras, `None` if nothing has been seen yet.
+    #[inline]
+    fn step_extras(&mut self, from: Option<(usize, usize)>) {
+        let (string, lead) = (self.as_str(), self.char_lead_pos());
+        let width = |at: usize| string[at..].chars().next().map_or(0, char::len_utf8);
+        match from {
+            Some((_, from_lead)) if from_lead == lead => {}
+            None => {
+                for (pos, ch) in string[..lead].chars().enumerate() {
+                    self.info.extras.change(&ch, pos);
+                }
+            }
+            Some((from_pos, from_lead)) if from_lead < lead => {
+                let between = string[from_lead + width(from_lead)..lead].chars();
+                for (i, ch) in between.enumerate() {
+                    self.info.extras.change(&ch, from_pos + 1 + i);
+                }
+            }
+            Some((from_pos, from_lead)) => {
+                let between = string[lead + width(lead)..from_lead].chars().rev();
+                for (i, ch) in between.enumerate() {
+                    self.info.extras.change(&ch, from_pos - 1 - i);
+                }
+            }
         }
     }
 
+    /// first byte of the char `pos` with no walking, on an ascii source
+    /// or from the nearest sample of the [`char_index`](StrCursor::char_index).
+    /// * `None` if it has to walk, or if walking from here is shorter.
+    #[inline]
+    fn known_byte_pos(&self, pos: usize) -> Option<usize> {
+        if self.len == Some(self.len_as_bytes()) {
+            return Some(pos);
+        }
+        #[cfg(feature = "alloc")]
+        if let Some(index) = &self.char_index {
+            if self.is_init() && self.pos().abs_diff(pos) < index.stride() {
+                return None;
+            }
+            return index.byte_pos(pos);
+        }
+        None
+    }
+
     #[inline]
     fn jump_to_added(&mut self, rhs: usize) -> Option<char> {
         self.jump_to_offset(rhs as isize)
@@ -139,23 +217,74 @@ impl<'s, E: Extras<char>> StrCursor<'s, E> {
     pub fn unwrapped_next(&mut self) -> char {
         self.next().unwrap()
     }
+    /// built the first time, then kept like [`len`](TextCursorTrait::len).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn line_index(&mut self) -> &LineIndex<'s> {
+        let string = self.as_str();
+        self.line_index
+            .get_or_insert_with(|| LineIndex::new(string))
+    }
+    /// built the first time, then kept like [`line_index`](StrCursor::line_index),
+    /// `jump` starts from its nearest sample from then on, and `len` is known.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn char_index(&mut self) -> &CharIndex<'s> {
+        let string = self.as_str();
+        let index = self
+            .char_index
+            .get_or_insert_with(|| CharIndex::new(string));
+        self.len = Some(index.len());
+        index
+    }
+    /// builds the [`char_index`](StrCursor::char_index) right away,
+    /// with a sample every `stride` chars.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn with_char_index(mut self, stride: usize) -> Self {
+        let index = CharIndex::with_stride(self.as_str(), stride);
+        self.len = Some(index.len());
+        self.char_index = Some(index);
+        self
+    }
+    /// jumps to the char at the zero-based (line, column) of the [`line_index`](StrCursor::line_index).
+    /// * the column can be the line ending, `None` if there's no char there.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn jump_to_line_col(&mut self, line: usize, column: usize) -> Option<char> {
+        let index = self.line_index();
+        let (pos, len) = (index.char_pos(line, column)?, index.len());
+        if pos >= len {
+            return None;
+        }
+        self.len = Some(len);
+        self.jump(pos)
+    }
 }
 
 impl<'s, E: Extras<char>> Iterator for StrCursor<'s, E> {
     type Item = char;
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_init() && self.backwards() {
+            // the first char either way, like the other cursors.
+            let noeffects = mem::replace(&mut self.info.noeffects, true);
+            let ch = self.jump(0);
+            self.info.noeffects = noeffects;
+            self.blush_extras();
+            return ch;
+        }
         // 1 byte next and then scanning char
         // save the first code point pos
         self.cursor.next()?;
         let byte_pos = self.pos_as_bytes();
         let (ch, pos) = match self.backwards() {
             false => {
-                let ch = utf::next_char(&mut self.cursor)?;
+                let ch = Utf8::next_char(&mut self.cursor)?;
                 (ch, self.pos().checked_add(1)?)
             }
             true => {
-                let ch = utf::next_back_char(&mut self.cursor)?;
+                let ch = Utf8::next_back_char(&mut self.cursor)?;
                 (ch, self.pos().checked_sub(1)?)
             }
         };
@@ -171,7 +300,11 @@ impl<'s, E: Extras<char>> Iterator for StrCursor<'s, E> {
     }
 }
 
-impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
+impl<'s, E: Extras<char>> TextCursorTrait<'s, E> for StrCursor<'s, E> {
+    type Decoder = Utf8;
+    type Text = str;
+    type Info = StrCursorInfo<E>;
+
     #[inline]
     fn is_init(&self) -> bool {
         self.current() != EOF_CHAR
@@ -198,14 +331,6 @@ impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
     fn pos(&self) -> usize {
         self.info.pos
     }
-    #[inline]
-    fn pos_as_bytes(&self) -> usize {
-        self.cursor.pos()
-    }
-    #[inline]
-    fn char_start_pos(&self) -> usize {
-        self.info.char_start_pos
-    }
     /// if you've never tried it before,
     /// this method will create remainder iterator and then
     /// consume it to count number of chars.
@@ -213,12 +338,10 @@ impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
     fn len(&mut self) -> usize {
         if let Some(n) = self.len {
             n
+        } else if self.is_empty() {
+            0
         } else {
-            let offset = self
-                .as_right_side_bytes()
-                .iter()
-                .filter(|&&byte| !utf::utf8_is_cont_byte(byte))
-                .count();
+            let offset = utf::count_chars(self.as_right_side_bytes());
             self.len = Some(self.pos() + offset + 1);
             self.len()
         }
@@ -228,16 +351,8 @@ impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
         self.len.is_some()
     }
     #[inline]
-    fn len_as_bytes(&self) -> usize {
-        self.cursor.len()
-    }
-    #[inline]
-    fn is_empty(&self) -> bool {
-        self.as_bytes().len() == 0
-    }
-    #[inline]
-    fn as_bytes(&self) -> &'s [u8] {
-        self.cursor.as_slice()
+    fn as_text(&self) -> &'s str {
+        utf::from_utf8_unchecked(self.cursor.as_slice())
     }
     #[inline]
     fn extras(&self) -> &E {
@@ -266,100 +381,109 @@ impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
     fn saved(&self) -> &StrCursorInfo<E> {
         &self.saved_info
     }
+    /// loads the inner byte position and direction as well.
     #[inline]
     fn load(&mut self) {
         self.info = self.saved_info.clone();
+        self.cursor.info = self.info.inner.clone();
     }
     #[inline]
-    fn jump_to_last(&mut self) -> char {
-        let last_pos = self.len().saturating_sub(1);
-        self.jump(last_pos).unwrap()
+    fn push_checkpoint(&mut self) -> Checkpoint<StrCursorInfo<E>> {
+        self.info.inner = self.cursor.info.clone();
+        Checkpoint::new(self.info.clone())
+    }
+    #[inline]
+    fn restore(&mut self, checkpoint: Checkpoint<StrCursorInfo<E>>) {
+        self.info = checkpoint.into_info();
+        self.cursor.info = self.info.inner.clone();
+    }
+    /// the current char, empty before the first one.
+    #[inline]
+    fn span_of_current(&self) -> Span {
+        char_span(
+            self.pos(),
+            self.pos_as_bytes(),
+            self.char_start_pos(),
+            self.is_init(),
+        )
     }
     /// - if you had tried `len` before,
     /// - this method does more performance.
     /// * *[inline function]*
     #[inline]
     fn jump(&mut self, pos: usize) -> Option<char> {
-        let ch = match pos {
-            _ if self.is_init() && pos == self.pos() => return Some(self.current()),
-            0 => {
-                self.unsafe_set_pos_as_bytes(0);
-                let ch = utf::next_char(&mut self.cursor)?;
-                if self.backwards() {
-                    self.set_char_start_pos(self.pos_as_bytes());
-                    self.unsafe_set_pos_as_bytes(0);
-                } else {
-                    self.set_char_start_pos(0);
-                }
-                ch
-            }
+        if self.is_init() && pos == self.pos() {
+            return Some(self.current());
+        }
+        if matches!(self.len, Some(len) if pos >= len) {
+            return None;
+        }
+        // the target first, nothing moves if there's no char there.
+        let new_byte_pos = match pos {
+            _ if self.is_empty() => return None,
+            0 => 0,
             _ if matches!(self.len, Some(len) if len.saturating_sub(1) == pos) => {
-                let byte_last_pos = self.len_as_bytes().saturating_sub(1);
-                self.unsafe_set_pos_as_bytes(byte_last_pos);
-                let ch = utf::next_back_char(&mut self.cursor)?;
-                if self.backwards() {
-                    self.set_char_start_pos(byte_last_pos);
-                } else {
-                    self.set_char_start_pos(self.pos_as_bytes());
-                    self.unsafe_set_pos_as_bytes(byte_last_pos);
-                }
-                ch
+                utf::nth_char_start_back(self.as_bytes(), 0)?
             }
-            _ => {
-                // =-=-=-=-=-=-=-=-=-=-=-=-=-=
-                let (dist, is_dist) = self.pos().detailed_diff(pos);
-                let new_byte_pos = match is_dist {
-                    Ordering::Greater => {
-                        let (offset, _) = self
-                            .cursor
-                            .as_right_side_slice()
-                            .iter()
-                            .enumerate()
-                            .filter(|(_, &byte)| !utf::utf8_is_cont_byte(byte))
-                            .take(dist)
-                            .last()?;
-                        self.cursor.pos() + offset + 1
-                    }
-                    Ordering::Equal => return Some(self.current()),
-                    Ordering::Less => {
-                        let (offset, _) = self
-                            .cursor
-                            .as_left_side_slice_include_current()
-                            .iter()
-                            .rev()
-                            .enumerate()
-                            .filter(|(_, &byte)| !utf::utf8_is_cont_byte(byte))
-                            .take(dist + 1)
-                            .last()?;
-                        self.cursor.pos() - offset
+            _ => match self.known_byte_pos(pos) {
+                Some(byte_pos) => byte_pos,
+                None => {
+                    let (dist, is_dist) = self.pos().detailed_diff(pos);
+                    match is_dist {
+                        Ordering::Greater => {
+                            let right = self.cursor.as_right_side_slice();
+                            self.cursor.pos() + utf::nth_char_start(right, dist - 1)? + 1
+                        }
+                        Ordering::Equal => return Some(self.current()),
+                        Ordering::Less => {
+                            let left = self.cursor.as_left_side_slice_include_current();
+                            utf::nth_char_start_back(left, dist)?
+                        }
                     }
-                };
-                self.unsafe_set_pos_as_bytes(new_byte_pos);
-
-                // =-=-=-=-=-=-=-=-=-=-=-=-=-=
-                if !self.cursor.is_init() {
-                    self.cursor.set_init(true);
-                }
-                let ch;
-                if self.backwards() {
-                    ch = utf::next_char(&mut self.cursor)?;
-                    self.set_char_start_pos(self.cursor.pos());
-                    self.unsafe_set_pos_as_bytes(new_byte_pos);
-                } else {
-                    self.set_char_start_pos(new_byte_pos);
-                    ch = utf::next_char(&mut self.cursor)?;
-                }
-                if self.pos_as_bytes() == self.len_as_bytes().saturating_sub(1) {
-                    self.len = Some(pos); // sets length
                 }
-                ch
-            }
+            },
         };
+        let first = !self.is_init();
+        // decoding bumps the inner cursor.
+        if !self.cursor.is_init() {
+            self.cursor.set_init(true);
+        }
+        self.unsafe_set_pos_as_bytes(new_byte_pos);
+        let ch;
+        if self.backwards() {
+            ch = Utf8::next_char(&mut self.cursor)?;
+            self.set_char_start_pos(self.cursor.pos());
+            self.unsafe_set_pos_as_bytes(new_byte_pos);
+        } else {
+            self.set_char_start_pos(new_byte_pos);
+            ch = Utf8::next_char(&mut self.cursor)?;
+        }
+        if self.char_lead_pos() + ch.len_utf8() == self.len_as_bytes() {
+            self.len = Some(pos + 1); // sets length
+        }
         self.set_current(ch);
+        if E::STEPWISE && first && pos == 0 {
+            self.blush_extras();
+        }
         self.set_pos(pos)
     }
 }
 
+impl<'s, E: Extras<char>> StrCursorTrait<'s, E> for StrCursor<'s, E> {
+    #[inline]
+    fn pos_as_bytes(&self) -> usize {
+        self.cursor.pos()
+    }
+    #[inline]
+    fn char_start_pos(&self) -> usize {
+        self.info.char_start_pos
+    }
+    #[inline]
+    fn len_as_bytes(&self) -> usize {
+        self.cursor.len()
+    }
+}
+
 // ------- WARNING: isize -------
 
 impl<'s, E: Extras<char>> AddAssign<usize> for StrCursor<'s, E> {
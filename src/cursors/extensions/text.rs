@@ -0,0 +1,352 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// moves by chars over any [`Decoder`](Decoder)'s units,
+/// UTF-8 or UTF-16 bytes, `&[u16]`, Latin-1 or ASCII.
+/// * `pos()` counts chars, `pos_as_units()` is the first unit of the current char,
+/// * the slices and the `Span` bytes are in units,
+/// * it's a [`TextCursorTrait`](TextCursorTrait) like a [`StrCursor`](StrCursor), which adds the `&str` slices.
+///
+/// ```
+/// use cursor::*;
+///
+/// let units: Vec<u16> = "a😀b".encode_utf16().collect();
+/// let mut cursor = TextCursor::<Utf16>::new(&units);
+/// assert_eq!(cursor.next(), Some('a'));
+/// assert_eq!(cursor.next(), Some('😀'));
+/// assert_eq!((cursor.pos(), cursor.pos_as_units()), (1, 1));
+/// assert_eq!(cursor.current_units(), &units[1..3]);
+/// assert_eq!(cursor.next(), Some('b'));
+/// assert_eq!(cursor.prev(), Some('😀'));
+/// ```
+pub struct TextCursor<'s, D: Decoder, E: Extras<char> = NoneExtras<char>> {
+    units: &'s [D::Unit],
+    len: Option<usize>,
+    #[cfg(feature = "alloc")]
+    char_index: Option<CharIndex<'s, Text<D>>>,
+    info: TextCursorInfo<E>,
+    saved_info: TextCursorInfo<E>,
+}
+impl<D: Decoder, E: Extras<char>> Clone for TextCursor<'_, D, E> {
+    #[inline]
+    fn clone(&self) -> Self {
+        TextCursor {
+            units: self.units,
+            len: self.len,
+            #[cfg(feature = "alloc")]
+            char_index: self.char_index.clone(),
+            info: self.info.clone(),
+            saved_info: self.saved_info.clone(),
+        }
+    }
+}
+impl<D: Decoder, E: Extras<char>> fmt::Debug for TextCursor<'_, D, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TextCursor")
+            .field(&self.as_preserved_units())
+            .field(&self.current())
+            .field(&self.as_remaining_units())
+            .finish()
+    }
+}
+impl<D: Decoder, E: Extras<char>> ToExtras<E> for TextCursor<'_, D, E> {
+    type Input = char;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+impl<D: Decoder, E: Extras<char>> AsRef<[D::Unit]> for TextCursor<'_, D, E> {
+    #[inline]
+    fn as_ref(&self) -> &[D::Unit] {
+        self.units
+    }
+}
+
+#[inline]
+fn text_cursor_new<D: Decoder, EXTRAS: Extras<char>>(
+    units: &[D::Unit],
+) -> TextCursor<'_, D, EXTRAS> {
+    TextCursor {
+        units,
+        len: None,
+        #[cfg(feature = "alloc")]
+        char_index: None,
+        info: TextCursorInfo::new(),
+        saved_info: TextCursorInfo::new(),
+    }
+}
+impl<'s, D: Decoder> TextCursor<'s, D, NoneExtras<char>> {
+    #[inline]
+    pub fn new(units: &'s [D::Unit]) -> Self {
+        text_cursor_new(units)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<char>>(units: &[D::Unit]) -> TextCursor<'_, D, EXTRAS> {
+        text_cursor_new(units)
+    }
+}
+
+impl<'s, D: Decoder, E: Extras<char>> TextCursor<'s, D, E> {
+    // ------ private ------
+
+    /// decodes the next char on the `right` or the left, no effects.
+    #[inline]
+    fn step(&mut self, right: bool) -> Option<char> {
+        let info = &mut self.info;
+        let init = info.unit_end > info.unit_start;
+        let (ch, start, end) = match (init, right) {
+            (false, _) => {
+                let (ch, width) = D::decode(self.units, 0)?;
+                (ch, 0, width)
+            }
+            (true, true) => {
+                let Some((ch, width)) = D::decode(self.units, info.unit_end) else {
+                    self.len = Some(info.pos + 1);
+                    return None;
+                };
+                info.pos += 1;
+                (ch, info.unit_end, info.unit_end + width)
+            }
+            (true, false) => {
+                let (ch, width) = D::decode_back(self.units, info.unit_start)?;
+                info.pos -= 1;
+                (ch, info.unit_start - width, info.unit_start)
+            }
+        };
+        info.unit_start = start;
+        info.unit_end = end;
+        info.current = ch;
+        Some(ch)
+    }
+    /// [`STEPWISE`](Extras::STEPWISE) extras see the chars since the last one they saw first.
+    #[inline]
+    fn blush_extras(&mut self) {
+        if self.noeffects() {
+            return;
+        }
+        if E::STEPWISE {
+            self.step_extras();
+        }
+        self.info.extras_pos = Some((self.info.pos, self.info.unit_start));
+        self.info.extras.change(&self.info.current, self.info.pos);
+    }
+    /// reports the chars between the last one the extras saw and the current one.
+    #[inline]
+    fn step_extras(&mut self) {
+        let (start, end) = (self.info.unit_start, self.info.unit_end);
+        match self.info.extras_pos {
+            None => {
+                for (pos, ch) in D::chars(&self.units[..start]).enumerate() {
+                    self.info.extras.change(&ch, pos);
+                }
+            }
+            Some((seen_pos, seen_start)) if seen_start < start => {
+                let seen_end = seen_start + D::decode(self.units, seen_start).map_or(0, |(_, w)| w);
+                for (i, ch) in D::chars(&self.units[seen_end..start]).enumerate() {
+                    self.info.extras.change(&ch, seen_pos + 1 + i);
+                }
+            }
+            Some((seen_pos, seen_start)) if start < seen_start => {
+                for (i, ch) in D::chars(&self.units[end..seen_start]).rev().enumerate() {
+                    self.info.extras.change(&ch, seen_pos - 1 - i);
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    /// first unit of the char `pos`, from the nearest sample of the [`char_index`](TextCursor::char_index),
+    /// or walking from the current char or the first one, whichever is nearer.
+    #[inline]
+    fn unit_pos(&self, pos: usize) -> Option<usize> {
+        let len = self.units.len();
+        if self.len == Some(len) {
+            // one unit per char.
+            return Some(pos);
+        }
+        #[cfg(feature = "alloc")]
+        if let Some(index) = &self.char_index {
+            if !(self.is_init() && self.pos().abs_diff(pos) < index.stride()) {
+                return index.byte_pos(pos);
+            }
+        }
+        let text = Text::<D>::new(self.units);
+        let (from, start, end) = (self.info.pos, self.info.unit_start, self.info.unit_end);
+        if !self.is_init() || pos < from.abs_diff(pos) {
+            return text.nth_char_start(pos);
+        }
+        if pos > from {
+            let skip = text.slice(end..len).nth_char_start(pos - from - 1)?;
+            return Some(end + skip);
+        }
+        let mut at = start;
+        for _ in pos..from {
+            (_, at) = text.char_before(at)?;
+        }
+        Some(at)
+    }
+
+    // ------ public ------
+
+    #[inline]
+    pub fn unwrapped_next(&mut self) -> char {
+        self.next().unwrap()
+    }
+    #[inline]
+    pub fn set_backwards(&mut self, backwards: bool) {
+        self.info.backwards = backwards;
+    }
+    #[inline]
+    pub fn info(&self) -> &TextCursorInfo<E> {
+        &self.info
+    }
+    /// built the first time, then kept like [`len`](TextCursorTrait::len),
+    /// `jump` starts from its nearest sample from then on, and `len` is known.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn char_index(&mut self) -> &CharIndex<'s, Text<D>> {
+        let text = Text::<D>::new(self.units);
+        let index = self.char_index.get_or_insert_with(|| CharIndex::new(text));
+        self.len = Some(index.len());
+        index
+    }
+    /// builds the [`char_index`](TextCursor::char_index) right away,
+    /// with a sample every `stride` chars.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn with_char_index(mut self, stride: usize) -> Self {
+        let index = CharIndex::with_stride(Text::<D>::new(self.units), stride);
+        self.len = Some(index.len());
+        self.char_index = Some(index);
+        self
+    }
+}
+
+impl<'s, D: Decoder + 's, E: Extras<char>> TextCursorTrait<'s, E> for TextCursor<'s, D, E> {
+    type Decoder = D;
+    type Text = Text<D>;
+    type Info = TextCursorInfo<E>;
+
+    /// number of chars, counted once and kept.
+    #[inline]
+    fn len(&mut self) -> usize {
+        if let Some(n) = self.len {
+            return n;
+        }
+        let n = if self.is_init() {
+            self.info.pos + 1 + D::count_chars(&self.units[self.info.unit_end..])
+        } else {
+            D::count_chars(self.units)
+        };
+        self.len = Some(n);
+        n
+    }
+    #[inline]
+    fn is_len(&self) -> bool {
+        self.len.is_some()
+    }
+    #[inline]
+    fn is_init(&self) -> bool {
+        self.info.unit_end > self.info.unit_start
+    }
+    /// if `next` or `jump` can effect the [`Extras`](Extras).
+    #[inline]
+    fn noeffects(&self) -> bool {
+        self.info.noeffects
+    }
+    #[inline]
+    fn noeffects_mut(&mut self) -> &mut bool {
+        &mut self.info.noeffects
+    }
+    #[inline]
+    fn backwards(&self) -> bool {
+        self.info.backwards
+    }
+    #[inline]
+    fn turnaround(&mut self) {
+        self.info.backwards = !self.info.backwards;
+    }
+    #[inline]
+    fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    fn current(&self) -> char {
+        self.info.current
+    }
+    #[inline]
+    fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    fn extras_mut(&mut self) -> &mut E {
+        &mut self.info.extras
+    }
+    #[inline]
+    fn reset(&mut self) {
+        self.info.reset();
+    }
+    #[inline]
+    fn save(&mut self) {
+        self.saved_info = self.info.clone();
+    }
+    #[inline]
+    fn saved(&self) -> &TextCursorInfo<E> {
+        &self.saved_info
+    }
+    /// loads the direction as well.
+    #[inline]
+    fn load(&mut self) {
+        self.info = self.saved_info.clone();
+    }
+    #[inline]
+    fn push_checkpoint(&mut self) -> Checkpoint<TextCursorInfo<E>> {
+        Checkpoint::new(self.info.clone())
+    }
+    #[inline]
+    fn restore(&mut self, checkpoint: Checkpoint<TextCursorInfo<E>>) {
+        self.info = checkpoint.into_info();
+    }
+    #[inline]
+    fn as_text(&self) -> &'s Text<D> {
+        Text::new(self.units)
+    }
+    #[inline]
+    fn span_of_current(&self) -> Span {
+        self.info.span()
+    }
+    /// decodes the char at `pos` only, `None` and no moves past the end.
+    /// * [`STEPWISE`](Extras::STEPWISE) extras still see every char on the way.
+    fn jump(&mut self, pos: usize) -> Option<char> {
+        if self.is_init() && pos == self.pos() {
+            return Some(self.current());
+        }
+        if matches!(self.len, Some(len) if pos >= len) {
+            return None;
+        }
+        let start = self.unit_pos(pos)?;
+        let (ch, width) = D::decode(self.units, start)?;
+        if start + width == self.units.len() {
+            self.len = Some(pos + 1);
+        }
+        let info = &mut self.info;
+        info.pos = pos;
+        info.unit_start = start;
+        info.unit_end = start + width;
+        info.current = ch;
+        self.blush_extras();
+        Some(ch)
+    }
+}
+
+impl<D: Decoder, E: Extras<char>> Iterator for TextCursor<'_, D, E> {
+    type Item = char;
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        let ch = self.step(!self.backwards())?;
+        self.blush_extras();
+        Some(ch)
+    }
+}
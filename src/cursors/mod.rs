@@ -3,7 +3,28 @@
 use super::*;
 
 mod extensions;
+pub use extensions::bstr::*;
+pub use extensions::graphemes::*;
 pub use extensions::string::*;
+pub use extensions::text::*;
+
+mod mutable;
+pub use mutable::*;
+
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+pub use owned::*;
+
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::*;
+
+#[cfg(feature = "std")]
+mod read;
+#[cfg(feature = "std")]
+pub use read::*;
 
 // ---------------------------
 
@@ -53,7 +74,7 @@ impl<T, E: Extras<T>> AsMut<Self> for Cursor<'_, T, E> {
 }
 
 #[inline]
-fn cursor_new<T, EXTRAS: Extras<T>>(slice: &[T]) -> Cursor<T, EXTRAS> {
+fn cursor_new<T, EXTRAS: Extras<T>>(slice: &[T]) -> Cursor<'_, T, EXTRAS> {
     Cursor {
         slice,
         len: slice.len(),
@@ -67,7 +88,7 @@ impl<'s, T: 's> Cursor<'s, T, NoneExtras<T>> {
         cursor_new(slice)
     }
     #[inline]
-    pub fn new_with_extras<EXTRAS: Extras<T>>(slice: &[T]) -> Cursor<T, EXTRAS> {
+    pub fn new_with_extras<EXTRAS: Extras<T>>(slice: &[T]) -> Cursor<'_, T, EXTRAS> {
         cursor_new(slice)
     }
 }
@@ -94,17 +115,25 @@ impl<'s, T: 's, E: Extras<T>> Cursor<'s, T, E> {
         if !self.is_init() {
             self.set_init(true);
         }
-
         self.info.pos = new_pos;
 
         self.blush_extras();
         Some(self.current())
     }
+    /// [`STEPWISE`](Extras::STEPWISE) extras see the items since the last one they saw first.
     #[inline]
     fn blush_extras(&mut self) {
-        if !self.noeffects() {
-            self.info.extras.change(self.current(), self.pos());
+        if self.noeffects() {
+            return;
         }
+        let pos = self.pos();
+        if E::STEPWISE {
+            for at in stepped(self.info.extras_pos, pos) {
+                self.info.extras.change(&self.slice[at], at);
+            }
+        }
+        self.info.extras_pos = Some(pos);
+        self.info.extras.change(self.current(), pos);
     }
 
     #[inline]
@@ -132,7 +161,13 @@ impl<'s, T, E: Extras<T>> Iterator for Cursor<'s, T, E> {
     fn next(&mut self) -> Option<Self::Item> {
         match self.backwards() {
             _ if !self.is_init() => {
+                if self.slice.is_empty() {
+                    return None;
+                }
                 self.set_init(true);
+                if E::STEPWISE {
+                    self.blush_extras();
+                }
                 Some(self.current())
             }
             false => self.set_pos(self.pos().checked_add(1)?),
@@ -209,6 +244,16 @@ impl<'s, T: 's, E: Extras<T>> CursorTrait<'s, T, E> for Cursor<'s, T, E> {
     fn load(&mut self) {
         self.info = self.saved_info.clone();
     }
+    #[inline]
+    fn push_checkpoint(&mut self) -> Checkpoint<CursorInfo<T, E>> {
+        let mut info = self.info.clone();
+        info.noeffects = self.info.noeffects;
+        Checkpoint::new(info)
+    }
+    #[inline]
+    fn restore(&mut self, checkpoint: Checkpoint<CursorInfo<T, E>>) {
+        self.info = checkpoint.into_info();
+    }
 
     #[inline]
     fn jump(&mut self, pos: usize) -> Option<&'s T> {
@@ -0,0 +1,592 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// same navigation with [`Cursor`](Cursor), but over `&mut [T]`.
+/// * writes (`set`, `current_mut`, `swap_with_offset`, `fill_range`)
+/// * are reported to the [`Extras`](Extras) like moves are.
+#[derive(PartialEq, Eq)]
+pub struct CursorMut<'s, T: 's, E: Extras<T> = NoneExtras<T>> {
+    slice: &'s mut [T],
+    len: usize,
+    info: CursorInfo<T, E>,
+    saved_info: CursorInfo<T, E>,
+}
+impl<T: fmt::Debug, E: Extras<T>> fmt::Debug for CursorMut<'_, T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorMut")
+            .field(&self.as_preserved_slice())
+            .field(&self.current())
+            .field(&self.as_remaining_slice())
+            .finish()
+    }
+}
+impl<T, E: Extras<T>> ToExtras<E> for CursorMut<'_, T, E> {
+    type Input = T;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+/// this would reset the newer cursor
+impl<T, E: Extras<T>> ToCursorMut<T, E> for CursorMut<'_, T, E> {}
+impl<T, E: Extras<T>> AsRef<[T]> for CursorMut<'_, T, E> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, E: Extras<T>> AsMut<[T]> for CursorMut<'_, T, E> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_slice_mut()
+    }
+}
+/// keeps the position, direction, extras and saved info.
+impl<'s, T, E: Extras<T>> From<CursorMut<'s, T, E>> for Cursor<'s, T, E> {
+    #[inline]
+    fn from(cursor: CursorMut<'s, T, E>) -> Self {
+        cursor.into_cursor()
+    }
+}
+
+#[inline]
+fn cursor_mut_new<T, EXTRAS: Extras<T>>(slice: &mut [T]) -> CursorMut<'_, T, EXTRAS> {
+    CursorMut {
+        len: slice.len(),
+        slice,
+        info: CursorInfo::new(),
+        saved_info: CursorInfo::new(),
+    }
+}
+impl<'s, T: 's> CursorMut<'s, T, NoneExtras<T>> {
+    #[inline]
+    pub fn new(slice: &'s mut [T]) -> Self {
+        cursor_mut_new(slice)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<T>>(slice: &mut [T]) -> CursorMut<'_, T, EXTRAS> {
+        cursor_mut_new(slice)
+    }
+}
+
+impl<'s, T: 's, E: Extras<T>> CursorMut<'s, T, E> {
+    // ------ private ------
+    #[inline]
+    fn set_init(&mut self, val: bool) {
+        self.info.init = val;
+    }
+    #[inline]
+    fn set_pos(&mut self, new_pos: usize) -> Option<&T> {
+        if new_pos == self.pos() {
+            return Some(self.current());
+        } else if new_pos >= self.len() {
+            return None;
+        }
+
+        if !self.is_init() {
+            self.set_init(true);
+        }
+        self.info.pos = new_pos;
+
+        self.blush_move();
+        Some(self.current())
+    }
+    /// reports the move to the current item,
+    /// [`STEPWISE`](Extras::STEPWISE) extras see the items since the last move they saw first.
+    #[inline]
+    fn blush_move(&mut self) {
+        if self.noeffects() {
+            return;
+        }
+        let pos = self.pos();
+        if E::STEPWISE {
+            for at in stepped(self.info.extras_pos, pos) {
+                self.blush_extras(at);
+            }
+        }
+        self.info.extras_pos = Some(pos);
+        self.blush_extras(pos);
+    }
+    /// reports `slice[pos]` to the extras.
+    #[inline]
+    fn blush_extras(&mut self, pos: usize) {
+        if !self.noeffects() {
+            self.info.extras.change(&self.slice[pos], pos);
+        }
+    }
+
+    // ------ public ------
+    /// * WARNING: directly sets position. no effects.
+    #[inline]
+    pub fn unsafe_set_pos(&mut self, new_pos: usize) {
+        self.info.pos = new_pos;
+    }
+    /// consumes the mutable borrow and continues as a [`Cursor`](Cursor).
+    #[inline]
+    pub fn into_cursor(self) -> Cursor<'s, T, E> {
+        Cursor {
+            slice: self.slice,
+            len: self.len,
+            info: self.info,
+            saved_info: self.saved_info,
+        }
+    }
+
+    #[inline]
+    pub fn item_size(&self) -> usize {
+        mem::size_of::<T>()
+    }
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        0..self.len()
+    }
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.info.init
+    }
+
+    /// if `next`, `jump` or any write can effect the [`Extras`](Extras).
+    #[inline]
+    pub fn noeffects(&self) -> bool {
+        self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_mut(&mut self) -> &mut bool {
+        &mut self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_on(&mut self) {
+        self.info.noeffects = true;
+    }
+    #[inline]
+    pub fn noeffects_off(&mut self) {
+        self.info.noeffects = false;
+    }
+
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.info.backwards
+    }
+    #[inline]
+    pub fn backwards_mut(&mut self) -> &mut bool {
+        &mut self.info.backwards
+    }
+    #[inline]
+    pub fn turnaround(&mut self) {
+        self.info.backwards = !self.info.backwards;
+    }
+    #[inline]
+    pub fn head_to_left(&mut self) {
+        if !self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn head_to_right(&mut self) {
+        if self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn head_to_pos(&mut self, pos: usize) {
+        match self.pos().cmp(&pos) {
+            Ordering::Greater => self.head_to_left(),
+            Ordering::Equal => {}
+            Ordering::Less => self.head_to_right(),
+        }
+    }
+
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn extras_mut(&mut self) -> &mut E {
+        &mut self.info.extras
+    }
+    /// cloning `saved().extras` to `self.extras()`.
+    #[inline]
+    pub fn to_range_extras(&self) -> Range<E> {
+        self.saved().extras.clone()..self.extras().clone()
+    }
+
+    /// excepts saved_info.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.info.reset();
+    }
+    #[inline]
+    pub fn save(&mut self) {
+        self.saved_info = self.info.clone();
+    }
+    #[inline]
+    pub fn saved(&self) -> &CursorInfo<T, E> {
+        &self.saved_info
+    }
+    /// loads position and extras only, written items stay written.
+    #[inline]
+    pub fn load(&mut self) {
+        self.info = self.saved_info.clone();
+    }
+
+    // ------ SLICE ------
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.slice
+    }
+    /// * WARNING: writing through this slice has no effects.
+    #[inline]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        self.slice
+    }
+    /// saved pos to current pos.
+    #[inline]
+    pub fn as_slice_loaded(&self) -> &[T] {
+        let pos = self.pos();
+        let saved_pos = self.saved().pos;
+        match pos.cmp(&saved_pos) {
+            Ordering::Greater => &self.slice[saved_pos..pos.saturating_add(1)],
+            Ordering::Equal => &self.slice[pos..pos.saturating_add(1)],
+            Ordering::Less => &self.slice[pos..saved_pos.saturating_add(1)],
+        }
+    }
+    #[inline]
+    pub fn as_preserved_slice(&self) -> &[T] {
+        if self.backwards() {
+            self.as_right_side_slice()
+        } else {
+            self.as_left_side_slice()
+        }
+    }
+    #[inline]
+    pub fn as_remaining_slice(&self) -> &[T] {
+        if self.backwards() {
+            self.as_left_side_slice()
+        } else {
+            self.as_right_side_slice()
+        }
+    }
+    /// * WARNING: writing through this slice has no effects.
+    #[inline]
+    pub fn as_remaining_slice_mut(&mut self) -> &mut [T] {
+        let pos = self.pos();
+        if self.backwards() {
+            &mut self.slice[..pos]
+        } else {
+            &mut self.slice[pos.saturating_add(1)..]
+        }
+    }
+    #[inline]
+    pub fn as_left_side_slice(&self) -> &[T] {
+        &self.slice[..self.pos()]
+    }
+    #[inline]
+    pub fn as_right_side_slice(&self) -> &[T] {
+        &self.slice[self.pos().saturating_add(1)..]
+    }
+
+    // ------ READ ------
+
+    #[inline]
+    pub fn current(&self) -> &T {
+        &self.slice[self.pos()]
+    }
+    #[inline]
+    pub fn current_deref(&self) -> T
+    where
+        T: Copy,
+    {
+        self.slice[self.pos()]
+    }
+
+    // ------ WRITE ------
+
+    /// the extras will be changed when the returned guard
+    /// is dropped, only if it has been written through.
+    #[inline]
+    pub fn current_mut(&mut self) -> CurrentMut<'_, T, E> {
+        let pos = self.pos();
+        CurrentMut {
+            item: &mut self.slice[pos],
+            extras: &mut self.info.extras,
+            pos,
+            noeffects: self.info.noeffects,
+            written: false,
+        }
+    }
+    /// replaces the current item and returns the old one.
+    #[inline]
+    pub fn set(&mut self, value: T) -> T {
+        let pos = self.pos();
+        let old = mem::replace(&mut self.slice[pos], value);
+        self.blush_extras(pos);
+        old
+    }
+    /// swaps the current item with the item at `pos() + offset`.
+    /// cursor stays, returns the new current item.
+    #[inline]
+    pub fn swap_with_offset(&mut self, offset: isize) -> Option<&T> {
+        let pos = self.pos();
+        let other = pos.checked_move_offset(offset)?;
+        if other >= self.len() {
+            return None;
+        }
+        if other != pos {
+            self.slice.swap(pos, other);
+            self.blush_extras(other);
+            self.blush_extras(pos);
+        }
+        Some(self.current())
+    }
+    /// fills `slice[range]` with clones of the `value`.
+    /// cursor stays, every written position changes the extras.
+    #[inline]
+    pub fn fill_range(&mut self, range: Range<usize>, value: T) -> Option<&mut [T]>
+    where
+        T: Clone,
+    {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        self.slice[range.clone()].fill(value);
+        for pos in range.clone() {
+            self.blush_extras(pos);
+        }
+        Some(&mut self.slice[range])
+    }
+
+    // ------------ JUMP ------------
+
+    #[inline]
+    pub fn jump(&mut self, pos: usize) -> Option<&T> {
+        self.set_pos(pos)
+    }
+    /// if abs == 1, initial coordinate is -1 or just 0
+    #[inline]
+    pub fn jump_to_offset(&mut self, offset: isize) -> Option<&T> {
+        let (abs, sig) = isize_to_usize(offset);
+        match (abs, sig) {
+            (0, _) => Some(self.current()),
+            (1, _) => {
+                let turn = self.backwards() == (sig == 1);
+                if turn {
+                    self.turnaround();
+                }
+                let moved = self.next().is_some();
+                if turn {
+                    self.turnaround();
+                }
+                if !moved {
+                    return None;
+                }
+                Some(self.current())
+            }
+            (_, 1) => self.jump(self.pos().checked_add(abs)?),
+            (_, -1) => self.jump(self.pos().checked_sub(abs)?),
+            _ => unreachable!(),
+        }
+    }
+    #[inline]
+    pub fn jump_to_first(&mut self) -> &T {
+        self.jump(0).unwrap()
+    }
+    #[inline]
+    pub fn jump_to_last(&mut self) -> &T {
+        self.jump(self.len().saturating_sub(1)).unwrap()
+    }
+    /// jump to the saved pos.
+    #[inline]
+    pub fn jump_to_load(&mut self) -> &T {
+        self.jump(self.saved().pos).unwrap()
+    }
+
+    // ------------ NEXT ------------
+
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn next(&mut self) -> Option<&T> {
+        match self.backwards() {
+            _ if !self.is_init() => {
+                if self.slice.is_empty() {
+                    return None;
+                }
+                self.set_init(true);
+                if E::STEPWISE {
+                    self.blush_move();
+                }
+                Some(self.current())
+            }
+            false => self.set_pos(self.pos().checked_add(1)?),
+            true => self.set_pos(self.pos().checked_sub(1)?),
+        }
+    }
+    /// next_back() `without turnaround()`
+    #[inline]
+    pub fn prev(&mut self) -> Option<&T> {
+        self.turnaround();
+        let moved = self.next().is_some();
+        self.turnaround();
+        if !moved {
+            return None;
+        }
+        Some(self.current())
+    }
+    #[inline]
+    pub fn next_to_left(&mut self) -> Option<&T> {
+        self.head_to_left();
+        self.next()
+    }
+    #[inline]
+    pub fn next_to_right(&mut self) -> Option<&T> {
+        self.head_to_right();
+        self.next()
+    }
+    #[inline]
+    pub fn next_to_pos(&mut self, pos: usize) -> Option<&T> {
+        if pos >= self.len() {
+            return None;
+        }
+        let (diff, is_pos) = self.pos().detailed_diff(pos);
+        match is_pos {
+            Ordering::Greater => self.head_to_right(),
+            Ordering::Equal => {}
+            Ordering::Less => self.head_to_left(),
+        }
+        for _ in 1..diff {
+            self.next()?;
+        }
+        self.next()
+    }
+    #[inline]
+    pub fn next_to_offset(&mut self, offset: isize) -> Option<&T> {
+        if self.pos().checked_move_offset(offset)? >= self.len() {
+            return None;
+        }
+        let (abs, sig) = isize_to_usize(offset);
+        match sig {
+            1 => self.head_to_right(),
+            0 => {}
+            -1 => self.head_to_left(),
+            _ => unreachable!(),
+        }
+        for _ in 1..abs {
+            self.next()?;
+        }
+        self.next()
+    }
+    #[inline]
+    pub fn next_to_first(&mut self) -> &T {
+        self.head_to_left();
+        while self.next().is_some() {}
+        self.current()
+    }
+    #[inline]
+    pub fn next_to_last(&mut self) -> &T {
+        self.head_to_right();
+        while self.next().is_some() {}
+        self.current()
+    }
+    /// bump until meets f() = `true`.
+    #[inline]
+    pub fn next_to_until(&mut self, f: fn(&T) -> bool) -> Option<&T> {
+        while !f(self.next()?) {}
+        Some(self.current())
+    }
+    /// bump while f() = `true`.
+    #[inline]
+    pub fn next_to_while(&mut self, f: fn(&T) -> bool) -> Option<&T> {
+        while f(self.next()?) {}
+        Some(self.current())
+    }
+    /// bump until meets saved pos.
+    #[inline]
+    pub fn next_to_load(&mut self) -> &T {
+        self.next_to_pos(self.saved().pos).unwrap()
+    }
+}
+
+// ------- WARNING: isize -------
+
+impl<'s, T, E: Extras<T>> AddAssign<usize> for CursorMut<'s, T, E> {
+    #[inline]
+    fn add_assign(&mut self, rhs: usize) {
+        self.jump_to_offset(rhs as isize);
+    }
+}
+
+impl<'c, 's, T, E: Extras<T>> Add<usize> for &'c mut CursorMut<'s, T, E> {
+    type Output = Option<&'c T>;
+    #[inline]
+    fn add(self, rhs: usize) -> Self::Output {
+        self.jump_to_offset(rhs as isize)
+    }
+}
+
+impl<'s, T, E: Extras<T>> SubAssign<usize> for CursorMut<'s, T, E> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: usize) {
+        self.jump_to_offset(-(rhs as isize));
+    }
+}
+
+impl<'c, 's, T, E: Extras<T>> Sub<usize> for &'c mut CursorMut<'s, T, E> {
+    type Output = Option<&'c T>;
+    #[inline]
+    fn sub(self, rhs: usize) -> Self::Output {
+        self.jump_to_offset(-(rhs as isize))
+    }
+}
+
+// ------ guard ------
+
+/// returned by [`CursorMut::current_mut`](CursorMut::current_mut).
+pub struct CurrentMut<'c, T, E: Extras<T>> {
+    item: &'c mut T,
+    extras: &'c mut E,
+    pos: usize,
+    noeffects: bool,
+    written: bool,
+}
+impl<T: fmt::Debug, E: Extras<T>> fmt::Debug for CurrentMut<'_, T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CurrentMut").field(&self.item).finish()
+    }
+}
+impl<T, E: Extras<T>> Deref for CurrentMut<'_, T, E> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.item
+    }
+}
+impl<T, E: Extras<T>> DerefMut for CurrentMut<'_, T, E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.written = true;
+        self.item
+    }
+}
+impl<T, E: Extras<T>> Drop for CurrentMut<'_, T, E> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.written && !self.noeffects {
+            self.extras.change(self.item, self.pos);
+        }
+    }
+}
@@ -0,0 +1,413 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+
+/// a [`Cursor`](Cursor) that owns its source, so it can be stored,
+/// returned or sent anywhere.
+/// * `.cursor()` lends the whole [`CursorTrait`](CursorTrait) surface,
+/// * and the moves are kept when the lent cursor is dropped.
+pub struct OwnedCursor<T, E: Extras<T> = NoneExtras<T>, S: AsRef<[T]> = Vec<T>> {
+    source: S,
+    info: CursorInfo<T, E>,
+    saved_info: CursorInfo<T, E>,
+}
+impl<T: fmt::Debug, E: Extras<T>, S: AsRef<[T]>> fmt::Debug for OwnedCursor<T, E, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedCursor")
+            .field(&self.as_slice())
+            .field(&self.pos())
+            .finish()
+    }
+}
+impl<T, E: Extras<T>, S: AsRef<[T]> + Clone> Clone for OwnedCursor<T, E, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        OwnedCursor {
+            source: self.source.clone(),
+            info: self.info.clone(),
+            saved_info: self.saved_info.clone(),
+        }
+    }
+}
+impl<T, E: Extras<T>, S: AsRef<[T]>> ToExtras<E> for OwnedCursor<T, E, S> {
+    type Input = T;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+/// this would reset the newer cursor
+impl<T, E: Extras<T>, S: AsRef<[T]>> ToCursor<T, E> for OwnedCursor<T, E, S> {}
+impl<T, E: Extras<T>, S: AsRef<[T]>> AsRef<[T]> for OwnedCursor<T, E, S> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+impl<T, E: Extras<T>> From<Vec<T>> for OwnedCursor<T, E, Vec<T>> {
+    #[inline]
+    fn from(source: Vec<T>) -> Self {
+        owned_cursor_new(source)
+    }
+}
+impl<T, E: Extras<T>> From<Box<[T]>> for OwnedCursor<T, E, Box<[T]>> {
+    #[inline]
+    fn from(source: Box<[T]>) -> Self {
+        owned_cursor_new(source)
+    }
+}
+impl<T, E: Extras<T>> From<Arc<[T]>> for OwnedCursor<T, E, Arc<[T]>> {
+    #[inline]
+    fn from(source: Arc<[T]>) -> Self {
+        owned_cursor_new(source)
+    }
+}
+impl<T, E: Extras<T>, const N: usize> From<[T; N]> for OwnedCursor<T, E, [T; N]> {
+    #[inline]
+    fn from(source: [T; N]) -> Self {
+        owned_cursor_new(source)
+    }
+}
+
+#[inline]
+fn owned_cursor_new<T, EXTRAS: Extras<T>, S: AsRef<[T]>>(source: S) -> OwnedCursor<T, EXTRAS, S> {
+    OwnedCursor {
+        source,
+        info: CursorInfo::new(),
+        saved_info: CursorInfo::new(),
+    }
+}
+impl<T, S: AsRef<[T]>> OwnedCursor<T, NoneExtras<T>, S> {
+    #[inline]
+    pub fn new(source: S) -> Self {
+        owned_cursor_new(source)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<T>>(source: S) -> OwnedCursor<T, EXTRAS, S> {
+        owned_cursor_new(source)
+    }
+}
+
+impl<T, E: Extras<T>, S: AsRef<[T]>> OwnedCursor<T, E, S> {
+    /// lends a [`Cursor`](Cursor) that continues from the last state.
+    #[inline]
+    pub fn cursor(&mut self) -> CursorGuard<'_, T, E> {
+        let slice = self.source.as_ref();
+        CursorGuard {
+            cursor: Cursor {
+                slice,
+                len: slice.len(),
+                info: mem::take(&mut self.info),
+                saved_info: mem::take(&mut self.saved_info),
+            },
+            info: &mut self.info,
+            saved_info: &mut self.saved_info,
+        }
+    }
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.source.as_ref()
+    }
+    #[inline]
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+    #[inline]
+    pub fn into_source(self) -> S {
+        self.source
+    }
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.info.backwards
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn saved(&self) -> &CursorInfo<T, E> {
+        &self.saved_info
+    }
+}
+
+/// returned by [`OwnedCursor::cursor`](OwnedCursor::cursor).
+pub struct CursorGuard<'o, T, E: Extras<T>> {
+    cursor: Cursor<'o, T, E>,
+    info: &'o mut CursorInfo<T, E>,
+    saved_info: &'o mut CursorInfo<T, E>,
+}
+impl<T: fmt::Debug, E: Extras<T>> fmt::Debug for CursorGuard<'_, T, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cursor.fmt(f)
+    }
+}
+impl<'o, T, E: Extras<T>> Deref for CursorGuard<'o, T, E> {
+    type Target = Cursor<'o, T, E>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.cursor
+    }
+}
+impl<T, E: Extras<T>> DerefMut for CursorGuard<'_, T, E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cursor
+    }
+}
+impl<T, E: Extras<T>> Drop for CursorGuard<'_, T, E> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.info = mem::take(&mut self.cursor.info);
+        *self.saved_info = mem::take(&mut self.cursor.saved_info);
+    }
+}
+
+// ------ extensions ------
+
+/// a [`StrCursor`](StrCursor) that owns its source, so it can be stored,
+/// returned or sent anywhere.
+/// * `.cursor()` lends the whole [`StrCursorTrait`](StrCursorTrait) surface,
+/// * and the moves are kept when the lent cursor is dropped.
+pub struct OwnedStrCursor<E: Extras<char> = NoneExtras<char>, S: AsRef<str> = String> {
+    source: S,
+    inner: CursorInfo<u8, NoneExtras<u8>>,
+    inner_saved: CursorInfo<u8, NoneExtras<u8>>,
+    len: Option<usize>,
+    /// the indexes built by a lent cursor, without the source.
+    line_index: Option<LineIndex<'static>>,
+    char_index: Option<CharIndex<'static>>,
+    info: StrCursorInfo<E>,
+    saved_info: StrCursorInfo<E>,
+}
+impl<E: Extras<char>, S: AsRef<str>> fmt::Debug for OwnedStrCursor<E, S> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedStrCursor")
+            .field(&self.as_str())
+            .field(&self.pos())
+            .finish()
+    }
+}
+impl<E: Extras<char>, S: AsRef<str> + Clone> Clone for OwnedStrCursor<E, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        OwnedStrCursor {
+            source: self.source.clone(),
+            inner: self.inner.clone(),
+            inner_saved: self.inner_saved.clone(),
+            len: self.len,
+            line_index: self.line_index.clone(),
+            char_index: self.char_index.clone(),
+            info: self.info.clone(),
+            saved_info: self.saved_info.clone(),
+        }
+    }
+}
+impl<E: Extras<char>, S: AsRef<str>> ToExtras<E> for OwnedStrCursor<E, S> {
+    type Input = char;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+/// this will reset the newer cursor
+impl<E: Extras<char>, S: AsRef<str>> ToCursor<u8> for OwnedStrCursor<E, S> {}
+impl<E: Extras<char>, S: AsRef<str>> AsRef<[u8]> for OwnedStrCursor<E, S> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+impl<E: Extras<char>> From<String> for OwnedStrCursor<E, String> {
+    #[inline]
+    fn from(source: String) -> Self {
+        owned_str_cursor_new(source)
+    }
+}
+impl<E: Extras<char>> From<Box<str>> for OwnedStrCursor<E, Box<str>> {
+    #[inline]
+    fn from(source: Box<str>) -> Self {
+        owned_str_cursor_new(source)
+    }
+}
+impl<E: Extras<char>> From<Arc<str>> for OwnedStrCursor<E, Arc<str>> {
+    #[inline]
+    fn from(source: Arc<str>) -> Self {
+        owned_str_cursor_new(source)
+    }
+}
+
+#[inline]
+fn owned_str_cursor_new<EXTRAS: Extras<char>, S: AsRef<str>>(
+    source: S,
+) -> OwnedStrCursor<EXTRAS, S> {
+    OwnedStrCursor {
+        source,
+        inner: CursorInfo::new(),
+        inner_saved: CursorInfo::new(),
+        len: None,
+        line_index: None,
+        char_index: None,
+        info: StrCursorInfo::new(),
+        saved_info: StrCursorInfo::new(),
+    }
+}
+impl<S: AsRef<str>> OwnedStrCursor<NoneExtras<char>, S> {
+    #[inline]
+    pub fn new(source: S) -> Self {
+        owned_str_cursor_new(source)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<char>>(source: S) -> OwnedStrCursor<EXTRAS, S> {
+        owned_str_cursor_new(source)
+    }
+}
+
+impl<E: Extras<char>, S: AsRef<str>> OwnedStrCursor<E, S> {
+    /// lends a [`StrCursor`](StrCursor) that continues from the last state,
+    /// the saved state and the indexes it builds are kept as well.
+    #[inline]
+    pub fn cursor(&mut self) -> StrCursorGuard<'_, E> {
+        let string = self.source.as_ref();
+        let bytes = string.as_bytes();
+        StrCursorGuard {
+            cursor: StrCursor {
+                cursor: Cursor {
+                    slice: bytes,
+                    len: bytes.len(),
+                    info: mem::take(&mut self.inner),
+                    saved_info: mem::take(&mut self.inner_saved),
+                },
+                len: self.len,
+                line_index: self.line_index.take().map(|index| index.attach(string)),
+                char_index: self.char_index.take().map(|index| index.attach(string)),
+                info: mem::take(&mut self.info),
+                saved_info: mem::take(&mut self.saved_info),
+            },
+            inner: &mut self.inner,
+            inner_saved: &mut self.inner_saved,
+            len: &mut self.len,
+            line_index: &mut self.line_index,
+            char_index: &mut self.char_index,
+            info: &mut self.info,
+            saved_info: &mut self.saved_info,
+        }
+    }
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.source.as_ref()
+    }
+    #[inline]
+    pub fn source(&self) -> &S {
+        &self.source
+    }
+    #[inline]
+    pub fn into_source(self) -> S {
+        self.source
+    }
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    pub fn pos_as_bytes(&self) -> usize {
+        self.inner.pos
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.inner.backwards
+    }
+    #[inline]
+    pub fn current(&self) -> char {
+        self.info.current
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn saved(&self) -> &StrCursorInfo<E> {
+        &self.saved_info
+    }
+}
+
+/// returned by [`OwnedStrCursor::cursor`](OwnedStrCursor::cursor).
+pub struct StrCursorGuard<'o, E: Extras<char>> {
+    cursor: StrCursor<'o, E>,
+    inner: &'o mut CursorInfo<u8, NoneExtras<u8>>,
+    inner_saved: &'o mut CursorInfo<u8, NoneExtras<u8>>,
+    len: &'o mut Option<usize>,
+    line_index: &'o mut Option<LineIndex<'static>>,
+    char_index: &'o mut Option<CharIndex<'static>>,
+    info: &'o mut StrCursorInfo<E>,
+    saved_info: &'o mut StrCursorInfo<E>,
+}
+impl<E: Extras<char>> fmt::Debug for StrCursorGuard<'_, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cursor.fmt(f)
+    }
+}
+impl<'o, E: Extras<char>> Deref for StrCursorGuard<'o, E> {
+    type Target = StrCursor<'o, E>;
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.cursor
+    }
+}
+impl<E: Extras<char>> DerefMut for StrCursorGuard<'_, E> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cursor
+    }
+}
+impl<E: Extras<char>> Drop for StrCursorGuard<'_, E> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.inner = mem::take(&mut self.cursor.cursor.info);
+        *self.inner_saved = mem::take(&mut self.cursor.cursor.saved_info);
+        *self.len = self.cursor.len;
+        *self.line_index = self.cursor.line_index.take().map(LineIndex::detach);
+        *self.char_index = self.cursor.char_index.take().map(CharIndex::detach);
+        *self.info = mem::take(&mut self.cursor.info);
+        *self.saved_info = mem::take(&mut self.cursor.saved_info);
+    }
+}
+
+// ------ conversions ------
+
+/// moves the source into an [`OwnedCursor`](OwnedCursor).
+pub trait IntoCursor<T>
+where
+    Self: AsRef<[T]> + Sized,
+{
+    #[inline]
+    fn into_cursor(self) -> OwnedCursor<T, NoneExtras<T>, Self> {
+        OwnedCursor::new(self)
+    }
+}
+impl<T> IntoCursor<T> for Vec<T> {}
+impl<T> IntoCursor<T> for Box<[T]> {}
+impl<T> IntoCursor<T> for Arc<[T]> {}
+impl<T, const N: usize> IntoCursor<T> for [T; N] {}
+
+/// moves the source into an [`OwnedStrCursor`](OwnedStrCursor).
+pub trait IntoStrCursor
+where
+    Self: AsRef<str> + Sized,
+{
+    #[inline]
+    fn into_str_cursor(self) -> OwnedStrCursor<NoneExtras<char>, Self> {
+        OwnedStrCursor::new(self)
+    }
+}
+impl IntoStrCursor for String {}
+impl IntoStrCursor for Box<str> {}
+impl IntoStrCursor for Arc<str> {}
@@ -0,0 +1,788 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+use std::io::{self, Read};
+
+/// bytes pulled out of the reader per refill.
+pub const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+/// why a [`ReadCursor`](ReadCursor) or a [`ReadStrCursor`](ReadStrCursor) couldn't move.
+#[derive(Debug)]
+pub enum ReadError {
+    Stream(StreamError),
+    /// the bytes at `byte_pos` are not UTF-8.
+    InvalidUtf8 {
+        byte_pos: usize,
+    },
+    Io(io::Error),
+}
+impl fmt::Display for ReadError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Stream(e) => e.fmt(f),
+            ReadError::InvalidUtf8 { byte_pos } => {
+                write!(f, "invalid UTF-8 sequence at byte {}", byte_pos)
+            }
+            ReadError::Io(e) => e.fmt(f),
+        }
+    }
+}
+impl std::error::Error for ReadError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Stream(e) => Some(e),
+            ReadError::InvalidUtf8 { .. } => None,
+            ReadError::Io(e) => Some(e),
+        }
+    }
+}
+impl From<StreamError> for ReadError {
+    #[inline]
+    fn from(e: StreamError) -> Self {
+        ReadError::Stream(e)
+    }
+}
+impl From<io::Error> for ReadError {
+    #[inline]
+    fn from(e: io::Error) -> Self {
+        ReadError::Io(e)
+    }
+}
+
+// ------ buffer ------
+
+/// a window of the reader, from `base` to `base + buf.len()`.
+struct ReadBuffer<R> {
+    reader: R,
+    buf: Vec<u8>,
+    base: usize,
+    eof: bool,
+    chunk_size: usize,
+}
+
+impl<R: Read> ReadBuffer<R> {
+    #[inline]
+    fn new(reader: R) -> Self {
+        ReadBuffer {
+            reader,
+            buf: Vec::new(),
+            base: 0,
+            eof: false,
+            chunk_size: READ_CHUNK_SIZE,
+        }
+    }
+    #[inline]
+    fn end(&self) -> usize {
+        self.base + self.buf.len()
+    }
+    #[inline]
+    fn get(&self, pos: usize) -> Option<u8> {
+        self.buf.get(pos.checked_sub(self.base)?).copied()
+    }
+    /// `range` must be buffered.
+    #[inline]
+    fn slice(&self, range: Range<usize>) -> &[u8] {
+        &self.buf[range.start - self.base..range.end - self.base]
+    }
+    #[inline]
+    fn check_retained(&self, pos: usize) -> Result<(), ReadError> {
+        if pos < self.base {
+            Err(StreamError::Forgotten {
+                requested: pos,
+                oldest: self.base,
+            }
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+    /// reads until `pos` is buffered, forgetting the bytes before `keep_from` as it goes.
+    /// * `forget(base, bytes)` sees each run of bytes before it's gone.
+    #[inline]
+    fn fill_to<F: FnMut(usize, &[u8])>(
+        &mut self,
+        pos: usize,
+        keep_from: usize,
+        mut forget: F,
+    ) -> Result<(), ReadError> {
+        self.check_retained(pos)?;
+        while self.end() <= pos {
+            if self.eof {
+                return Err(StreamError::EndOfInput.into());
+            }
+            if keep_from > self.base {
+                let n = (keep_from - self.base).min(self.buf.len());
+                forget(self.base, &self.buf[..n]);
+                self.buf.drain(..n);
+                self.base += n;
+            }
+            let len = self.buf.len();
+            self.buf.resize(len + self.chunk_size, 0);
+            let read = loop {
+                match self.reader.read(&mut self.buf[len..]) {
+                    Ok(n) => break n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => {
+                        self.buf.truncate(len);
+                        return Err(e.into());
+                    }
+                }
+            };
+            self.buf.truncate(len + read);
+            if read == 0 {
+                self.eof = true;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------ bytes ------
+
+/// a byte cursor over any [`Read`](std::io::Read), without reading it fully.
+/// * refills on demand and keeps the bytes from the saved pos,
+/// * so `as_bytes_loaded` is always contiguous.
+/// * otherwise only the byte before the current one is kept,
+///   a long jump forgets what it reads past, even when the input runs out first,
+///   the current byte is then still there but nothing behind it.
+pub struct ReadCursor<R: Read, E: Extras<u8> = NoneExtras<u8>> {
+    buffer: ReadBuffer<R>,
+    pinned: Option<usize>,
+    /// the current byte, once a failed jump has read the buffer past it.
+    held: Option<u8>,
+    info: CursorInfo<u8, E>,
+    saved_info: CursorInfo<u8, E>,
+}
+impl<R: Read, E: Extras<u8>> fmt::Debug for ReadCursor<R, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReadCursor")
+            .field(&self.buffer.base)
+            .field(&self.pos())
+            .field(&self.current())
+            .finish()
+    }
+}
+impl<R: Read, E: Extras<u8>> ToExtras<E> for ReadCursor<R, E> {
+    type Input = u8;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+
+#[inline]
+fn read_cursor_new<R: Read, EXTRAS: Extras<u8>>(reader: R) -> ReadCursor<R, EXTRAS> {
+    ReadCursor {
+        buffer: ReadBuffer::new(reader),
+        pinned: None,
+        held: None,
+        info: CursorInfo::new(),
+        saved_info: CursorInfo::new(),
+    }
+}
+impl<R: Read> ReadCursor<R, NoneExtras<u8>> {
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        read_cursor_new(reader)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<u8>>(reader: R) -> ReadCursor<R, EXTRAS> {
+        read_cursor_new(reader)
+    }
+}
+
+impl<R: Read, E: Extras<u8>> ReadCursor<R, E> {
+    // ------ private ------
+    /// the oldest byte to keep on the way to `new_pos`.
+    #[inline]
+    fn keep_from(&self, new_pos: usize) -> usize {
+        let before = new_pos.saturating_sub(1);
+        match self.pinned {
+            Some(pinned) => pinned.min(before),
+            None => before,
+        }
+    }
+    #[inline]
+    fn set_pos(&mut self, new_pos: usize) -> Result<u8, ReadError> {
+        self.buffer.check_retained(new_pos)?;
+
+        let from = self.is_init().then(|| self.pos());
+        // from the last byte the extras saw, what's buffered on the way first,
+        // then each run before it's forgotten.
+        let seen = self.info.extras_pos;
+        let step = E::STEPWISE && !self.noeffects() && seen != Some(new_pos);
+        let before = step.then(|| self.info.extras.clone());
+        let mut unstepped = new_pos;
+        if step {
+            let (base, end) = (self.buffer.base, self.buffer.end());
+            for pos in stepped(seen, new_pos).filter(|&pos| base <= pos && pos < end) {
+                self.info.extras.change(&self.buffer.get(pos).unwrap(), pos);
+            }
+            unstepped = end.max(seen.map_or(0, |seen| seen + 1)).min(new_pos);
+        }
+        let keep_from = self.keep_from(new_pos);
+        let (info, held) = (&mut self.info, &mut self.held);
+        let filled = self.buffer.fill_to(new_pos, keep_from, |base, bytes| {
+            for (pos, byte) in (base..).zip(bytes) {
+                if info.init && pos == info.pos {
+                    *held = Some(*byte);
+                }
+                if step && unstepped <= pos && pos < new_pos {
+                    info.extras.change(byte, pos);
+                    unstepped = pos + 1;
+                }
+            }
+        });
+        if let Err(e) = filled {
+            if let Some(extras) = before {
+                self.info.extras = extras;
+            }
+            return Err(e);
+        }
+        if step {
+            for pos in unstepped..new_pos {
+                self.info.extras.change(&self.buffer.get(pos).unwrap(), pos);
+            }
+        }
+
+        if !self.is_init() {
+            self.info.init = true;
+        }
+        let byte = self.buffer.get(new_pos).unwrap();
+        if new_pos != self.pos() || step || (E::STEPWISE && from.is_none()) {
+            self.info.pos = new_pos;
+            self.held = None;
+            if !self.noeffects() {
+                self.info.extras_pos = Some(new_pos);
+                self.info.extras.change(&byte, new_pos);
+            }
+        }
+        Ok(byte)
+    }
+
+    // ------ public ------
+    /// bytes per refill.
+    #[inline]
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.buffer.chunk_size = chunk_size.max(1);
+    }
+    /// if the reader has been read to the end.
+    #[inline]
+    pub fn is_eof(&self) -> bool {
+        self.buffer.eof
+    }
+    /// the oldest position that can still be visited.
+    #[inline]
+    pub fn oldest_pos(&self) -> usize {
+        self.buffer.base
+    }
+    #[inline]
+    pub fn as_buffered_bytes(&self) -> &[u8] {
+        &self.buffer.buf
+    }
+    #[inline]
+    pub fn into_reader(self) -> R {
+        self.buffer.reader
+    }
+
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.info.init
+    }
+    /// if `next` or `jump` can effect the [`Extras`](Extras).
+    #[inline]
+    pub fn noeffects(&self) -> bool {
+        self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_mut(&mut self) -> &mut bool {
+        &mut self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_on(&mut self) {
+        self.info.noeffects = true;
+    }
+    #[inline]
+    pub fn noeffects_off(&mut self) {
+        self.info.noeffects = false;
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.info.backwards
+    }
+    #[inline]
+    pub fn backwards_mut(&mut self) -> &mut bool {
+        &mut self.info.backwards
+    }
+    #[inline]
+    pub fn turnaround(&mut self) {
+        self.info.backwards = !self.info.backwards;
+    }
+    #[inline]
+    pub fn head_to_left(&mut self) {
+        if !self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn head_to_right(&mut self) {
+        if self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn extras_mut(&mut self) -> &mut E {
+        &mut self.info.extras
+    }
+    /// cloning `saved().extras` to `self.extras()`.
+    #[inline]
+    pub fn to_range_extras(&self) -> Range<E> {
+        self.saved().extras.clone()..self.extras().clone()
+    }
+    /// pins the bytes from here until the next `save`.
+    #[inline]
+    pub fn save(&mut self) {
+        self.saved_info = self.info.clone();
+        self.pinned = Some(self.pos());
+    }
+    #[inline]
+    pub fn saved(&self) -> &CursorInfo<u8, E> {
+        &self.saved_info
+    }
+    #[inline]
+    pub fn load(&mut self) -> Result<(), ReadError> {
+        self.buffer.check_retained(self.saved_info.pos)?;
+        self.info = self.saved_info.clone();
+        self.held = None;
+        Ok(())
+    }
+
+    /// `None` until the first byte has been read.
+    #[inline]
+    pub fn current(&self) -> Option<u8> {
+        self.buffer.get(self.pos()).or(self.held)
+    }
+    /// saved pos to current pos.
+    #[inline]
+    pub fn as_bytes_loaded(&self) -> &[u8] {
+        let pos = self.pos();
+        let saved_pos = self.saved().pos;
+        let range = if pos < saved_pos {
+            pos..saved_pos + 1
+        } else {
+            saved_pos..pos + 1
+        };
+        if range.start < self.buffer.base || range.end > self.buffer.end() {
+            return &[];
+        }
+        self.buffer.slice(range)
+    }
+
+    // ------------ JUMP ------------
+
+    #[inline]
+    pub fn jump(&mut self, pos: usize) -> Result<u8, ReadError> {
+        self.set_pos(pos)
+    }
+    #[inline]
+    pub fn jump_to_offset(&mut self, offset: isize) -> Result<u8, ReadError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(StreamError::EndOfInput)?;
+        self.set_pos(pos)
+    }
+
+    // ------------ NEXT ------------
+
+    /// same with `next`, but tells why it couldn't move.
+    #[inline]
+    pub fn try_next(&mut self) -> Result<u8, ReadError> {
+        let pos = match self.backwards() {
+            _ if !self.is_init() => self.pos(),
+            false => self.pos() + 1,
+            true => self.pos().checked_sub(1).ok_or(StreamError::EndOfInput)?,
+        };
+        self.set_pos(pos)
+    }
+    /// next_back() `without turnaround()`
+    #[inline]
+    pub fn prev(&mut self) -> Option<u8> {
+        self.turnaround();
+        let out = self.next();
+        self.turnaround();
+        out
+    }
+    /// bump until meets f() = `true`.
+    #[inline]
+    pub fn next_to_until(&mut self, f: fn(&u8) -> bool) -> Option<u8> {
+        while !f(&self.next()?) {}
+        self.current()
+    }
+    /// bump while f() = `true`.
+    #[inline]
+    pub fn next_to_while(&mut self, f: fn(&u8) -> bool) -> Option<u8> {
+        while f(&self.next()?) {}
+        self.current()
+    }
+}
+
+impl<R: Read, E: Extras<u8>> Iterator for ReadCursor<R, E> {
+    type Item = u8;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next().ok()
+    }
+}
+
+// ------ extensions ------
+
+/// a char cursor over any [`Read`](std::io::Read), without reading it fully.
+/// * UTF-8 sequences split by a refill are decoded once complete,
+/// * a maximal invalid subpart is one U+FFFD, split the same way both directions,
+/// * `try_next` reports it once as [`ReadError::InvalidUtf8`](ReadError::InvalidUtf8),
+///   still moving onto it, `next` just returns the U+FFFD.
+pub struct ReadStrCursor<R: Read, E: Extras<char> = NoneExtras<char>> {
+    buffer: ReadBuffer<R>,
+    pinned: Option<usize>,
+    /// `inner.pos` is the last byte of the current char,
+    /// `char_start_pos` is the first one.
+    info: StrCursorInfo<E>,
+    saved_info: StrCursorInfo<E>,
+}
+impl<R: Read, E: Extras<char>> fmt::Debug for ReadStrCursor<R, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ReadStrCursor")
+            .field(&self.buffer.base)
+            .field(&self.pos())
+            .field(&self.current())
+            .finish()
+    }
+}
+impl<R: Read, E: Extras<char>> ToExtras<E> for ReadStrCursor<R, E> {
+    type Input = char;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+
+#[inline]
+fn read_str_cursor_new<R: Read, EXTRAS: Extras<char>>(reader: R) -> ReadStrCursor<R, EXTRAS> {
+    ReadStrCursor {
+        buffer: ReadBuffer::new(reader),
+        pinned: None,
+        info: StrCursorInfo::new(),
+        saved_info: StrCursorInfo::new(),
+    }
+}
+impl<R: Read> ReadStrCursor<R, NoneExtras<char>> {
+    #[inline]
+    pub fn new(reader: R) -> Self {
+        read_str_cursor_new(reader)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<char>>(reader: R) -> ReadStrCursor<R, EXTRAS> {
+        read_str_cursor_new(reader)
+    }
+}
+
+impl<R: Read, E: Extras<char>> ReadStrCursor<R, E> {
+    // ------ private ------
+    /// the oldest byte to keep on the way to the char at `start`,
+    /// the current char is the one before it.
+    #[inline]
+    fn keep_from(&self, start: usize) -> usize {
+        let before = self.char_start_pos().min(start);
+        match self.pinned {
+            Some(pinned) => pinned.min(before),
+            None => before,
+        }
+    }
+    /// decodes the sequence led by `start`, refilling until it's complete,
+    /// `Err(width)` of a maximal invalid subpart.
+    #[inline]
+    fn decode_at(&mut self, start: usize) -> Result<Result<(char, usize), usize>, ReadError> {
+        let keep_from = self.keep_from(start);
+        self.buffer.fill_to(start, keep_from, |_, _| {})?;
+        let width = utf::utf8_char_width(self.buffer.get(start).unwrap());
+        if width > 1 {
+            match self.buffer.fill_to(start + width - 1, keep_from, |_, _| {}) {
+                // cut by the end, the subpart is what's left.
+                Err(ReadError::Stream(StreamError::EndOfInput)) => {}
+                res => res?,
+            }
+        }
+        let bytes = self
+            .buffer
+            .slice(start..self.buffer.end().min(start + width.max(1)));
+        Ok(utf::decode_sequence(bytes).unwrap())
+    }
+    /// decodes the sequence ending at `end`, split the same way `decode_at` would.
+    #[inline]
+    fn decode_before(&self, end: usize) -> Result<Result<(char, usize), usize>, ReadError> {
+        self.buffer.check_retained(end)?;
+        let from = end.saturating_sub(3).max(self.buffer.base);
+        Ok(utf::decode_back_sequence(self.buffer.slice(from..end + 1)).unwrap())
+    }
+    #[inline]
+    fn set_char(&mut self, ch: char, start: usize, width: usize, pos: usize) -> char {
+        let moved = !self.is_init() || pos != self.pos();
+        self.info.inner.init = true;
+        self.info.char_start_pos = start;
+        self.info.inner.pos = start + width - 1;
+        self.info.current = ch;
+        if moved {
+            self.info.pos = pos;
+            if !self.noeffects() {
+                if E::STEPWISE {
+                    self.step_extras(start, width, pos);
+                }
+                self.info.extras_pos = Some((pos, start));
+                self.info.extras.change(&ch, pos);
+            }
+        }
+        ch
+    }
+    /// reports the chars between the last one the extras saw and the one at `start`,
+    /// what's still buffered of the way.
+    #[inline]
+    fn step_extras(&mut self, start: usize, width: usize, pos: usize) {
+        let base = self.buffer.base;
+        match self.info.extras_pos {
+            Some((_, lead)) if lead == start => {}
+            Some((seen_pos, lead)) if start < lead => {
+                let between = Utf8::chars(self.buffer.slice(start + width..lead)).rev();
+                for (i, ch) in between.enumerate() {
+                    self.info.extras.change(&ch, seen_pos - 1 - i);
+                }
+            }
+            seen => {
+                let from = seen.map_or(0, |(_, lead)| lead);
+                let units = self.buffer.slice(from.max(base)..start);
+                // the seen char itself, if it's still there.
+                let skip = usize::from(seen.is_some() && from >= base);
+                let first = pos + skip - Utf8::count_chars(units);
+                for (i, ch) in Utf8::chars(units).skip(skip).enumerate() {
+                    self.info.extras.change(&ch, first + i);
+                }
+            }
+        }
+    }
+
+    // ------ public ------
+    /// bytes per refill.
+    #[inline]
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.buffer.chunk_size = chunk_size.max(1);
+    }
+    /// if the reader has been read to the end.
+    #[inline]
+    pub fn is_eof(&self) -> bool {
+        self.buffer.eof
+    }
+    /// the oldest byte position that can still be visited.
+    #[inline]
+    pub fn oldest_pos_as_bytes(&self) -> usize {
+        self.buffer.base
+    }
+    #[inline]
+    pub fn as_buffered_bytes(&self) -> &[u8] {
+        &self.buffer.buf
+    }
+    #[inline]
+    pub fn into_reader(self) -> R {
+        self.buffer.reader
+    }
+
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.info.inner.init
+    }
+    /// if `next` can effect the [`Extras`](Extras).
+    #[inline]
+    pub fn noeffects(&self) -> bool {
+        self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_mut(&mut self) -> &mut bool {
+        &mut self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_on(&mut self) {
+        self.info.noeffects = true;
+    }
+    #[inline]
+    pub fn noeffects_off(&mut self) {
+        self.info.noeffects = false;
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.info.inner.backwards
+    }
+    #[inline]
+    pub fn turnaround(&mut self) {
+        self.info.inner.backwards = !self.info.inner.backwards;
+    }
+    #[inline]
+    pub fn head_to_left(&mut self) {
+        if !self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn head_to_right(&mut self) {
+        if self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    /// the last byte of the current char.
+    #[inline]
+    pub fn pos_as_bytes(&self) -> usize {
+        self.info.inner.pos
+    }
+    /// the first byte of the current char.
+    #[inline]
+    pub fn char_start_pos(&self) -> usize {
+        self.info.char_start_pos
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn extras_mut(&mut self) -> &mut E {
+        &mut self.info.extras
+    }
+    /// cloning `saved().extras` to `self.extras()`.
+    #[inline]
+    pub fn to_range_extras(&self) -> Range<E> {
+        self.saved().extras.clone()..self.extras().clone()
+    }
+    /// pins the bytes from here until the next `save`.
+    #[inline]
+    pub fn save(&mut self) {
+        self.saved_info = self.info.clone();
+        self.pinned = Some(self.char_start_pos());
+    }
+    #[inline]
+    pub fn saved(&self) -> &StrCursorInfo<E> {
+        &self.saved_info
+    }
+    #[inline]
+    pub fn load(&mut self) -> Result<(), ReadError> {
+        self.buffer.check_retained(self.saved_info.char_start_pos)?;
+        self.info = self.saved_info.clone();
+        Ok(())
+    }
+
+    /// [`EOF_CHAR`](EOF_CHAR) until the first char has been read.
+    #[inline]
+    pub fn current(&self) -> char {
+        self.info.current
+    }
+    /// saved pos to current pos.
+    #[inline]
+    pub fn as_bytes_loaded(&self) -> &[u8] {
+        let (curr_start, curr_end) = (self.char_start_pos(), self.pos_as_bytes() + 1);
+        let (saved_start, saved_end) = (
+            self.saved_info.char_start_pos,
+            self.saved_info.inner.pos + 1,
+        );
+        let range = curr_start.min(saved_start)..curr_end.max(saved_end);
+        if !self.is_init() || range.start < self.buffer.base || range.end > self.buffer.end() {
+            return &[];
+        }
+        self.buffer.slice(range)
+    }
+    /// saved pos to current pos, if they are all UTF-8.
+    #[inline]
+    pub fn as_str_loaded(&self) -> Result<&str, ReadError> {
+        let start = self.char_start_pos().min(self.saved_info.char_start_pos);
+        str::from_utf8(self.as_bytes_loaded()).map_err(|err| ReadError::InvalidUtf8 {
+            byte_pos: start + err.valid_up_to(),
+        })
+    }
+
+    // ------------ NEXT ------------
+
+    /// same with `next`, but tells why it couldn't move.
+    /// * on an invalid subpart, it moves onto it as U+FFFD and returns
+    ///   [`ReadError::InvalidUtf8`](ReadError::InvalidUtf8), the next call goes on after it.
+    #[inline]
+    pub fn try_next(&mut self) -> Result<char, ReadError> {
+        let (start, pos, decoded) = match self.backwards() {
+            _ if !self.is_init() => (0, 0, self.decode_at(0)?),
+            false => {
+                let start = self.pos_as_bytes() + 1;
+                (start, self.pos() + 1, self.decode_at(start)?)
+            }
+            true => {
+                let end = self
+                    .char_start_pos()
+                    .checked_sub(1)
+                    .ok_or(StreamError::EndOfInput)?;
+                let decoded = self.decode_before(end)?;
+                let width = decoded.map_or_else(|width| width, |(_, width)| width);
+                (end + 1 - width, self.pos() - 1, decoded)
+            }
+        };
+        match decoded {
+            Ok((ch, width)) => Ok(self.set_char(ch, start, width, pos)),
+            Err(width) => {
+                self.set_char(char::REPLACEMENT_CHARACTER, start, width, pos);
+                Err(ReadError::InvalidUtf8 { byte_pos: start })
+            }
+        }
+    }
+    /// next_back() `without turnaround()`
+    #[inline]
+    pub fn prev(&mut self) -> Option<char> {
+        self.turnaround();
+        let out = self.next();
+        self.turnaround();
+        out
+    }
+    /// bump until meets f() = `true`.
+    #[inline]
+    pub fn next_to_until(&mut self, f: fn(char) -> bool) -> Option<char> {
+        while !f(self.next()?) {}
+        Some(self.current())
+    }
+    /// bump while f() = `true`.
+    #[inline]
+    pub fn next_to_while(&mut self, f: fn(char) -> bool) -> Option<char> {
+        while f(self.next()?) {}
+        Some(self.current())
+    }
+}
+
+impl<R: Read, E: Extras<char>> Iterator for ReadStrCursor<R, E> {
+    type Item = char;
+    /// U+FFFD on an invalid subpart.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.try_next() {
+            Ok(ch) => Some(ch),
+            Err(ReadError::InvalidUtf8 { .. }) => Some(char::REPLACEMENT_CHARACTER),
+            Err(_) => None,
+        }
+    }
+}
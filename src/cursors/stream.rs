@@ -0,0 +1,359 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use super::*;
+
+/// why a [`StreamCursor`](StreamCursor) couldn't move.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum StreamError {
+    /// the position is behind the retained history.
+    Forgotten { requested: usize, oldest: usize },
+    /// no more items in the heading direction.
+    EndOfInput,
+}
+impl fmt::Display for StreamError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Forgotten { requested, oldest } => write!(
+                f,
+                "position {} is behind the retained history (oldest: {})",
+                requested, oldest
+            ),
+            StreamError::EndOfInput => write!(f, "end of input"),
+        }
+    }
+}
+impl std::error::Error for StreamError {}
+
+/// pulls items lazily out of any [`Iterator`](Iterator).
+/// * keeps `lookback` items behind the current one,
+/// * so `turnaround`, `prev` and `load` work within that window.
+/// * a long jump never buffers more than the window, even when the input runs out first,
+///   the current item is then still there but nothing behind it.
+pub struct StreamCursor<I: Iterator, E: Extras<I::Item> = NoneExtras<<I as Iterator>::Item>> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    /// absolute pos of `buf[0]`.
+    oldest: usize,
+    lookback: usize,
+    /// the current item, once a failed jump has pulled the window past it.
+    held: Option<I::Item>,
+    info: CursorInfo<I::Item, E>,
+    saved_info: CursorInfo<I::Item, E>,
+}
+impl<I: Iterator, E: Extras<I::Item>> fmt::Debug for StreamCursor<I, E>
+where
+    I::Item: fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StreamCursor")
+            .field(&self.oldest)
+            .field(&self.buf)
+            .field(&self.current())
+            .finish()
+    }
+}
+impl<I: Iterator, E: Extras<I::Item>> ToExtras<E> for StreamCursor<I, E> {
+    type Input = I::Item;
+    #[inline]
+    fn to_extras(&self) -> E {
+        self.info.extras.clone()
+    }
+}
+
+#[inline]
+fn stream_cursor_new<I: Iterator, EXTRAS: Extras<I::Item>>(
+    iter: I,
+    lookback: usize,
+) -> StreamCursor<I, EXTRAS> {
+    StreamCursor {
+        iter,
+        buf: VecDeque::with_capacity(lookback.saturating_add(1)),
+        oldest: 0,
+        lookback,
+        held: None,
+        info: CursorInfo::new(),
+        saved_info: CursorInfo::new(),
+    }
+}
+impl<I: Iterator> StreamCursor<I, NoneExtras<I::Item>> {
+    #[inline]
+    pub fn new(iter: I, lookback: usize) -> Self {
+        stream_cursor_new(iter, lookback)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<I::Item>>(
+        iter: I,
+        lookback: usize,
+    ) -> StreamCursor<I, EXTRAS> {
+        stream_cursor_new(iter, lookback)
+    }
+}
+
+impl<I: Iterator, E: Extras<I::Item>> StreamCursor<I, E> {
+    // ------ private ------
+    /// pulls until `pos` is buffered, stepping the extras over the pulled items before `pos` if `step`.
+    /// * forgets what falls `lookback` behind the last pulled item as it goes,
+    ///   so the window ends up behind `pos`, or behind the last item if the input ends first.
+    #[inline]
+    fn fill_to(&mut self, pos: usize, step: bool) -> Result<(), StreamError> {
+        while self.oldest + self.buf.len() <= pos {
+            let end = self.oldest + self.buf.len();
+            match self.iter.next() {
+                Some(item) => {
+                    if step && end < pos {
+                        self.info.extras.change(&item, end);
+                    }
+                    self.buf.push_back(item);
+                    self.forget_before(end.saturating_sub(self.lookback));
+                }
+                None => return Err(StreamError::EndOfInput),
+            }
+        }
+        Ok(())
+    }
+    /// forgets everything before `keep_from`.
+    /// * the current item is held aside if it goes too.
+    #[inline]
+    fn forget_before(&mut self, keep_from: usize) {
+        while self.oldest < keep_from {
+            match self.buf.pop_front() {
+                Some(item) => {
+                    if self.is_init() && self.oldest == self.pos() {
+                        self.held = Some(item);
+                    }
+                    self.oldest += 1;
+                }
+                None => break,
+            }
+        }
+    }
+    #[inline]
+    fn check_retained(&self, pos: usize) -> Result<(), StreamError> {
+        if pos < self.oldest {
+            Err(StreamError::Forgotten {
+                requested: pos,
+                oldest: self.oldest,
+            })
+        } else {
+            Ok(())
+        }
+    }
+    #[inline]
+    fn set_pos(&mut self, new_pos: usize) -> Result<&I::Item, StreamError> {
+        self.check_retained(new_pos)?;
+
+        let from = self.is_init().then(|| self.pos());
+        // from the last item the extras saw, what's still retained of the way.
+        let seen = self.info.extras_pos;
+        let step = E::STEPWISE && !self.noeffects() && seen != Some(new_pos);
+        let before = step.then(|| self.info.extras.clone());
+        if step {
+            let (oldest, end) = (self.oldest, self.oldest + self.buf.len());
+            for pos in stepped(seen, new_pos).filter(|&pos| oldest <= pos && pos < end) {
+                self.info.extras.change(&self.buf[pos - oldest], pos);
+            }
+        }
+        if let Err(e) = self.fill_to(new_pos, step) {
+            if let Some(extras) = before {
+                self.info.extras = extras;
+            }
+            return Err(e);
+        }
+
+        if !self.is_init() {
+            self.info.init = true;
+        }
+        if new_pos != self.pos() || step || (E::STEPWISE && from.is_none()) {
+            self.info.pos = new_pos;
+            self.held = None;
+            self.forget_before(new_pos.saturating_sub(self.lookback));
+            self.blush_extras();
+        }
+        Ok(&self.buf[new_pos - self.oldest])
+    }
+    #[inline]
+    fn blush_extras(&mut self) {
+        if !self.noeffects() {
+            let pos = self.pos();
+            self.info.extras_pos = Some(pos);
+            self.info.extras.change(&self.buf[pos - self.oldest], pos);
+        }
+    }
+
+    // ------ public ------
+    /// how many items are kept behind the current one.
+    #[inline]
+    pub fn lookback(&self) -> usize {
+        self.lookback
+    }
+    /// the oldest position that can still be visited.
+    #[inline]
+    pub fn oldest_pos(&self) -> usize {
+        self.oldest
+    }
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.info.init
+    }
+    /// if `next` or `jump` can effect the [`Extras`](Extras).
+    #[inline]
+    pub fn noeffects(&self) -> bool {
+        self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_mut(&mut self) -> &mut bool {
+        &mut self.info.noeffects
+    }
+    #[inline]
+    pub fn noeffects_on(&mut self) {
+        self.info.noeffects = true;
+    }
+    #[inline]
+    pub fn noeffects_off(&mut self) {
+        self.info.noeffects = false;
+    }
+    #[inline]
+    pub fn backwards(&self) -> bool {
+        self.info.backwards
+    }
+    #[inline]
+    pub fn backwards_mut(&mut self) -> &mut bool {
+        &mut self.info.backwards
+    }
+    #[inline]
+    pub fn turnaround(&mut self) {
+        self.info.backwards = !self.info.backwards;
+    }
+    #[inline]
+    pub fn head_to_left(&mut self) {
+        if !self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn head_to_right(&mut self) {
+        if self.backwards() {
+            self.turnaround();
+        }
+    }
+    #[inline]
+    pub fn pos(&self) -> usize {
+        self.info.pos
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        &self.info.extras
+    }
+    #[inline]
+    pub fn extras_mut(&mut self) -> &mut E {
+        &mut self.info.extras
+    }
+    /// cloning `saved().extras` to `self.extras()`.
+    #[inline]
+    pub fn to_range_extras(&self) -> Range<E> {
+        self.saved().extras.clone()..self.extras().clone()
+    }
+    #[inline]
+    pub fn save(&mut self) {
+        self.saved_info = self.info.clone();
+    }
+    #[inline]
+    pub fn saved(&self) -> &CursorInfo<I::Item, E> {
+        &self.saved_info
+    }
+    /// fails if the saved pos has been forgotten.
+    #[inline]
+    pub fn load(&mut self) -> Result<(), StreamError> {
+        self.check_retained(self.saved_info.pos)?;
+        self.info = self.saved_info.clone();
+        self.held = None;
+        Ok(())
+    }
+
+    /// `None` until the first item has been pulled.
+    #[inline]
+    pub fn current(&self) -> Option<&I::Item> {
+        match self.pos().checked_sub(self.oldest) {
+            Some(i) => self.buf.get(i),
+            None => self.held.as_ref(),
+        }
+    }
+    /// the retained items, from the oldest one.
+    #[inline]
+    pub fn retained(&self) -> impl Iterator<Item = &I::Item> {
+        self.buf.iter()
+    }
+
+    // ------------ JUMP ------------
+
+    #[inline]
+    pub fn jump(&mut self, pos: usize) -> Result<&I::Item, StreamError> {
+        self.set_pos(pos)
+    }
+    #[inline]
+    pub fn jump_to_offset(&mut self, offset: isize) -> Result<&I::Item, StreamError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(StreamError::EndOfInput)?;
+        self.set_pos(pos)
+    }
+    /// jump to the saved pos.
+    #[inline]
+    pub fn jump_to_load(&mut self) -> Result<&I::Item, StreamError> {
+        self.set_pos(self.saved_info.pos)
+    }
+
+    // ------------ NEXT ------------
+
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn next(&mut self) -> Option<&I::Item> {
+        self.try_next().ok()
+    }
+    /// same with `next`, but tells why it couldn't move.
+    #[inline]
+    pub fn try_next(&mut self) -> Result<&I::Item, StreamError> {
+        let pos = match self.backwards() {
+            _ if !self.is_init() => self.pos(),
+            false => self.pos() + 1,
+            true => self.pos().checked_sub(1).ok_or(StreamError::EndOfInput)?,
+        };
+        self.set_pos(pos)
+    }
+    /// next_back() `without turnaround()`
+    #[inline]
+    pub fn prev(&mut self) -> Option<&I::Item> {
+        self.try_prev().ok()
+    }
+    /// same with `prev`, but tells why it couldn't move.
+    #[inline]
+    pub fn try_prev(&mut self) -> Result<&I::Item, StreamError> {
+        self.turnaround();
+        let moved = self.try_next().map(|_| ());
+        self.turnaround();
+        moved?;
+        Ok(&self.buf[self.pos() - self.oldest])
+    }
+    /// bump until meets f() = `true`.
+    #[inline]
+    pub fn next_to_until(&mut self, f: fn(&I::Item) -> bool) -> Option<&I::Item> {
+        while !f(self.next()?) {}
+        self.current()
+    }
+    /// bump while f() = `true`.
+    #[inline]
+    pub fn next_to_while(&mut self, f: fn(&I::Item) -> bool) -> Option<&I::Item> {
+        while f(self.next()?) {}
+        self.current()
+    }
+    /// pulls the whole stream, keeping only the window.
+    #[inline]
+    pub fn next_to_last(&mut self) -> Option<&I::Item> {
+        self.head_to_right();
+        while self.next().is_some() {}
+        self.current()
+    }
+}
@@ -0,0 +1,670 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! rustc-style error snippets over a source `&str`.
+//!
+//! * labels point at [`Span`](crate::Span)s, primary ones with `^`, secondary ones with `-`,
+//! * spans over several lines get a `|` gutter,
+//! * tabs and wide chars like `안녕` stay aligned,
+//! * plain text or ANSI colours.
+//!
+//! ```
+//! use cursor::{diagnostics::*, *};
+//!
+//! let source = "let x = 1\nprint x";
+//! let rendered = Diagnostic::error("expected `;`")
+//!     .with_origin("main.lox")
+//!     .with_label(Span::new(9..10, 9..10), "expected `;` here")
+//!     .with_note("statements end with `;`")
+//!     .render(source);
+//!
+//! assert_eq!(rendered, "\
+//! error: expected `;`
+//!  --> main.lox:1:10
+//!   |
+//! 1 | let x = 1
+//!   |          ^ expected `;` here
+//!   |
+//!   = note: statements end with `;`
+//! ");
+//! ```
+
+use super::*;
+use std::{collections::BTreeSet, fmt::Write};
+
+mod tables;
+use tables::{WIDE, ZERO_WIDTH};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+impl Severity {
+    #[inline]
+    fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+            Severity::Help => "help",
+        }
+    }
+    #[inline]
+    fn style(self) -> Style {
+        match self {
+            Severity::Error => Style::Red,
+            Severity::Warning => Style::Yellow,
+            Severity::Note => Style::Green,
+            Severity::Help => Style::Cyan,
+        }
+    }
+}
+impl fmt::Display for Severity {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// a message pointing at a span.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    /// `^` if primary, `-` if not.
+    pub primary: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    /// usually a file name, shown in the `-->` line.
+    pub origin: Option<String>,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    #[inline]
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            message: message.into(),
+            origin: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+    #[inline]
+    pub fn error(message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Error, message)
+    }
+    #[inline]
+    pub fn warning(message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Warning, message)
+    }
+    #[inline]
+    pub fn note(message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Note, message)
+    }
+    #[inline]
+    pub fn help(message: impl Into<String>) -> Self {
+        Diagnostic::new(Severity::Help, message)
+    }
+    #[inline]
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+    #[inline]
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: true,
+        });
+        self
+    }
+    #[inline]
+    pub fn with_secondary_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label {
+            span,
+            message: message.into(),
+            primary: false,
+        });
+        self
+    }
+    #[inline]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// plain text.
+    #[inline]
+    pub fn render(&self, source: &str) -> String {
+        Renderer::plain().render(self, source)
+    }
+    /// with ANSI colours.
+    #[inline]
+    pub fn render_ansi(&self, source: &str) -> String {
+        Renderer::ansi().render(self, source)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ColorMode {
+    Plain,
+    Ansi,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Renderer {
+    pub color: ColorMode,
+    /// spaces per tab.
+    pub tab_width: usize,
+}
+impl Default for Renderer {
+    #[inline]
+    fn default() -> Self {
+        Renderer::plain()
+    }
+}
+
+impl Renderer {
+    #[inline]
+    pub fn plain() -> Self {
+        Renderer {
+            color: ColorMode::Plain,
+            tab_width: 4,
+        }
+    }
+    #[inline]
+    pub fn ansi() -> Self {
+        Renderer {
+            color: ColorMode::Ansi,
+            tab_width: 4,
+        }
+    }
+    #[inline]
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    pub fn render(&self, diagnostic: &Diagnostic, source: &str) -> String {
+        let mut out = Out {
+            text: String::new(),
+            ansi: self.color == ColorMode::Ansi,
+        };
+        let severity = diagnostic.severity;
+        out.paint(severity.style(), severity.as_str());
+        out.paint(Style::Bold, &format!(": {}", diagnostic.message));
+        out.text.push('\n');
+
+        let snippet = Snippet::new(self, diagnostic, source);
+        let w = snippet.number_width();
+        if let Some(label) = snippet.main_label() {
+            let (line, col) = snippet.lines.line_col(label.start).unwrap();
+            out.text.push_str(&" ".repeat(w));
+            out.paint(Style::Blue, "--> ");
+            match &diagnostic.origin {
+                Some(origin) => writeln!(out.text, "{}:{}:{}", origin, line + 1, col + 1),
+                None => writeln!(out.text, "{}:{}", line + 1, col + 1),
+            }
+            .unwrap();
+            snippet.render(&mut out, w);
+        }
+        if !diagnostic.notes.is_empty() && !diagnostic.labels.is_empty() {
+            out.gutter(w, "");
+            out.text.push('\n');
+        }
+        for note in &diagnostic.notes {
+            out.text.push_str(&" ".repeat(w));
+            out.paint(Style::Blue, " = ");
+            out.paint(Style::Bold, "note");
+            writeln!(out.text, ": {}", note).unwrap();
+        }
+        out.text
+    }
+}
+
+// ------ widths ------
+
+/// columns `c` takes in a terminal, `2` for the wide chars like `안`, `0` for the combining ones.
+pub fn char_width(c: char) -> usize {
+    let c = c as u32;
+    if c < 0x7f {
+        return (c >= 0x20) as usize;
+    }
+    if c < 0xa0 || in_table(c, ZERO_WIDTH) {
+        0
+    } else if in_table(c, WIDE) {
+        2
+    } else {
+        1
+    }
+}
+
+/// columns `s` takes in a terminal, tabs excluded.
+pub fn str_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+#[inline]
+fn in_table(c: u32, table: &[(u32, u32)]) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| {
+            if hi < c {
+                Ordering::Less
+            } else if lo > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+// ------ output ------
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Style {
+    Plain,
+    Bold,
+    Red,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+}
+impl Style {
+    #[inline]
+    fn code(self) -> &'static str {
+        match self {
+            Style::Plain => "0",
+            Style::Bold => "1",
+            Style::Red => "1;31",
+            Style::Yellow => "1;33",
+            Style::Green => "1;32",
+            Style::Cyan => "1;36",
+            Style::Blue => "1;34",
+        }
+    }
+}
+
+struct Out {
+    text: String,
+    ansi: bool,
+}
+impl Out {
+    #[inline]
+    fn paint(&mut self, style: Style, s: &str) {
+        if !self.ansi || style == Style::Plain || s.is_empty() {
+            self.text.push_str(s);
+        } else {
+            write!(self.text, "\x1b[{}m{}\x1b[0m", style.code(), s).unwrap();
+        }
+    }
+    /// `{number} | `, without the trailing space if `number` is empty.
+    #[inline]
+    fn gutter(&mut self, width: usize, number: &str) {
+        self.paint(Style::Blue, &format!("{:>w$} |", number, w = width));
+    }
+    /// runs of the same style.
+    #[inline]
+    fn cells(&mut self, cells: &[(char, Style)]) {
+        let mut run = String::new();
+        let mut style = Style::Plain;
+        for &(c, s) in cells {
+            if s != style && !run.is_empty() {
+                self.paint(style, &run);
+                run.clear();
+            }
+            style = s;
+            run.push(c);
+        }
+        self.paint(style, &run);
+    }
+}
+
+// ------ layout ------
+
+struct Resolved<'d> {
+    label: &'d Label,
+    /// byte of the first char.
+    start: usize,
+    start_line: usize,
+    end_line: usize,
+    /// display columns, `end_col` is exclusive for one line, the last char for more.
+    start_col: usize,
+    end_col: usize,
+    /// gutter slot of a multi-line label.
+    depth: usize,
+}
+impl Resolved<'_> {
+    #[inline]
+    fn is_multi(&self) -> bool {
+        self.start_line != self.end_line
+    }
+    #[inline]
+    fn mark(&self) -> char {
+        if self.label.primary {
+            '^'
+        } else {
+            '-'
+        }
+    }
+}
+
+struct Snippet<'d, 's> {
+    source: &'s str,
+    tab_width: usize,
+    severity: Style,
+    lines: LineIndex<'s>,
+    labels: Vec<Resolved<'d>>,
+    multis: usize,
+}
+
+impl<'d, 's> Snippet<'d, 's> {
+    fn new(renderer: &Renderer, diagnostic: &'d Diagnostic, source: &'s str) -> Self {
+        let mut snippet = Snippet {
+            source,
+            tab_width: renderer.tab_width,
+            severity: diagnostic.severity.style(),
+            lines: LineIndex::new(source),
+            labels: Vec::new(),
+            multis: 0,
+        };
+        for label in &diagnostic.labels {
+            let resolved = snippet.resolve(label);
+            snippet.labels.push(resolved);
+        }
+        snippet
+            .labels
+            .sort_by_key(|l| (l.start_line, l.start_col, !l.label.primary));
+        for label in snippet.labels.iter_mut().filter(|l| l.is_multi()) {
+            label.depth = snippet.multis;
+            snippet.multis += 1;
+        }
+        snippet
+    }
+
+    #[inline]
+    fn line_of(&self, byte: usize) -> usize {
+        self.lines.line_of(byte).unwrap()
+    }
+    #[inline]
+    fn line_start(&self, line: usize) -> usize {
+        self.lines.line_range(line).unwrap().start
+    }
+    /// the line without its line ending.
+    #[inline]
+    fn line_text(&self, line: usize) -> &'s str {
+        self.lines.line_str(line).unwrap()
+    }
+    /// display column of `byte` in `line`.
+    #[inline]
+    fn col(&self, line: usize, byte: usize) -> usize {
+        let text = self.line_text(line);
+        let end = (byte - self.line_start(line)).min(text.len());
+        self.width(&text[..end])
+    }
+    #[inline]
+    fn width(&self, s: &str) -> usize {
+        s.chars()
+            .map(|c| {
+                if c == '\t' {
+                    self.tab_width
+                } else {
+                    char_width(c)
+                }
+            })
+            .sum()
+    }
+    #[inline]
+    fn floor_char(&self, mut byte: usize) -> usize {
+        while !self.source.is_char_boundary(byte) {
+            byte -= 1;
+        }
+        byte
+    }
+
+    fn ceil_char(&self, mut byte: usize) -> usize {
+        while !self.source.is_char_boundary(byte) {
+            byte += 1;
+        }
+        byte
+    }
+
+    fn resolve(&self, label: &'d Label) -> Resolved<'d> {
+        let len = self.source.len();
+        let start = self.floor_char(label.span.byte_start.min(len));
+        // a span ending inside a char covers all of it.
+        let end = self.ceil_char(label.span.byte_end.clamp(start, len));
+        let start_line = self.line_of(start);
+        let last = self.floor_char(if end > start { end - 1 } else { start });
+        let mut end_line = self.line_of(last);
+        // a span ending with a line ending stays on its line.
+        if end_line > start_line && self.line_text(end_line).is_empty() {
+            end_line = self.line_of(self.line_start(end_line) - 1);
+        }
+        let start_col = self.col(start_line, start);
+        let end_col = if end_line == start_line {
+            start_col.max(self.col(start_line, end)).max(start_col + 1)
+        } else {
+            self.col(
+                end_line,
+                last.min(self.line_start(end_line) + self.line_text(end_line).len()),
+            )
+        };
+        Resolved {
+            label,
+            start,
+            start_line,
+            end_line,
+            start_col,
+            end_col,
+            depth: 0,
+        }
+    }
+
+    #[inline]
+    fn style_of(&self, label: &Resolved<'_>) -> Style {
+        if label.label.primary {
+            self.severity
+        } else {
+            Style::Blue
+        }
+    }
+    #[inline]
+    fn main_label(&self) -> Option<&Resolved<'d>> {
+        self.labels
+            .iter()
+            .find(|l| l.label.primary)
+            .or_else(|| self.labels.first())
+    }
+
+    /// lines to show, the long multi-line spans are cut in the middle.
+    fn lines(&self) -> BTreeSet<usize> {
+        let mut lines = BTreeSet::new();
+        for l in &self.labels {
+            if l.end_line - l.start_line <= 4 {
+                lines.extend(l.start_line..=l.end_line);
+            } else {
+                lines.extend([l.start_line, l.start_line + 1, l.end_line - 1, l.end_line]);
+            }
+        }
+        // a single hidden line is shown rather than `...`.
+        let gaps: Vec<_> = lines
+            .iter()
+            .zip(lines.iter().skip(1))
+            .filter(|(&a, &b)| b - a == 2)
+            .map(|(&a, _)| a + 1)
+            .collect();
+        lines.extend(gaps);
+        lines
+    }
+
+    #[inline]
+    fn number_width(&self) -> usize {
+        let last = self.labels.iter().map(|l| l.end_line).max().unwrap_or(0);
+        (last + 1).to_string().len()
+    }
+
+    fn render(&self, out: &mut Out, w: usize) {
+        let g = self.multis * 2;
+        let mut open = vec![false; self.multis];
+        let gutter_cells = |open: &[bool]| -> Vec<(char, Style)> {
+            let mut cells = vec![(' ', Style::Plain); g];
+            for (depth, _) in open.iter().enumerate().filter(|(_, &o)| o) {
+                cells[depth * 2] = ('|', self.severity);
+            }
+            cells
+        };
+        let row = |out: &mut Out, cells: &[(char, Style)], message: &str, style: Style| {
+            out.gutter(w, "");
+            out.text.push(' ');
+            out.cells(cells);
+            if !message.is_empty() {
+                out.text.push(' ');
+                out.paint(style, message);
+            }
+            out.text.truncate(out.text.trim_end_matches(' ').len());
+            out.text.push('\n');
+        };
+
+        out.gutter(w, "");
+        out.text.push('\n');
+        let mut prev: Option<usize> = None;
+        for line in self.lines() {
+            if matches!(prev, Some(p) if line > p + 1) {
+                out.paint(Style::Blue, "...");
+                out.text.push('\n');
+            }
+            prev = Some(line);
+
+            // the source line.
+            out.gutter(w, &(line + 1).to_string());
+            out.text.push(' ');
+            out.cells(&gutter_cells(&open));
+            let text = self.line_text(line);
+            for c in text.chars() {
+                match c {
+                    '\t' => out.text.push_str(&" ".repeat(self.tab_width)),
+                    _ => out.text.push(c),
+                }
+            }
+            out.text.truncate(out.text.trim_end_matches(' ').len());
+            out.text.push('\n');
+
+            // one-line labels, the last one gets its message inline
+            // unless another one reaches over its start.
+            let singles: Vec<_> = self
+                .labels
+                .iter()
+                .filter(|l| !l.is_multi() && l.start_line == line)
+                .collect();
+            if !singles.is_empty() {
+                let mut cells = gutter_cells(&open);
+                for l in singles.iter().filter(|l| !l.label.primary) {
+                    fill(&mut cells, g + l.start_col, g + l.end_col, '-', Style::Blue);
+                }
+                for l in singles.iter().filter(|l| l.label.primary) {
+                    fill(
+                        &mut cells,
+                        g + l.start_col,
+                        g + l.end_col,
+                        '^',
+                        self.severity,
+                    );
+                }
+                let (last, rest) = singles.split_last().unwrap();
+                let inline = rest.iter().all(|l| l.end_col <= last.start_col);
+                let pending: Vec<_> = if inline {
+                    row(out, &cells, &last.label.message, self.style_of(last));
+                    rest.iter()
+                } else {
+                    row(out, &cells, "", Style::Plain);
+                    singles.iter()
+                }
+                .filter(|l| !l.label.message.is_empty())
+                .collect();
+
+                let connectors = |labels: &[&&Resolved<'d>]| {
+                    let mut cells = gutter_cells(&open);
+                    for l in labels {
+                        fill(
+                            &mut cells,
+                            g + l.start_col,
+                            g + l.start_col + 1,
+                            '|',
+                            self.style_of(l),
+                        );
+                    }
+                    cells
+                };
+                if !pending.is_empty() {
+                    row(out, &connectors(&pending), "", Style::Plain);
+                }
+                for i in (0..pending.len()).rev() {
+                    let l = pending[i];
+                    let mut cells = connectors(&pending[..i]);
+                    cells.resize(g + l.start_col, (' ', Style::Plain));
+                    out.gutter(w, "");
+                    out.text.push(' ');
+                    out.cells(&cells);
+                    out.paint(self.style_of(l), &l.label.message);
+                    out.text.push('\n');
+                }
+            }
+
+            // multi-line labels ending here, then starting here.
+            for l in self
+                .labels
+                .iter()
+                .filter(|l| l.is_multi() && l.end_line == line)
+            {
+                let (mark, style) = (l.mark(), self.style_of(l));
+                let mut cells = gutter_cells(&open);
+                fill(&mut cells, l.depth * 2, l.depth * 2 + 1, '|', style);
+                fill(&mut cells, l.depth * 2 + 1, g + l.end_col, '_', style);
+                fill(&mut cells, g + l.end_col, g + l.end_col + 1, mark, style);
+                row(out, &cells, &l.label.message, style);
+                open[l.depth] = false;
+            }
+            for l in self
+                .labels
+                .iter()
+                .filter(|l| l.is_multi() && l.start_line == line)
+            {
+                let (mark, style) = (l.mark(), self.style_of(l));
+                let mut cells = gutter_cells(&open);
+                fill(&mut cells, l.depth * 2 + 1, g + l.start_col, '_', style);
+                fill(
+                    &mut cells,
+                    g + l.start_col,
+                    g + l.start_col + 1,
+                    mark,
+                    style,
+                );
+                row(out, &cells, "", style);
+                open[l.depth] = true;
+            }
+        }
+    }
+}
+
+/// `cells[from..to]` with `c`, grown if needed.
+#[inline]
+fn fill(cells: &mut Vec<(char, Style)>, from: usize, to: usize, c: char, style: Style) {
+    if cells.len() < to {
+        cells.resize(to, (' ', Style::Plain));
+    }
+    for cell in &mut cells[from..to] {
+        *cell = (c, style);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! a reusable lexer driver over [`StrCursor`](crate::StrCursor).
+//!
+//! * rules map a literal, a predicate, a [`Pattern`](crate::Pattern),
+//!   an [`AnchoredMatch`](crate::AnchoredMatch) or a closure to a token kind,
+//! * the longest match wins, then the highest priority, then the rule added first,
+//! * trivia rules are matched like the others but skipped,
+//! * unrecognised input becomes one error token, up to where a rule matches again.
+//!
+//! ```
+//! use cursor::lexer::*;
+//!
+//! #[derive(Debug, PartialEq, Clone, Copy)]
+//! enum Kind { If, Ident, Number, EqEq, Eq, Error }
+//!
+//! let lexer = Lexer::new("if x == 42 ?? y", Kind::Error)
+//!     .literal(Kind::If, "if")
+//!     .literal(Kind::EqEq, "==")
+//!     .literal(Kind::Eq, "=")
+//!     .predicate(Kind::Ident, char::is_alphabetic)
+//!     .predicate(Kind::Number, |c| c.is_ascii_digit())
+//!     .skip_whitespace();
+//!
+//! let tokens: Vec<_> = lexer.map(|t| (t.kind, t.text)).collect();
+//! assert_eq!(tokens, [
+//!     (Kind::If, "if"), (Kind::Ident, "x"), (Kind::EqEq, "=="),
+//!     (Kind::Number, "42"), (Kind::Error, "??"), (Kind::Ident, "y"),
+//! ]);
+//! ```
+
+use super::*;
+use crate::combinators::Input;
+use alloc::{boxed::Box, vec::Vec};
+
+/// a lexed token.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Token<'s, K> {
+    pub kind: K,
+    pub span: Span,
+    pub text: &'s str,
+}
+
+/// how a rule matches.
+trait Matcher<'s> {
+    /// byte length of a match at `start` in the whole source.
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize>;
+    /// where the first non-empty match from `from` starts.
+    /// * tries each char by default.
+    #[inline]
+    fn find_from(&mut self, source: &'s str, from: usize) -> Option<usize> {
+        source[from..]
+            .char_indices()
+            .map(|(i, _)| from + i)
+            .find(|&start| {
+                self.match_at(source, start)
+                    .is_some_and(|len| hits(source, start, len))
+            })
+    }
+}
+
+struct Rule<'s, K> {
+    /// `None` for trivia.
+    kind: Option<K>,
+    priority: i32,
+    matcher: Box<dyn Matcher<'s> + 's>,
+    /// (from, found) of the last `find_from`, the lexer only moves on.
+    next_hit: Option<(usize, Option<usize>)>,
+}
+
+impl<'s, K> Rule<'s, K> {
+    /// where the rule matches next from `from`, the last search is reused while it's still ahead.
+    #[inline]
+    fn find_from(&mut self, source: &'s str, from: usize) -> Option<usize> {
+        match self.next_hit {
+            Some((searched, found)) if searched <= from && found.is_none_or(|at| at >= from) => {
+                found
+            }
+            _ => {
+                let found = self.matcher.find_from(source, from);
+                self.next_hit = Some((from, found));
+                found
+            }
+        }
+    }
+}
+
+/// see the [module](self) docs.
+pub struct Lexer<'s, K, E: Extras<char> = NoneExtras<char>> {
+    cursor: StrCursor<'s, E>,
+    rules: Vec<Rule<'s, K>>,
+    error: K,
+}
+
+impl<'s, K> Lexer<'s, K> {
+    #[inline]
+    pub fn new(source: &'s str, error: K) -> Self {
+        Lexer::from_cursor(StrCursor::new(source), error)
+    }
+    #[inline]
+    pub fn new_with_extras<EXTRAS: Extras<char>>(
+        source: &'s str,
+        error: K,
+    ) -> Lexer<'s, K, EXTRAS> {
+        Lexer::from_cursor(StrCursor::new_with_extras::<EXTRAS>(source), error)
+    }
+}
+
+impl<'s, K, E: Extras<char>> Lexer<'s, K, E> {
+    /// lexes from where `cursor` is, `error` is the kind of unrecognised input.
+    #[inline]
+    pub fn from_cursor(cursor: StrCursor<'s, E>, error: K) -> Self {
+        Lexer {
+            cursor,
+            rules: Vec::new(),
+            error,
+        }
+    }
+
+    // ------ rules ------
+
+    /// exactly `literal`, priority `1` so it beats a predicate of the same length.
+    #[inline]
+    pub fn literal(self, kind: K, literal: &'s str) -> Self {
+        self.push(Some(kind), 1, Box::new(LiteralMatcher(literal)))
+    }
+    /// the longest run of chars matching `predicate`.
+    #[inline]
+    pub fn predicate<F: FnMut(char) -> bool + 's>(self, kind: K, predicate: F) -> Self {
+        self.push(Some(kind), 0, Box::new(PredicateMatcher(predicate)))
+    }
+    /// one match of `pattern`.
+    #[inline]
+    pub fn pattern<P: Pattern<str> + 's>(self, kind: K, pattern: P) -> Self {
+        self.push(Some(kind), 0, Box::new(PatternMatcher(pattern)))
+    }
+    /// a match of `regex` anchored at the current char, like a [`regexp::Regex`](crate::regexp::Regex).
+    #[inline]
+    pub fn regex<R: AnchoredMatch + 's>(self, kind: K, regex: R) -> Self {
+        self.push(Some(kind), 0, Box::new(RegexMatcher(regex)))
+    }
+    /// `f` gets what is left and returns how many bytes it matched.
+    #[inline]
+    pub fn custom<F: FnMut(&'s str) -> Option<usize> + 's>(self, kind: K, f: F) -> Self {
+        self.push(Some(kind), 0, Box::new(CustomMatcher(f)))
+    }
+
+    // ------ trivia ------
+
+    /// skips the longest runs of chars matching `predicate`.
+    #[inline]
+    pub fn trivia<F: FnMut(char) -> bool + 's>(self, predicate: F) -> Self {
+        self.push(None, 0, Box::new(PredicateMatcher(predicate)))
+    }
+    /// skips whatever `f` matches, like comments.
+    #[inline]
+    pub fn trivia_custom<F: FnMut(&'s str) -> Option<usize> + 's>(self, f: F) -> Self {
+        self.push(None, 0, Box::new(CustomMatcher(f)))
+    }
+    /// skips the unicode whitespaces.
+    #[inline]
+    pub fn skip_whitespace(self) -> Self {
+        self.trivia(char::is_whitespace)
+    }
+
+    /// priority of the last added rule, higher wins between matches of the same length.
+    #[inline]
+    pub fn priority(mut self, priority: i32) -> Self {
+        if let Some(rule) = self.rules.last_mut() {
+            rule.priority = priority;
+        }
+        self
+    }
+
+    // ------ state ------
+
+    #[inline]
+    pub fn cursor(&self) -> &StrCursor<'s, E> {
+        &self.cursor
+    }
+    #[inline]
+    pub fn into_cursor(self) -> StrCursor<'s, E> {
+        self.cursor
+    }
+    #[inline]
+    pub fn extras(&self) -> &E {
+        self.cursor.extras()
+    }
+    /// what hasn't been lexed yet.
+    #[inline]
+    pub fn rest(&self) -> &'s str {
+        self.cursor.rest()
+    }
+
+    // ------ private ------
+
+    #[inline]
+    fn push(mut self, kind: Option<K>, priority: i32, matcher: Box<dyn Matcher<'s> + 's>) -> Self {
+        self.rules.push(Rule {
+            kind,
+            priority,
+            matcher,
+            next_hit: None,
+        });
+        self
+    }
+
+    /// (index of the rule, byte length) of the best match at `start`.
+    #[inline]
+    fn best_match(&mut self, start: usize) -> Option<(usize, usize)> {
+        let source = self.cursor.as_str();
+        let mut best: Option<(usize, usize, i32)> = None;
+        for (i, rule) in self.rules.iter_mut().enumerate() {
+            let len = match rule.matcher.match_at(source, start) {
+                Some(len) if hits(source, start, len) => len,
+                _ => continue,
+            };
+            if best.is_none_or(|(_, l, p)| (len, rule.priority) > (l, p)) {
+                best = Some((i, len, rule.priority));
+            }
+        }
+        best.map(|(i, len, _)| (i, len))
+    }
+
+    #[inline]
+    fn consume(&mut self, len: usize) -> (Span, &'s str) {
+        let (start, byte_start) = (self.cursor.next_pos(), self.cursor.offset());
+        let text = &self.cursor.as_str()[byte_start..byte_start + len];
+        let chars = utf::count_chars(text.as_bytes());
+        self.cursor.bump(chars);
+        let span = Span::new(start..start + chars, byte_start..byte_start + len);
+        (span, text)
+    }
+}
+
+impl<'s, K: Clone, E: Extras<char>> Iterator for Lexer<'s, K, E> {
+    type Item = Token<'s, K>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let start = self.cursor.offset();
+            if start == self.cursor.as_str().len() {
+                return None;
+            }
+            match self.best_match(start) {
+                Some((i, len)) => {
+                    let (span, text) = self.consume(len);
+                    match &self.rules[i].kind {
+                        Some(kind) => {
+                            return Some(Token {
+                                kind: kind.clone(),
+                                span,
+                                text,
+                            })
+                        }
+                        None => continue,
+                    }
+                }
+                None => {
+                    // up to the next char where any rule matches, the searches are reused by the next runs.
+                    let source = self.cursor.as_str();
+                    let from = start + self.cursor.rest().chars().next().map_or(0, char::len_utf8);
+                    let end = self
+                        .rules
+                        .iter_mut()
+                        .filter_map(|rule| rule.find_from(source, from))
+                        .min()
+                        .unwrap_or(source.len());
+                    let (span, text) = self.consume(end - start);
+                    return Some(Token {
+                        kind: self.error.clone(),
+                        span,
+                        text,
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl<K: fmt::Debug, E: Extras<char>> fmt::Debug for Lexer<'_, K, E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lexer")
+            .field("cursor", &self.cursor)
+            .field("rules", &self.rules.len())
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+// ------ matchers ------
+
+/// a match the lexer can take.
+#[inline]
+fn hits(source: &str, start: usize, len: usize) -> bool {
+    len > 0 && source.is_char_boundary(start + len)
+}
+
+struct LiteralMatcher<'s>(&'s str);
+impl<'s> Matcher<'s> for LiteralMatcher<'s> {
+    #[inline]
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize> {
+        source[start..].starts_with(self.0).then_some(self.0.len())
+    }
+    #[inline]
+    fn find_from(&mut self, source: &'s str, from: usize) -> Option<usize> {
+        if self.0.is_empty() {
+            return None;
+        }
+        Some(from + source[from..].find(self.0)?)
+    }
+}
+
+struct PredicateMatcher<F>(F);
+impl<'s, F: FnMut(char) -> bool> Matcher<'s> for PredicateMatcher<F> {
+    #[inline]
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize> {
+        let rest = &source[start..];
+        Some(rest.find(|c| !(self.0)(c)).unwrap_or(rest.len()))
+    }
+    #[inline]
+    fn find_from(&mut self, source: &'s str, from: usize) -> Option<usize> {
+        Some(from + source[from..].find(&mut self.0)?)
+    }
+}
+
+struct PatternMatcher<P>(P);
+impl<'s, P: Pattern<str>> Matcher<'s> for PatternMatcher<P> {
+    #[inline]
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize> {
+        let rest = &source[start..];
+        let chars = self.0.match_prefix(rest)?;
+        Some(
+            rest.char_indices()
+                .nth(chars)
+                .map_or(rest.len(), |(i, _)| i),
+        )
+    }
+}
+
+struct RegexMatcher<R>(R);
+impl<'s, R: AnchoredMatch> Matcher<'s> for RegexMatcher<R> {
+    #[inline]
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize> {
+        Some(self.0.match_at(source, start)? - start)
+    }
+    #[inline]
+    fn find_from(&mut self, source: &'s str, from: usize) -> Option<usize> {
+        self.0.find_from(source, from).map(|(start, _)| start)
+    }
+}
+
+struct CustomMatcher<F>(F);
+impl<'s, F: FnMut(&'s str) -> Option<usize>> Matcher<'s> for CustomMatcher<F> {
+    #[inline]
+    fn match_at(&mut self, source: &'s str, start: usize) -> Option<usize> {
+        (self.0)(&source[start..])
+    }
+}
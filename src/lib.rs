@@ -7,11 +7,28 @@
 
 #![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod cmn;
 pub use cmn::*;
 
 mod traits;
 pub use traits::*;
 
+#[cfg(feature = "derive")]
+pub use cursor_derive::Extras;
+
 mod cursors;
 pub use cursors::*;
+
+#[cfg(feature = "alloc")]
+pub mod regexp;
+
+pub mod combinators;
+
+#[cfg(feature = "alloc")]
+pub mod lexer;
+
+#[cfg(feature = "std")]
+pub mod diagnostics;
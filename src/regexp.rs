@@ -0,0 +1,780 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! a small regular-expression engine for [`match_regex`](crate::TextCursorTrait::match_regex),
+//! over a `str` or the decoded chars of a `Text`.
+//!
+//! * literals, `.`, classes `[a-z_]`, `[^...]`, `\d \w \s \D \W \S`,
+//! * groups `(...)`, `(?:...)`, alternation `|`,
+//! * repetition `* + ? {n} {n,} {n,m}`, lazy with a trailing `?`,
+//! * anchors `^ $` (line), `\A \z` (text), `\b \B` (word boundary).
+//!
+//! compiled once, matched with a Pike VM:
+//! leftmost-first like Perl, but linear in the input, no backtracking blow-ups.
+//! a reversed copy of the program finds where a match ending at a char starts,
+//! for cursors heading left.
+
+use super::*;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// the compiled program can't be larger than this.
+const MAX_INSTS: usize = 1 << 16;
+/// `{n,m}` can't count more than this.
+const MAX_REPEAT: u32 = 1000;
+
+/// why a pattern couldn't compile.
+/// * `pos` is the byte offset in the pattern.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RegexError {
+    /// the pattern ended in the middle of something.
+    UnexpectedEnd,
+    /// this char can't be here.
+    Unexpected { pos: usize },
+    /// the group or class opened here is never closed.
+    Unclosed { pos: usize },
+    /// a repetition without anything before it.
+    NothingToRepeat { pos: usize },
+    /// a `{n,m}` that doesn't make sense.
+    BadRepetition { pos: usize },
+    /// an unknown `\` escape.
+    BadEscape { pos: usize },
+    /// the compiled program is too large.
+    TooBig,
+}
+impl fmt::Display for RegexError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegexError::UnexpectedEnd => write!(f, "unexpected end of pattern"),
+            RegexError::Unexpected { pos } => write!(f, "unexpected char at {}", pos),
+            RegexError::Unclosed { pos } => write!(f, "unclosed group or class at {}", pos),
+            RegexError::NothingToRepeat { pos } => write!(f, "nothing to repeat at {}", pos),
+            RegexError::BadRepetition { pos } => write!(f, "bad repetition at {}", pos),
+            RegexError::BadEscape { pos } => write!(f, "bad escape at {}", pos),
+            RegexError::TooBig => write!(f, "compiled pattern is too big"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for RegexError {}
+
+// ------ syntax ------
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Perl {
+    Digit,
+    Word,
+    Space,
+}
+impl Perl {
+    #[inline]
+    fn matches(self, c: char) -> bool {
+        match self {
+            Perl::Digit => c.is_ascii_digit(),
+            Perl::Word => is_word_char(c),
+            Perl::Space => c.is_whitespace(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Look {
+    StartLine,
+    EndLine,
+    StartText,
+    EndText,
+    WordBoundary,
+    NotWordBoundary,
+}
+impl Look {
+    #[inline]
+    fn holds<H: Haystack + ?Sized>(self, text: &H, at: usize) -> bool {
+        let prev = text.char_before(at);
+        let prev = prev.map(|(c, _)| c);
+        let next = text.char_at(at).map(|(c, _)| c);
+        let boundary = || prev.is_some_and(is_word_char) != next.is_some_and(is_word_char);
+        match self {
+            Look::StartLine => matches!(prev, None | Some('\n')),
+            Look::EndLine => matches!(next, None | Some('\n')),
+            Look::StartText => prev.is_none(),
+            Look::EndText => next.is_none(),
+            Look::WordBoundary => boundary(),
+            Look::NotWordBoundary => !boundary(),
+        }
+    }
+}
+
+#[inline]
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+struct Class {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+    /// (class, negated)
+    perls: Vec<(Perl, bool)>,
+}
+impl Class {
+    #[inline]
+    fn matches(&self, c: char) -> bool {
+        let hit = self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+            || self.perls.iter().any(|&(perl, neg)| perl.matches(c) != neg);
+        hit != self.negated
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Empty,
+    Char(char),
+    Any,
+    Class(Class),
+    Look(Look),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+impl Node {
+    /// the same pattern read from right to left.
+    fn reversed(&self) -> Node {
+        match self {
+            Node::Concat(nodes) => Node::Concat(nodes.iter().rev().map(Node::reversed).collect()),
+            Node::Alt(alts) => Node::Alt(alts.iter().map(Node::reversed).collect()),
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+            } => Node::Repeat {
+                node: Box::new(node.reversed()),
+                min: *min,
+                max: *max,
+                greedy: *greedy,
+            },
+            node => node.clone(),
+        }
+    }
+}
+
+/// what a `\` stands for.
+enum Escape {
+    Char(char),
+    Perl(Perl, bool),
+    Look(Look),
+}
+
+struct Parser<'p> {
+    pattern: &'p str,
+    pos: usize,
+}
+impl<'p> Parser<'p> {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.pos..].chars().next()
+    }
+    #[inline]
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+    #[inline]
+    fn eat(&mut self, c: char) -> bool {
+        let hit = self.peek() == Some(c);
+        if hit {
+            self.pos += c.len_utf8();
+        }
+        hit
+    }
+
+    fn parse_alt(&mut self) -> Result<Node, RegexError> {
+        let mut alts = vec![self.parse_concat()?];
+        while self.eat('|') {
+            alts.push(self.parse_concat()?);
+        }
+        Ok(if alts.len() == 1 {
+            alts.pop().unwrap()
+        } else {
+            Node::Alt(alts)
+        })
+    }
+    fn parse_concat(&mut self) -> Result<Node, RegexError> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_repeat(atom)?);
+        }
+        Ok(match nodes.len() {
+            0 => Node::Empty,
+            1 => nodes.pop().unwrap(),
+            _ => Node::Concat(nodes),
+        })
+    }
+    fn parse_atom(&mut self) -> Result<Node, RegexError> {
+        let start = self.pos;
+        let c = self.bump().ok_or(RegexError::UnexpectedEnd)?;
+        Ok(match c {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(RegexError::Unexpected { pos: start + 1 });
+                }
+                let node = self.parse_alt()?;
+                if !self.eat(')') {
+                    return Err(RegexError::Unclosed { pos: start });
+                }
+                node
+            }
+            '[' => Node::Class(self.parse_class(start)?),
+            '.' => Node::Any,
+            '^' => Node::Look(Look::StartLine),
+            '$' => Node::Look(Look::EndLine),
+            '\\' => match self.parse_escape()? {
+                Escape::Char(c) => Node::Char(c),
+                Escape::Perl(perl, negated) => Node::Class(Class {
+                    perls: vec![(perl, negated)],
+                    ..Class::default()
+                }),
+                Escape::Look(look) => Node::Look(look),
+            },
+            '*' | '+' | '?' => return Err(RegexError::NothingToRepeat { pos: start }),
+            '{' if self.counted_ahead(start) => {
+                return Err(RegexError::NothingToRepeat { pos: start })
+            }
+            c => Node::Char(c),
+        })
+    }
+    /// if `{` at `start` opens a `{n,m}`, otherwise it's a literal.
+    #[inline]
+    fn counted_ahead(&self, start: usize) -> bool {
+        let rest = &self.pattern[start + 1..];
+        let body = match rest.find('}') {
+            Some(end) => &rest[..end],
+            None => return false,
+        };
+        !body.is_empty()
+            && body.starts_with(|c: char| c.is_ascii_digit())
+            && body.chars().all(|c| c.is_ascii_digit() || c == ',')
+    }
+    fn parse_repeat(&mut self, mut node: Node) -> Result<Node, RegexError> {
+        loop {
+            let start = self.pos;
+            let (min, max) = match self.peek() {
+                Some('*') => (0, None),
+                Some('+') => (1, None),
+                Some('?') => (0, Some(1)),
+                Some('{') if self.counted_ahead(start) => {
+                    self.bump();
+                    let min = self.parse_number(start)?;
+                    let max = if self.eat(',') {
+                        if self.peek() == Some('}') {
+                            None
+                        } else {
+                            Some(self.parse_number(start)?)
+                        }
+                    } else {
+                        Some(min)
+                    };
+                    if !self.eat('}') || matches!(max, Some(max) if max < min) {
+                        return Err(RegexError::BadRepetition { pos: start });
+                    }
+                    if matches!(node, Node::Look(_)) {
+                        return Err(RegexError::NothingToRepeat { pos: start });
+                    }
+                    let greedy = !self.eat('?');
+                    node = Node::Repeat {
+                        node: Box::new(node),
+                        min,
+                        max,
+                        greedy,
+                    };
+                    continue;
+                }
+                _ => return Ok(node),
+            };
+            self.bump();
+            if matches!(node, Node::Look(_)) {
+                return Err(RegexError::NothingToRepeat { pos: start });
+            }
+            let greedy = !self.eat('?');
+            node = Node::Repeat {
+                node: Box::new(node),
+                min,
+                max,
+                greedy,
+            };
+        }
+    }
+    fn parse_number(&mut self, start: usize) -> Result<u32, RegexError> {
+        let mut n: u32 = 0;
+        let mut digits = 0;
+        while let Some(d) = self.peek().and_then(|c| c.to_digit(10)) {
+            self.bump();
+            digits += 1;
+            n = n.saturating_mul(10).saturating_add(d);
+        }
+        if digits == 0 || n > MAX_REPEAT {
+            return Err(RegexError::BadRepetition { pos: start });
+        }
+        Ok(n)
+    }
+    fn parse_escape(&mut self) -> Result<Escape, RegexError> {
+        let start = self.pos - 1;
+        let c = self.bump().ok_or(RegexError::UnexpectedEnd)?;
+        Ok(match c {
+            'd' => Escape::Perl(Perl::Digit, false),
+            'D' => Escape::Perl(Perl::Digit, true),
+            'w' => Escape::Perl(Perl::Word, false),
+            'W' => Escape::Perl(Perl::Word, true),
+            's' => Escape::Perl(Perl::Space, false),
+            'S' => Escape::Perl(Perl::Space, true),
+            'b' => Escape::Look(Look::WordBoundary),
+            'B' => Escape::Look(Look::NotWordBoundary),
+            'A' => Escape::Look(Look::StartText),
+            'z' => Escape::Look(Look::EndText),
+            'n' => Escape::Char('\n'),
+            'r' => Escape::Char('\r'),
+            't' => Escape::Char('\t'),
+            '0' => Escape::Char('\0'),
+            c if c.is_alphanumeric() => return Err(RegexError::BadEscape { pos: start }),
+            c => Escape::Char(c),
+        })
+    }
+    fn parse_class(&mut self, start: usize) -> Result<Class, RegexError> {
+        let mut class = Class {
+            negated: self.eat('^'),
+            ..Class::default()
+        };
+        let mut first = true;
+        loop {
+            let at = self.pos;
+            let c = self.bump().ok_or(RegexError::Unclosed { pos: start })?;
+            let lo = match c {
+                ']' if !first => return Ok(class),
+                '\\' => match self.parse_escape()? {
+                    Escape::Char(c) => c,
+                    Escape::Perl(perl, negated) => {
+                        class.perls.push((perl, negated));
+                        first = false;
+                        continue;
+                    }
+                    Escape::Look(_) => return Err(RegexError::BadEscape { pos: at }),
+                },
+                c => c,
+            };
+            first = false;
+            // `a-z`, but `a-` and `a-]` are literals.
+            let is_range = self.peek() == Some('-')
+                && !matches!(
+                    self.pattern[self.pos + 1..].chars().next(),
+                    None | Some(']')
+                );
+            if !is_range {
+                class.ranges.push((lo, lo));
+                continue;
+            }
+            self.bump();
+            let hi = match self.bump().ok_or(RegexError::Unclosed { pos: start })? {
+                '\\' => match self.parse_escape()? {
+                    Escape::Char(c) => c,
+                    _ => return Err(RegexError::BadEscape { pos: at }),
+                },
+                c => c,
+            };
+            if hi < lo {
+                return Err(RegexError::Unexpected { pos: at });
+            }
+            class.ranges.push((lo, hi));
+        }
+    }
+}
+
+// ------ program ------
+
+#[derive(Debug, Clone, Copy)]
+enum Inst {
+    Char(char),
+    Any,
+    Class(usize),
+    Look(Look),
+    /// tries the first one first.
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+
+#[derive(Default)]
+struct Compiler {
+    insts: Vec<Inst>,
+    classes: Vec<Class>,
+}
+impl Compiler {
+    #[inline]
+    fn push(&mut self, inst: Inst) -> Result<usize, RegexError> {
+        if self.insts.len() >= MAX_INSTS {
+            return Err(RegexError::TooBig);
+        }
+        self.insts.push(inst);
+        Ok(self.insts.len() - 1)
+    }
+    #[inline]
+    fn here(&self) -> usize {
+        self.insts.len()
+    }
+    #[inline]
+    fn split(&mut self, greedy: bool, body: usize, out: usize) -> Inst {
+        if greedy {
+            Inst::Split(body, out)
+        } else {
+            Inst::Split(out, body)
+        }
+    }
+    fn compile(&mut self, node: &Node) -> Result<(), RegexError> {
+        match node {
+            Node::Empty => {}
+            Node::Char(c) => {
+                self.push(Inst::Char(*c))?;
+            }
+            Node::Any => {
+                self.push(Inst::Any)?;
+            }
+            Node::Class(class) => {
+                self.classes.push(class.clone());
+                self.push(Inst::Class(self.classes.len() - 1))?;
+            }
+            Node::Look(look) => {
+                self.push(Inst::Look(*look))?;
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    self.compile(node)?;
+                }
+            }
+            Node::Alt(alts) => {
+                let mut jumps = Vec::new();
+                let (last, init) = alts.split_last().unwrap();
+                for alt in init {
+                    let split = self.push(Inst::Split(0, 0))?;
+                    self.compile(alt)?;
+                    jumps.push(self.push(Inst::Jmp(0))?);
+                    self.insts[split] = Inst::Split(split + 1, self.here());
+                }
+                self.compile(last)?;
+                for jump in jumps {
+                    self.insts[jump] = Inst::Jmp(self.here());
+                }
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+            } => {
+                for _ in 0..*min {
+                    self.compile(node)?;
+                }
+                match max {
+                    None => {
+                        let split = self.push(Inst::Split(0, 0))?;
+                        self.compile(node)?;
+                        self.push(Inst::Jmp(split))?;
+                        self.insts[split] = self.split(*greedy, split + 1, self.here());
+                    }
+                    Some(max) => {
+                        let mut splits = Vec::new();
+                        for _ in *min..*max {
+                            splits.push(self.push(Inst::Split(0, 0))?);
+                            self.compile(node)?;
+                        }
+                        for split in splits {
+                            self.insts[split] = self.split(*greedy, split + 1, self.here());
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// a compiled pattern, anchored where it's matched.
+#[derive(Clone)]
+pub struct Regex {
+    source: Box<str>,
+    /// the pattern, then the reversed one from `back`.
+    insts: Box<[Inst]>,
+    back: usize,
+    classes: Box<[Class]>,
+}
+impl fmt::Debug for Regex {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Regex").field(&self.source).finish()
+    }
+}
+impl fmt::Display for Regex {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// the threads of one step, in priority order.
+struct Threads {
+    order: Vec<usize>,
+    seen: Vec<bool>,
+}
+impl Threads {
+    #[inline]
+    fn new(len: usize) -> Self {
+        Threads {
+            order: Vec::with_capacity(len),
+            seen: vec![false; len],
+        }
+    }
+    #[inline]
+    fn clear(&mut self) {
+        for &pc in &self.order {
+            self.seen[pc] = false;
+        }
+        self.order.clear();
+    }
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let mut parser = Parser { pattern, pos: 0 };
+        let node = parser.parse_alt()?;
+        if parser.pos < pattern.len() {
+            // only a stray `)` stops the top level.
+            return Err(RegexError::Unexpected { pos: parser.pos });
+        }
+        let mut compiler = Compiler::default();
+        compiler.compile(&node)?;
+        compiler.push(Inst::Match)?;
+        let back = compiler.here();
+        compiler.compile(&node.reversed())?;
+        compiler.push(Inst::Match)?;
+        Ok(Regex {
+            source: pattern.into(),
+            insts: compiler.insts.into_boxed_slice(),
+            back,
+            classes: compiler.classes.into_boxed_slice(),
+        })
+    }
+    /// the pattern it was compiled from.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+    /// end of the match starting exactly at `text[start..]`, leftmost-first.
+    /// * anchors and `\b` see the whole `text`.
+    #[inline]
+    pub fn match_at(&self, text: &str, start: usize) -> Option<usize> {
+        AnchoredMatch::match_at(self, text, start)
+    }
+    /// start of the match ending exactly at `text[..end]`,
+    /// the leftmost one that [`match_at`](Regex::match_at) would match up to `end` too.
+    /// * anchors and `\b` see the whole `text`, the match can't go past `end`.
+    #[inline]
+    pub fn match_back_at(&self, text: &str, end: usize) -> Option<usize> {
+        AnchoredMatch::match_back_at(self, text, end)
+    }
+    /// same with [`match_at`](Regex::match_at) over decoded units,
+    /// `start` and the end are in units.
+    #[inline]
+    pub fn match_text_at<D: Decoder>(&self, text: &Text<D>, start: usize) -> Option<usize> {
+        AnchoredMatch::match_at(self, text, start)
+    }
+    /// same with [`match_back_at`](Regex::match_back_at) over decoded units.
+    #[inline]
+    pub fn match_text_back_at<D: Decoder>(&self, text: &Text<D>, end: usize) -> Option<usize> {
+        AnchoredMatch::match_back_at(self, text, end)
+    }
+    /// if the whole `text` matches.
+    #[inline]
+    pub fn is_full_match(&self, text: &str) -> bool {
+        self.match_at(text, 0) == Some(text.len())
+    }
+    /// the Pike VM, `start` is where a char starts, no chars from `end` on.
+    fn run<H: Haystack + ?Sized>(&self, text: &H, start: usize, end: usize) -> Option<usize> {
+        let len = self.insts.len();
+        let (mut clist, mut nlist) = (Threads::new(len), Threads::new(len));
+        let mut stack = Vec::new();
+        let mut matched = None;
+        let mut at = start;
+        self.add_thread(&mut clist, &mut stack, 0, text, at);
+        while !clist.order.is_empty() {
+            let (ch, next_at) = match text.char_at(at).filter(|_| at < end) {
+                Some((ch, next_at)) => (Some(ch), next_at),
+                None => (None, at),
+            };
+            for i in 0..clist.order.len() {
+                let pc = clist.order[i];
+                let hit = match (self.insts[pc], ch) {
+                    (Inst::Match, _) => {
+                        // the rest have lower priority.
+                        matched = Some(at);
+                        break;
+                    }
+                    (Inst::Char(c), Some(ch)) => c == ch,
+                    (Inst::Any, Some(ch)) => ch != '\n',
+                    (Inst::Class(k), Some(ch)) => self.classes[k].matches(ch),
+                    _ => false,
+                };
+                if hit {
+                    self.add_thread(&mut nlist, &mut stack, pc + 1, text, next_at);
+                }
+            }
+            if ch.is_none() {
+                break;
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.clear();
+            at = next_at;
+        }
+        matched
+    }
+    /// the reversed program walks left from `end` to find every start of a match ending there,
+    /// then the leftmost one that matches up to `end` from the left too wins.
+    fn run_back<H: Haystack + ?Sized>(&self, text: &H, end: usize) -> Option<usize> {
+        let len = self.insts.len();
+        let (mut clist, mut nlist) = (Threads::new(len), Threads::new(len));
+        let mut stack = Vec::new();
+        let mut starts = Vec::new();
+        let mut at = end;
+        self.add_thread(&mut clist, &mut stack, self.back, text, at);
+        while !clist.order.is_empty() {
+            let (ch, prev_at) = match text.char_before(at) {
+                Some((ch, prev_at)) => (Some(ch), prev_at),
+                None => (None, at),
+            };
+            for i in 0..clist.order.len() {
+                let pc = clist.order[i];
+                let hit = match (self.insts[pc], ch) {
+                    (Inst::Match, _) => {
+                        if starts.last() != Some(&at) {
+                            starts.push(at);
+                        }
+                        false
+                    }
+                    (Inst::Char(c), Some(ch)) => c == ch,
+                    (Inst::Any, Some(ch)) => ch != '\n',
+                    (Inst::Class(k), Some(ch)) => self.classes[k].matches(ch),
+                    _ => false,
+                };
+                if hit {
+                    self.add_thread(&mut nlist, &mut stack, pc + 1, text, prev_at);
+                }
+            }
+            if ch.is_none() {
+                break;
+            }
+            mem::swap(&mut clist, &mut nlist);
+            nlist.clear();
+            at = prev_at;
+        }
+        starts
+            .into_iter()
+            .rev()
+            .find(|&start| self.run(text, start, end) == Some(end))
+    }
+    /// follows the empty moves from `pc`, in priority order.
+    fn add_thread<H: Haystack + ?Sized>(
+        &self,
+        list: &mut Threads,
+        stack: &mut Vec<usize>,
+        pc: usize,
+        text: &H,
+        at: usize,
+    ) {
+        stack.push(pc);
+        while let Some(pc) = stack.pop() {
+            if list.seen[pc] {
+                continue;
+            }
+            list.seen[pc] = true;
+            list.order.push(pc);
+            match self.insts[pc] {
+                Inst::Jmp(to) => stack.push(to),
+                Inst::Split(first, second) => {
+                    stack.push(second);
+                    stack.push(first);
+                }
+                Inst::Look(look) if look.holds(text, at) => stack.push(pc + 1),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// any [`Haystack`](Haystack), a `str` or a decoded [`Text`](Text).
+impl<H: Haystack + ?Sized> AnchoredMatch<H> for Regex {
+    fn match_at(&self, text: &H, start: usize) -> Option<usize> {
+        if !text.is_char_boundary(start) {
+            return None;
+        }
+        self.run(text, start, text.as_units().len())
+    }
+    fn match_back_at(&self, text: &H, end: usize) -> Option<usize> {
+        if !text.is_char_boundary(end) {
+            return None;
+        }
+        self.run_back(text, end)
+    }
+}
+
+/// an anchored search from `start` with the whole `text` around,
+/// so look-behinds like `\b` see the chars before it and nothing past `start` is scanned
+/// when it doesn't match there.
+/// * over the bytes of a `str` or of a UTF-8 [`Text`](Text).
+#[cfg(feature = "regex")]
+impl<H: Haystack<Decoder = Utf8> + ?Sized> AnchoredMatch<H> for ::regex_automata::meta::Regex {
+    #[inline]
+    fn match_at(&self, text: &H, start: usize) -> Option<usize> {
+        if !text.is_char_boundary(start) {
+            return None;
+        }
+        let input = ::regex_automata::Input::new(text.as_units())
+            .range(start..)
+            .anchored(::regex_automata::Anchored::Yes);
+        Some(self.search(&input)?.end())
+    }
+    /// from the leftmost match on, each search skips the starts nothing matches from.
+    #[inline]
+    fn match_back_at(&self, text: &H, end: usize) -> Option<usize> {
+        if !text.is_char_boundary(end) {
+            return None;
+        }
+        let mut at = 0;
+        loop {
+            let input = ::regex_automata::Input::new(text.as_units()).range(at..end);
+            let m = self.search(&input)?;
+            if m.end() == end {
+                return Some(m.start());
+            }
+            (_, at) = text.char_at(m.start())?;
+        }
+    }
+    /// one search, skipping the empty matches.
+    #[inline]
+    fn find_from(&self, text: &H, from: usize) -> Option<(usize, usize)> {
+        let mut at = from;
+        loop {
+            let m = self.search(&::regex_automata::Input::new(text.as_units()).range(at..))?;
+            if !m.is_empty() {
+                return Some((m.start(), m.end()));
+            }
+            (_, at) = text.char_at(m.start())?;
+        }
+    }
+}
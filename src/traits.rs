@@ -3,6 +3,12 @@
 use super::*;
 
 pub trait Extras<Input> {
+    /// if `true`, the cursors report every item they pass over,
+    /// one position at a time, the first one included,
+    /// so a `jump` looks the same as the `next`s it replaces,
+    /// and the moves made with `noeffects` on are reported on the next move it sees.
+    const STEPWISE: bool = false;
+
     fn new() -> Self;
     fn clone(&self) -> Self;
     fn change(&mut self, input: &Input, pos: usize);
@@ -21,16 +27,72 @@ pub trait ToExtras<E: Extras<Self::Input>> {
     }
 }
 
+/// for the `to_range_extras()` of composed extras.
+pub trait ExtrasRange<E> {
+    /// what `f` picks out of both ends, `range.map_extras(|(line_col, _)| line_col)`.
+    fn map_extras<R, F: FnMut(E) -> R>(self, f: F) -> Range<R>;
+}
+impl<E> ExtrasRange<E> for Range<E> {
+    #[inline]
+    fn map_extras<R, F: FnMut(E) -> R>(self, mut f: F) -> Range<R> {
+        f(self.start)..f(self.end)
+    }
+}
+
 pub trait ToCursor<T, E: Extras<T> = NoneExtras<T>>
 where
     Self: AsRef<[T]>,
 {
     #[inline]
-    fn to_cursor(&self) -> Cursor<T, E> {
+    fn to_cursor(&self) -> Cursor<'_, T, E> {
         Cursor::new_with_extras::<E>(self.as_ref())
     }
 }
 
+pub trait ToCursorMut<T, E: Extras<T> = NoneExtras<T>>
+where
+    Self: AsMut<[T]>,
+{
+    #[inline]
+    fn to_cursor_mut(&mut self) -> CursorMut<'_, T, E> {
+        CursorMut::new_with_extras::<E>(self.as_mut())
+    }
+}
+
+/// what [`match_regex`](TextCursorTrait::match_regex) runs over a [`Haystack`](Haystack),
+/// [`regexp::Regex`](crate::regexp::Regex) on any of them,
+/// or `regex_automata::meta::Regex` with the `regex` feature on UTF-8 ones.
+/// * `start`, `end` and the matches are in units, bytes on a `str`.
+pub trait AnchoredMatch<H: Haystack + ?Sized = str> {
+    /// end of the match starting exactly at `text[start..]`.
+    fn match_at(&self, text: &H, start: usize) -> Option<usize>;
+    /// start of the match ending exactly at `text[..end]`, the leftmost one
+    /// whose `match_at` ends there too, what a cursor heading left matches.
+    /// * tries `match_at` over `text[..end]` on each char by default,
+    ///   so anchors and `\b` don't see past `end` there.
+    #[inline]
+    fn match_back_at(&self, text: &H, end: usize) -> Option<usize> {
+        if !text.is_char_boundary(end) {
+            return None;
+        }
+        let text = text.slice(0..end);
+        (0..=end)
+            .filter(|&at| text.is_char_boundary(at))
+            .find(|&at| self.match_at(text, at) == Some(end))
+    }
+    /// (start, end) of the first non-empty match from `text[from..]` on.
+    /// * tries `match_at` on each char by default, a searching engine can do it in one pass.
+    #[inline]
+    fn find_from(&self, text: &H, from: usize) -> Option<(usize, usize)> {
+        (from..text.as_units().len())
+            .filter(|&at| text.is_char_boundary(at))
+            .find_map(|at| {
+                let end = self.match_at(text, at)?;
+                (end > at).then_some((at, end))
+            })
+    }
+}
+
 pub trait CursorTrait<'s, T: 's, E = NoneExtras<T>>
 where
     Self: Iterator<Item = &'s T>, // with .next()
@@ -108,6 +170,103 @@ where
     fn saved(&self) -> &CursorInfo<T, E>;
     fn load(&mut self);
 
+    /// snapshots the current state, apart from the `saved()` one.
+    #[inline]
+    fn push_checkpoint(&mut self) -> Checkpoint<CursorInfo<T, E>> {
+        let mut info = CursorInfo::new();
+        info.init = self.is_init();
+        info.backwards = self.backwards();
+        info.pos = self.pos();
+        info.extras = self.extras().clone();
+        info.noeffects = self.noeffects();
+        Checkpoint::new(info)
+    }
+    /// rewinds to the checkpoint.
+    /// * by default, `reset()` or a `jump` with `noeffects` on, then the rest is put back.
+    #[inline]
+    fn restore(&mut self, checkpoint: Checkpoint<CursorInfo<T, E>>) {
+        let info = checkpoint.into_info();
+        if info.init {
+            self.noeffects_on();
+            self.jump(info.pos);
+        } else {
+            self.reset();
+        }
+        *self.extras_mut() = info.extras;
+        *self.backwards_mut() = info.backwards;
+        *self.noeffects_mut() = info.noeffects;
+    }
+    /// drops the checkpoint, keeping the moves since it.
+    /// * same with letting it go out of scope, it just says so.
+    #[inline]
+    fn commit(&mut self, checkpoint: Checkpoint<CursorInfo<T, E>>) {
+        let _ = checkpoint;
+    }
+    /// runs `f` and rolls everything back on `None`.
+    /// * position, direction, `noeffects` and extras, not `saved()`.
+    #[inline]
+    fn attempt<R, F>(&mut self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Option<R>,
+    {
+        let checkpoint = self.push_checkpoint();
+        let out = f(self);
+        if out.is_some() {
+            self.commit(checkpoint);
+        } else {
+            self.restore(checkpoint);
+        }
+        out
+    }
+    /// runs `f` and rolls everything back on `Err`.
+    /// * position, direction, `noeffects` and extras, not `saved()`.
+    #[inline]
+    fn try_attempt<R, Err, F>(&mut self, f: F) -> Result<R, Err>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Result<R, Err>,
+    {
+        let checkpoint = self.push_checkpoint();
+        let out = f(self);
+        if out.is_ok() {
+            self.commit(checkpoint);
+        } else {
+            self.restore(checkpoint);
+        }
+        out
+    }
+    /// checkpoint pos to current pos.
+    #[inline]
+    fn slice_since(&self, checkpoint: &Checkpoint<CursorInfo<T, E>>) -> &'s [T] {
+        if self.is_empty() {
+            return &[];
+        }
+        let (pos, cp_pos) = (self.pos(), checkpoint.info().pos);
+        &self.as_slice()[pos.min(cp_pos)..pos.max(cp_pos).saturating_add(1)]
+    }
+
+    /// the current item, empty if there is none.
+    #[inline]
+    fn span_of_current(&self) -> Span {
+        item_span(self.pos(), !self.is_empty())
+    }
+    /// saved pos to current pos.
+    #[inline]
+    fn span_since_save(&self) -> Span {
+        item_span(self.saved().pos, !self.is_empty()).join(self.span_of_current())
+    }
+    /// checkpoint pos to current pos.
+    #[inline]
+    fn span_since(&self, checkpoint: &Checkpoint<CursorInfo<T, E>>) -> Span {
+        item_span(checkpoint.info().pos, !self.is_empty()).join(self.span_of_current())
+    }
+    /// the items in `span`, no scanning.
+    #[inline]
+    fn slice_span(&self, span: &Span) -> &'s [T] {
+        span.slice(self.as_slice())
+    }
+
     fn as_slice(&self) -> &'s [T];
 
     /// saved pos to current pos.
@@ -191,6 +350,67 @@ where
         &self.as_slice()[self.pos()..]
     }
 
+    // ------------ PEEK ------------
+
+    /// item at `offset` in the heading direction, `0` is current.
+    /// * no moves, no effects.
+    #[inline]
+    fn at_offset(&self, offset: isize) -> Option<&'s T> {
+        let offset = if self.backwards() {
+            offset.checked_neg()?
+        } else {
+            offset
+        };
+        self.as_slice().get(self.pos().checked_move_offset(offset)?)
+    }
+    /// what `next()` would return.
+    #[inline]
+    fn peek(&self) -> Option<&'s T> {
+        self.peek_nth(0)
+    }
+    /// what the `n + 1`th `next()` would return.
+    #[inline]
+    fn peek_nth(&self, n: usize) -> Option<&'s T> {
+        let steps = if self.is_init() { n.checked_add(1)? } else { n };
+        self.at_offset(isize::try_from(steps).ok()?)
+    }
+    /// what the `n + 1`th `prev()` would return.
+    #[inline]
+    fn peek_back_nth(&self, n: usize) -> Option<&'s T> {
+        let steps = if self.is_init() { n.checked_add(1)? } else { n };
+        self.at_offset(isize::try_from(steps).ok()?.checked_neg()?)
+    }
+    /// what the next `N` `next()`s would return.
+    #[inline]
+    fn peek_array<const N: usize>(&self) -> Option<[&'s T; N]> {
+        if N > 0 {
+            self.peek_nth(N - 1)?;
+        }
+        Some(array::from_fn(|i| self.peek_nth(i).unwrap()))
+    }
+    /// if the following `next()`s would return the `pattern`.
+    #[inline]
+    fn starts_with(&self, pattern: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        pattern
+            .iter()
+            .enumerate()
+            .all(|(i, item)| self.peek_nth(i) == Some(item))
+    }
+    /// if the following `next()`s would return the bytes of `pattern`.
+    #[inline]
+    fn starts_with_str(&self, pattern: &str) -> bool
+    where
+        T: PartialEq<u8>,
+    {
+        pattern
+            .bytes()
+            .enumerate()
+            .all(|(i, byte)| matches!(self.peek_nth(i), Some(item) if *item == byte))
+    }
+
     // ------------ JUMP ------------
 
     fn jump(&mut self, pos: usize) -> Option<&'s T>;
@@ -253,34 +473,15 @@ where
     fn jump_to_load(&mut self) -> &'s T {
         self.jump(self.saved().pos).unwrap()
     }
-    /// WARNING: isize
+    /// WARNING: isize, wraps around `len`.
     #[inline]
     fn jump_cycle(&mut self, pos: isize) -> &'s T {
-        let len = self.len() as isize;
-        if pos < 0 {
-            self.jump((len.saturating_sub(pos)) as usize).unwrap()
-        } else if len <= pos {
-            self.jump((pos.saturating_sub(len).saturating_sub(1)) as usize)
-                .unwrap()
-        } else {
-            self.jump(pos as usize).unwrap()
-        }
+        self.try_jump_cycle(pos).unwrap()
     }
     /// WARNING: isize
     #[inline]
     fn jump_to_offset_cycle(&mut self, offset: isize) -> &'s T {
-        let dst = (self.pos() as isize) + offset;
-        let len = self.len() as isize;
-        if dst == 0 {
-            self.current()
-        } else if dst < 0 {
-            self.jump((len.saturating_sub(dst)) as usize).unwrap()
-        } else if len <= dst {
-            self.jump((dst.saturating_sub(len).saturating_sub(1)) as usize)
-                .unwrap()
-        } else {
-            self.jump_to_offset(offset).unwrap()
-        }
+        self.try_jump_to_offset_cycle(offset).unwrap()
     }
 
     // ------------ NEXT ------------
@@ -404,15 +605,295 @@ where
         }
         self.next_cycle()
     }
+
+    // ------------ PATTERN ------------
+
+    /// bumps while the following items match `pattern`.
+    /// * (`skip_while` is taken by `Iterator`.)
+    /// * returns the number of skipped items.
+    #[inline]
+    fn skip_matching<P: Pattern<[T]>>(&mut self, mut pattern: P) -> usize {
+        let mut skipped = 0;
+        loop {
+            let right = !self.backwards();
+            let haystack = upcoming_slice(self, right);
+            let matched = if right {
+                pattern.match_prefix(haystack)
+            } else {
+                pattern.match_suffix(haystack)
+            };
+            match matched {
+                Some(n) if n > 0 => {
+                    advance_by(self, n);
+                    skipped += n;
+                }
+                _ => return skipped,
+            }
+        }
+    }
+    /// bumps until the following items match `pattern`, stopping before the match.
+    /// * no moves if there is no match.
+    #[inline]
+    fn skip_until<P: Pattern<[T]>>(&mut self, mut pattern: P) -> bool {
+        let right = !self.backwards();
+        match find_pattern_in_slice(upcoming_slice(self, right), right, &mut pattern) {
+            Some((before, _)) => {
+                advance_by(self, before);
+                true
+            }
+            None => false,
+        }
+    }
+    /// bumps through the first match of `pattern`.
+    /// * no moves if there is no match.
+    #[inline]
+    fn advance_past<P: Pattern<[T]>>(&mut self, mut pattern: P) -> bool {
+        let right = !self.backwards();
+        match find_pattern_in_slice(upcoming_slice(self, right), right, &mut pattern) {
+            Some((before, matched)) => {
+                advance_by(self, before + matched);
+                true
+            }
+            None => false,
+        }
+    }
+    /// bumps through `pattern` if the following items match it.
+    #[inline]
+    fn eat<P: Pattern<[T]>>(&mut self, pattern: P) -> bool {
+        self.expect(pattern).is_ok()
+    }
+    /// same with `eat`, but tells why it couldn't.
+    #[inline]
+    fn expect<P: Pattern<[T]>>(&mut self, mut pattern: P) -> Result<(), CursorError> {
+        let right = !self.backwards();
+        let haystack = upcoming_slice(self, right);
+        let matched = if right {
+            pattern.match_prefix(haystack)
+        } else {
+            pattern.match_suffix(haystack)
+        };
+        match matched {
+            Some(n) => {
+                advance_by(self, n);
+                Ok(())
+            }
+            None if self.is_empty() => Err(CursorError::EmptyInput),
+            None if haystack.is_empty() => Err(CursorError::EndOfInput),
+            None => Err(CursorError::Unexpected {
+                pos: upcoming_pos(self.pos(), self.is_init(), right),
+            }),
+        }
+    }
+
+    // ------------ SEARCH ------------
+
+    /// pos of the nearest `needle` ahead, in the heading direction.
+    /// * no moves, no effects.
+    #[inline]
+    fn find_next(&self, needle: &[T]) -> Option<usize>
+    where
+        T: Ord,
+    {
+        find_in_slice(self, needle, !self.backwards())
+    }
+    /// pos of the nearest `needle` behind, against the heading direction.
+    /// * no moves, no effects.
+    #[inline]
+    fn find_prev(&self, needle: &[T]) -> Option<usize>
+    where
+        T: Ord,
+    {
+        find_in_slice(self, needle, self.backwards())
+    }
+    /// bumps to the first item of the nearest `needle` ahead.
+    /// * the [`Extras`](Extras) see every item on the way,
+    /// * or it just jumps if `noeffects`.
+    #[inline]
+    fn jump_to_next(&mut self, needle: &[T]) -> Option<&'s T>
+    where
+        T: Ord,
+    {
+        let pos = self.find_next(needle)?;
+        if self.noeffects() {
+            return self.jump(pos);
+        }
+        let steps = self.pos().abs_diff(pos) + usize::from(!self.is_init());
+        for _ in 1..steps {
+            self.next()?;
+        }
+        self.next()
+    }
+    /// same with `jump_to_next`, against the heading direction.
+    #[inline]
+    fn jump_to_prev(&mut self, needle: &[T]) -> Option<&'s T>
+    where
+        T: Ord,
+    {
+        self.turnaround();
+        let out = self.jump_to_next(needle);
+        self.turnaround();
+        out
+    }
+
+    // ------------ TRY ------------
+
+    /// same with `jump`, but tells why it couldn't move.
+    #[inline]
+    fn try_jump(&mut self, pos: usize) -> Result<&'s T, CursorError> {
+        check_bounds(pos, self.len())?;
+        self.jump(pos).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_jump_to_offset(&mut self, offset: isize) -> Result<&'s T, CursorError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(CursorError::Overflow)?;
+        check_bounds(pos, self.len())?;
+        self.jump_to_offset(offset).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_jump_to_first(&mut self) -> Result<&'s T, CursorError> {
+        self.try_jump(0)
+    }
+    #[inline]
+    fn try_jump_to_last(&mut self) -> Result<&'s T, CursorError> {
+        let last_pos = self.len().saturating_sub(1);
+        self.try_jump(last_pos)
+    }
+    #[inline]
+    fn try_first_to_last(&mut self) -> Result<(), CursorError> {
+        self.try_jump_to_first()?;
+        self.try_next_to_last()?;
+        Ok(())
+    }
+    #[inline]
+    fn try_jump_to_load(&mut self) -> Result<&'s T, CursorError> {
+        self.try_jump(self.saved().pos)
+    }
+    /// `pos` wraps around `len`, so `-1` is the last one.
+    #[inline]
+    fn try_jump_cycle(&mut self, pos: isize) -> Result<&'s T, CursorError> {
+        let pos = cycle_pos(pos, self.len())?;
+        self.try_jump(pos)
+    }
+    #[inline]
+    fn try_jump_to_offset_cycle(&mut self, offset: isize) -> Result<&'s T, CursorError> {
+        let dst = isize::try_from(self.pos())
+            .ok()
+            .and_then(|pos| pos.checked_add(offset))
+            .ok_or(CursorError::Overflow)?;
+        let pos = cycle_pos(dst, self.len())?;
+        if pos as isize == dst {
+            self.try_jump_to_offset(offset)
+        } else {
+            self.try_jump(pos)
+        }
+    }
+    /// same with `next`, but tells why it couldn't move.
+    #[inline]
+    fn try_next(&mut self) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next().ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_prev(&mut self) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.prev().ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_pos(&mut self, pos: usize) -> Result<&'s T, CursorError> {
+        check_bounds(pos, self.len())?;
+        self.next_to_pos(pos).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_offset(&mut self, offset: isize) -> Result<&'s T, CursorError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(CursorError::Overflow)?;
+        check_bounds(pos, self.len())?;
+        self.next_to_offset(offset).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_first(&mut self) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_first())
+    }
+    #[inline]
+    fn try_next_to_last(&mut self) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_last())
+    }
+    #[inline]
+    fn try_next_to_left(&mut self) -> Result<&'s T, CursorError> {
+        self.head_to_left();
+        self.try_next()
+    }
+    #[inline]
+    fn try_next_to_right(&mut self) -> Result<&'s T, CursorError> {
+        self.head_to_right();
+        self.try_next()
+    }
+    #[inline]
+    fn try_next_to_until(&mut self, f: fn(&T) -> bool) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next_to_until(f).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_while(&mut self, f: fn(&T) -> bool) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next_to_while(f).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_load(&mut self) -> Result<&'s T, CursorError> {
+        self.try_next_to_pos(self.saved().pos)
+    }
+    #[inline]
+    fn try_next_cycle(&mut self) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_cycle())
+    }
+    #[inline]
+    fn try_next_to_offset_cycle(&mut self, offset: isize) -> Result<&'s T, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_offset_cycle(offset))
+    }
 }
 
 // ----------------
 
-pub trait StrCursorTrait<'s, E = NoneExtras<char>>
+/// the char cursors, a [`StrCursor`](StrCursor) over a `str`
+/// or a [`TextCursor`](TextCursor) over any [`Decoder`](Decoder)'s units.
+/// * `pos()` counts chars, the `_units` views and the `Span` bytes are in units of the `Text`,
+/// * [`StrCursorTrait`](StrCursorTrait) adds the byte and `&str` views of a `str`.
+pub trait TextCursorTrait<'s, E = NoneExtras<char>>
 where
     Self: Iterator<Item = char>,
     E: Extras<char>,
 {
+    type Decoder: Decoder;
+    /// what it reads, a `str` or a [`Text`](Text).
+    type Text: Haystack<Decoder = Self::Decoder> + ?Sized + 's;
+    /// what `saved()` and the checkpoints keep.
+    type Info: CharInfo<E>;
+
     #[inline]
     fn item_size(&self) -> usize {
         mem::size_of::<char>()
@@ -427,13 +908,13 @@ where
     fn len(&mut self) -> usize;
     fn is_len(&self) -> bool;
     #[inline]
-    fn len_as_bytes(&self) -> usize {
-        self.as_bytes().len()
+    fn len_as_units(&self) -> usize {
+        self.as_units().len()
     }
 
     #[inline]
     fn is_empty(&self) -> bool {
-        self.as_bytes().is_empty()
+        self.as_units().is_empty()
     }
 
     fn is_init(&self) -> bool;
@@ -475,8 +956,14 @@ where
     }
 
     fn pos(&self) -> usize;
-    fn pos_as_bytes(&self) -> usize;
-    fn char_start_pos(&self) -> usize;
+    /// the first unit of the current char.
+    #[inline]
+    fn pos_as_units(&self) -> usize {
+        self.span_of_current().bytes().start
+    }
+
+    /// `'\0'` before the first one.
+    fn current(&self) -> char;
 
     fn extras(&self) -> &E;
     fn extras_mut(&mut self) -> &mut E;
@@ -484,200 +971,234 @@ where
     /// cloning `saved().extras` to `self.extras()`.
     #[inline]
     fn to_range_extras(&self) -> Range<E> {
-        self.saved().extras.clone()..self.extras().clone()
+        self.saved().extras().clone()..self.extras().clone()
     }
 
     fn reset(&mut self);
     fn save(&mut self);
-    fn saved(&self) -> &StrCursorInfo<E>;
+    fn saved(&self) -> &Self::Info;
     fn load(&mut self);
 
-    // ------ BYTES ------
-
-    fn as_bytes(&self) -> &'s [u8];
-
-    /// saved pos to current pos.
+    /// snapshots the current state, apart from the `saved()` one.
+    fn push_checkpoint(&mut self) -> Checkpoint<Self::Info>;
+    /// rewinds to the checkpoint, with the direction.
+    fn restore(&mut self, checkpoint: Checkpoint<Self::Info>);
+    /// drops the checkpoint, keeping the moves since it.
+    /// * same with letting it go out of scope, it just says so.
     #[inline]
-    fn as_bytes_loaded(&self) -> &'s [u8] {
-        let slice = self.as_bytes();
-        let pos = self.pos_as_bytes();
-        let saved_pos = self.saved().inner.pos;
-        match pos.cmp(&saved_pos) {
-            Ordering::Greater => &slice[saved_pos..pos.saturating_add(1)],
-            Ordering::Equal => &slice[pos..1],
-            Ordering::Less => &slice[pos..saved_pos.saturating_add(1)],
+    fn commit(&mut self, checkpoint: Checkpoint<Self::Info>) {
+        let _ = checkpoint;
+    }
+    /// runs `f` and rolls everything back on `None`.
+    /// * position, direction, `noeffects` and extras, not `saved()`.
+    #[inline]
+    fn attempt<R, F>(&mut self, f: F) -> Option<R>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Option<R>,
+    {
+        let checkpoint = self.push_checkpoint();
+        let out = f(self);
+        if out.is_some() {
+            self.commit(checkpoint);
+        } else {
+            self.restore(checkpoint);
         }
+        out
     }
-
-    /// saved pos to current char start pos.
+    /// runs `f` and rolls everything back on `Err`.
+    /// * position, direction, `noeffects` and extras, not `saved()`.
     #[inline]
-    fn as_char_bytes_loaded(&self) -> &'s [u8] {
-        let slice = self.as_bytes();
-        let pos = self.char_start_pos();
-        let saved_pos = self.saved().inner.pos;
-        match pos.cmp(&saved_pos) {
-            Ordering::Greater => &slice[saved_pos..pos.saturating_add(1)],
-            Ordering::Equal => &slice[pos..1],
-            Ordering::Less => &slice[pos..saved_pos.saturating_add(1)],
+    fn try_attempt<R, Err, F>(&mut self, f: F) -> Result<R, Err>
+    where
+        Self: Sized,
+        F: FnOnce(&mut Self) -> Result<R, Err>,
+    {
+        let checkpoint = self.push_checkpoint();
+        let out = f(self);
+        if out.is_ok() {
+            self.commit(checkpoint);
+        } else {
+            self.restore(checkpoint);
         }
+        out
     }
 
+    // ------------ UNITS ------------
+
+    fn as_text(&self) -> &'s Self::Text;
+
     #[inline]
-    fn as_preserved_bytes(&self) -> &'s [u8] {
+    fn as_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        self.as_text().as_units()
+    }
+    /// the units of the current char, empty before the first one.
+    #[inline]
+    fn current_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[self.span_of_current().bytes()]
+    }
+    /// before the current char.
+    #[inline]
+    fn as_left_side_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[..self.span_of_current().bytes().start]
+    }
+    /// after the current char.
+    #[inline]
+    fn as_right_side_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[self.span_of_current().bytes().end..]
+    }
+    #[inline]
+    fn as_left_side_units_include_current(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[..self.span_of_current().bytes().end]
+    }
+    #[inline]
+    fn as_right_side_units_include_current(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[self.span_of_current().bytes().start..]
+    }
+    #[inline]
+    fn as_preserved_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
         if self.backwards() {
-            self.as_right_side_bytes()
+            self.as_right_side_units()
         } else {
-            self.as_left_side_bytes()
+            self.as_left_side_units()
         }
     }
-
     #[inline]
-    fn as_preserved_bytes_include_current(&self) -> &'s [u8] {
+    fn as_preserved_units_include_current(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
         if self.backwards() {
-            self.as_right_side_bytes_include_current()
+            self.as_right_side_units_include_current()
         } else {
-            self.as_left_side_bytes_include_current()
+            self.as_left_side_units_include_current()
         }
     }
-
     #[inline]
-    fn current_byte(&self) -> u8 {
-        self.as_bytes()[self.pos_as_bytes()]
-    }
-
-    #[inline]
-    fn as_remaining_bytes(&self) -> &'s [u8] {
+    fn as_remaining_units(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
         if self.backwards() {
-            self.as_left_side_bytes()
+            self.as_left_side_units()
         } else {
-            self.as_right_side_bytes()
+            self.as_right_side_units()
         }
     }
-
     #[inline]
-    fn as_remaining_bytes_include_current(&self) -> &'s [u8] {
+    fn as_remaining_units_include_current(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
         if self.backwards() {
-            self.as_left_side_bytes_include_current()
+            self.as_left_side_units_include_current()
         } else {
-            self.as_right_side_bytes_include_current()
+            self.as_right_side_units_include_current()
         }
     }
-
+    /// saved char to the current char, both included.
     #[inline]
-    fn as_left_side_bytes(&self) -> &'s [u8] {
-        &self.as_bytes()[..self.pos_as_bytes()]
+    fn as_units_loaded(&self) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[self.span_since_save().bytes()]
     }
-
+    /// checkpoint char to the current char, both included.
     #[inline]
-    fn as_right_side_bytes(&self) -> &'s [u8] {
-        &self.as_bytes()[self.pos_as_bytes().saturating_add(1)..]
+    fn units_since(
+        &self,
+        checkpoint: &Checkpoint<Self::Info>,
+    ) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[self.span_since(checkpoint).bytes()]
     }
-
+    /// the units in `span`, no scanning.
     #[inline]
-    fn as_left_side_bytes_include_current(&self) -> &'s [u8] {
-        &self.as_bytes()[..self.pos_as_bytes().saturating_add(1)]
+    fn units_span(&self, span: &Span) -> &'s [<Self::Decoder as Decoder>::Unit] {
+        &self.as_units()[span.bytes()]
     }
 
+    // ------------ SPAN ------------
+
+    /// the current char, empty before the first one.
+    fn span_of_current(&self) -> Span;
+    /// saved pos to current pos.
     #[inline]
-    fn as_right_side_bytes_include_current(&self) -> &'s [u8] {
-        &self.as_bytes()[self.pos_as_bytes()..]
+    fn span_since_save(&self) -> Span {
+        self.saved().span().join(self.span_of_current())
     }
-
-    // ------ STR ------
-
+    /// checkpoint pos to current pos.
     #[inline]
-    fn as_str(&self) -> &'s str {
-        utf::from_utf8_unchecked(self.as_bytes())
+    fn span_since(&self, checkpoint: &Checkpoint<Self::Info>) -> Span {
+        checkpoint.info().span().join(self.span_of_current())
     }
 
-    /// saved pos to current pos.
+    // ------------ PEEK ------------
+
+    /// char at `offset` in the heading direction, `0` is current.
+    /// * no moves, no effects.
     #[inline]
-    fn as_str_loaded(&self) -> &'s str {
-        let saved_backwards = self.saved().inner.backwards;
-        let curr_backwards = self.backwards();
-        let saved_pos = self.saved().inner.pos;
-        let curr_pos = self.pos_as_bytes();
-        let saved_char_start_pos = self.saved().char_start_pos;
-        let curr_char_start_pos = self.char_start_pos();
-        match curr_pos.cmp(&saved_pos) {
-            Ordering::Greater => {
-                let saved_pos = if saved_backwards {
-                    saved_pos
+    fn at_offset(&self, offset: isize) -> Option<char> {
+        let steps = offset.unsigned_abs();
+        match (steps, self.is_init()) {
+            (0, true) => Some(self.current()),
+            (0, false) => self.peek_nth(0),
+            (_, init) => {
+                let n = if init { steps - 1 } else { steps };
+                if offset > 0 {
+                    self.peek_nth(n)
                 } else {
-                    saved_char_start_pos
-                };
-                let curr_pos = if curr_backwards {
-                    curr_char_start_pos
-                } else {
-                    curr_pos
-                };
-                utf::from_utf8_unchecked(&self.as_bytes()[saved_pos..curr_pos + 1])
-            }
-            Ordering::Equal => utf::from_utf8_unchecked(if curr_backwards {
-                &self.as_bytes()[curr_pos..curr_char_start_pos + 1]
-            } else {
-                &self.as_bytes()[curr_char_start_pos..curr_pos + 1]
-            }),
-            Ordering::Less => {
-                let saved_pos = if saved_backwards {
-                    saved_char_start_pos
-                } else {
-                    saved_pos
-                };
-                let curr_pos = if curr_backwards {
-                    curr_pos
-                } else {
-                    curr_char_start_pos
-                };
-                utf::from_utf8_unchecked(&self.as_bytes()[curr_pos..saved_pos + 1])
+                    self.peek_back_nth(n)
+                }
             }
         }
     }
-
+    /// what `next()` would return.
     #[inline]
-    fn as_preserved_str(&self) -> &'s str {
-        if self.backwards() {
-            self.as_right_side_str()
+    fn peek(&self) -> Option<char> {
+        self.peek_nth(0)
+    }
+    /// what the `n + 1`th `next()` would return.
+    #[inline]
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        let right = !self.backwards();
+        let mut chars = upcoming_text(self, right).chars();
+        if right {
+            chars.nth(n)
         } else {
-            self.as_left_side_str()
+            chars.nth_back(n)
         }
     }
-
-    fn current(&self) -> char;
-
+    /// what the `n + 1`th `prev()` would return.
     #[inline]
-    fn as_remaining_str(&self) -> &'s str {
-        if self.backwards() {
-            self.as_left_side_str()
+    fn peek_back_nth(&self, n: usize) -> Option<char> {
+        let right = self.backwards();
+        let mut chars = upcoming_text(self, right).chars();
+        if right {
+            chars.nth(n)
         } else {
-            self.as_right_side_str()
+            chars.nth_back(n)
         }
     }
-
+    /// what the next `N` `next()`s would return.
     #[inline]
-    fn as_left_side_str(&self) -> &'s str {
-        let curr_pos = self.pos_as_bytes();
-        if let Some(n) = (&self.as_bytes()[..curr_pos.saturating_add(1)])
-            .iter()
-            .rev()
-            .position(|&byte| !utf::utf8_is_cont_byte(byte))
-        {
-            utf::from_utf8_unchecked(&self.as_bytes()[..curr_pos.saturating_sub(n)])
+    fn peek_array<const N: usize>(&self) -> Option<[char; N]> {
+        let right = !self.backwards();
+        let chars = upcoming_text(self, right).chars();
+        if right {
+            collect_chars(chars)
         } else {
-            ""
+            collect_chars(chars.rev())
         }
     }
-
+    /// if the following `next()`s would return the `pattern`.
     #[inline]
-    fn as_right_side_str(&self) -> &'s str {
-        let curr_pos = self.pos_as_bytes();
-        if let Some(n) = (&self.as_bytes()[self.pos_as_bytes().saturating_add(1)..])
-            .iter()
-            .position(|&byte| !utf::utf8_is_cont_byte(byte))
-        {
-            utf::from_utf8_unchecked(&self.as_bytes()[curr_pos.saturating_add(n + 1)..])
+    fn starts_with(&self, pattern: &[char]) -> bool {
+        let right = !self.backwards();
+        let chars = upcoming_text(self, right).chars();
+        if right {
+            chars_start_with(chars, pattern.iter().copied())
         } else {
-            ""
+            chars_start_with(chars.rev(), pattern.iter().copied())
+        }
+    }
+    /// if the following `next()`s would return the `pattern`.
+    #[inline]
+    fn starts_with_str(&self, pattern: &str) -> bool {
+        let right = !self.backwards();
+        let chars = upcoming_text(self, right).chars();
+        if right {
+            chars_start_with(chars, pattern.chars())
+        } else {
+            chars_start_with(chars.rev(), pattern.chars())
         }
     }
 
@@ -718,11 +1239,17 @@ where
             _ => unreachable!(),
         }
     }
+    /// [`EOF_CHAR`](EOF_CHAR) on an empty text.
     #[inline]
     fn jump_to_first(&mut self) -> char {
-        self.jump(0).unwrap()
+        self.jump(0).unwrap_or(EOF_CHAR)
+    }
+    /// [`EOF_CHAR`](EOF_CHAR) on an empty text.
+    #[inline]
+    fn jump_to_last(&mut self) -> char {
+        let last_pos = self.len().saturating_sub(1);
+        self.jump(last_pos).unwrap_or(EOF_CHAR)
     }
-    fn jump_to_last(&mut self) -> char;
     /// same with
     /// ```ignore
     /// {
@@ -738,7 +1265,7 @@ where
     /// jump to the saved pos.
     #[inline]
     fn jump_to_load(&mut self) -> char {
-        self.jump(self.saved().pos).unwrap()
+        self.jump(self.saved().pos()).unwrap()
     }
     /// - **WARNING: isize**
     /// - if you've never tried it before,
@@ -746,15 +1273,7 @@ where
     /// - consume it to count number of chars.
     #[inline]
     fn jump_cycle(&mut self, pos: isize) -> char {
-        let len = self.len() as isize;
-        if pos < 0 {
-            self.jump((len.saturating_sub(pos)) as usize).unwrap()
-        } else if len <= pos {
-            self.jump((pos.saturating_sub(len).saturating_sub(1)) as usize)
-                .unwrap()
-        } else {
-            self.jump(pos as usize).unwrap()
-        }
+        self.try_jump_cycle(pos).unwrap()
     }
     /// - **WARNING: isize**
     /// - if you've never tried it before,
@@ -762,18 +1281,7 @@ where
     /// - consume it to count number of chars.
     #[inline]
     fn jump_to_offset_cycle(&mut self, offset: isize) -> char {
-        let dst = (self.pos() as isize) + offset;
-        let len = self.len() as isize;
-        if dst == 0 {
-            self.current()
-        } else if dst < 0 {
-            self.jump((len.saturating_sub(dst)) as usize).unwrap()
-        } else if len <= dst {
-            self.jump((dst.saturating_sub(len).saturating_sub(1)) as usize)
-                .unwrap()
-        } else {
-            self.jump_to_offset(offset).unwrap()
-        }
+        self.try_jump_to_offset_cycle(offset).unwrap()
     }
 
     // ------------ NEXT ------------
@@ -853,7 +1361,7 @@ where
     /// bump until meets saved pos.
     #[inline]
     fn next_to_load(&mut self) -> char {
-        self.next_to_pos(self.saved().pos).unwrap()
+        self.next_to_pos(self.saved().pos()).unwrap()
     }
     /// bump until meets saved pos.
     #[inline]
@@ -897,4 +1405,677 @@ where
         }
         self.next_cycle()
     }
+
+    // ------------ PATTERN ------------
+
+    /// bumps while the following items match `pattern`.
+    /// * (`skip_while` is taken by `Iterator`.)
+    /// * returns the number of skipped items.
+    #[inline]
+    fn skip_matching<P: Pattern<Self::Text>>(&mut self, mut pattern: P) -> usize {
+        let mut skipped = 0;
+        loop {
+            let right = !self.backwards();
+            let haystack = upcoming_text(self, right);
+            let matched = if right {
+                pattern.match_prefix(haystack)
+            } else {
+                pattern.match_suffix(haystack)
+            };
+            match matched {
+                Some(n) if n > 0 => {
+                    advance_by(self, n);
+                    skipped += n;
+                }
+                _ => return skipped,
+            }
+        }
+    }
+    /// bumps until the following items match `pattern`, stopping before the match.
+    /// * no moves if there is no match.
+    #[inline]
+    fn skip_until<P: Pattern<Self::Text>>(&mut self, mut pattern: P) -> bool {
+        let right = !self.backwards();
+        match find_pattern_in_text(upcoming_text(self, right), right, &mut pattern) {
+            Some((before, _)) => {
+                advance_by(self, before);
+                true
+            }
+            None => false,
+        }
+    }
+    /// bumps through the first match of `pattern`.
+    /// * no moves if there is no match.
+    #[inline]
+    fn advance_past<P: Pattern<Self::Text>>(&mut self, mut pattern: P) -> bool {
+        let right = !self.backwards();
+        match find_pattern_in_text(upcoming_text(self, right), right, &mut pattern) {
+            Some((before, matched)) => {
+                advance_by(self, before + matched);
+                true
+            }
+            None => false,
+        }
+    }
+    /// bumps through `pattern` if the following items match it.
+    #[inline]
+    fn eat<P: Pattern<Self::Text>>(&mut self, pattern: P) -> bool {
+        self.expect(pattern).is_ok()
+    }
+    /// same with `eat`, but tells why it couldn't.
+    #[inline]
+    fn expect<P: Pattern<Self::Text>>(&mut self, mut pattern: P) -> Result<(), CursorError> {
+        let right = !self.backwards();
+        let haystack = upcoming_text(self, right);
+        let matched = if right {
+            pattern.match_prefix(haystack)
+        } else {
+            pattern.match_suffix(haystack)
+        };
+        match matched {
+            Some(n) => {
+                advance_by(self, n);
+                Ok(())
+            }
+            None if self.is_empty() => Err(CursorError::EmptyInput),
+            None if haystack.is_empty() => Err(CursorError::EndOfInput),
+            None => Err(CursorError::Unexpected {
+                pos: upcoming_pos(self.pos(), self.is_init(), right),
+            }),
+        }
+    }
+
+    // ------------ SEARCH ------------
+
+    /// pos of the nearest `needle` ahead, in the heading direction.
+    /// * no moves, no effects.
+    #[inline]
+    fn find_next(&self, needle: &str) -> Option<usize> {
+        find_in_text(self, needle, !self.backwards())
+    }
+    /// pos of the nearest `needle` behind, against the heading direction.
+    /// * no moves, no effects.
+    #[inline]
+    fn find_prev(&self, needle: &str) -> Option<usize> {
+        find_in_text(self, needle, self.backwards())
+    }
+    /// bumps to the first item of the nearest `needle` ahead.
+    /// * the [`Extras`](Extras) see every item on the way,
+    /// * or it just jumps if `noeffects`.
+    #[inline]
+    fn jump_to_next(&mut self, needle: &str) -> Option<char> {
+        let pos = self.find_next(needle)?;
+        if self.noeffects() {
+            return self.jump(pos);
+        }
+        let steps = self.pos().abs_diff(pos) + usize::from(!self.is_init());
+        for _ in 1..steps {
+            self.next()?;
+        }
+        self.next()
+    }
+    /// same with `jump_to_next`, against the heading direction.
+    #[inline]
+    fn jump_to_prev(&mut self, needle: &str) -> Option<char> {
+        self.turnaround();
+        let out = self.jump_to_next(needle);
+        self.turnaround();
+        out
+    }
+
+    // ------------ REGEX ------------
+
+    /// matches `regex` anchored at the current char (the first one if not init),
+    /// and bumps to the last char of the match.
+    /// * heading left like `eat`, the match ends with the current char
+    ///   and it bumps to the first char of the match.
+    /// * returns the matched text, no moves if it doesn't match.
+    /// * anchors and `\b` see the chars around it.
+    /// * the [`Extras`](Extras) see every char on the way,
+    /// * or it just jumps if `noeffects`.
+    #[inline]
+    fn match_regex<R: AnchoredMatch<Self::Text> + ?Sized>(
+        &mut self,
+        regex: &R,
+    ) -> Option<&'s Self::Text> {
+        let text = self.as_text();
+        let current = current_units_range(self);
+        let matched = if self.backwards() {
+            text.slice(regex.match_back_at(text, current.end)?..current.end)
+        } else {
+            text.slice(current.start..regex.match_at(text, current.start)?)
+        };
+        let chars = matched.count_chars();
+        if chars == 0 {
+            return Some(matched);
+        }
+        if self.noeffects() {
+            let pos = self.pos();
+            self.jump(if self.backwards() {
+                pos - (chars - 1)
+            } else {
+                pos + (chars - 1)
+            });
+        } else {
+            advance_by(self, chars - usize::from(self.is_init()));
+        }
+        Some(matched)
+    }
+
+    // ------------ TRY ------------
+
+    /// - same with `jump`, but tells why it couldn't move.
+    /// - if you've never tried `len` before,
+    /// - the bounds check will count number of chars once.
+    #[inline]
+    fn try_jump(&mut self, pos: usize) -> Result<char, CursorError> {
+        check_bounds(pos, self.len())?;
+        self.jump(pos).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_jump_to_offset(&mut self, offset: isize) -> Result<char, CursorError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(CursorError::Overflow)?;
+        check_bounds(pos, self.len())?;
+        self.jump_to_offset(offset).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_jump_to_first(&mut self) -> Result<char, CursorError> {
+        self.try_jump(0)
+    }
+    #[inline]
+    fn try_jump_to_last(&mut self) -> Result<char, CursorError> {
+        let last_pos = self.len().saturating_sub(1);
+        self.try_jump(last_pos)
+    }
+    #[inline]
+    fn try_first_to_last(&mut self) -> Result<(), CursorError> {
+        self.try_jump_to_first()?;
+        self.try_next_to_last()?;
+        Ok(())
+    }
+    #[inline]
+    fn try_jump_to_load(&mut self) -> Result<char, CursorError> {
+        self.try_jump(self.saved().pos())
+    }
+    /// `pos` wraps around `len`, so `-1` is the last one.
+    #[inline]
+    fn try_jump_cycle(&mut self, pos: isize) -> Result<char, CursorError> {
+        let pos = cycle_pos(pos, self.len())?;
+        self.try_jump(pos)
+    }
+    #[inline]
+    fn try_jump_to_offset_cycle(&mut self, offset: isize) -> Result<char, CursorError> {
+        let dst = isize::try_from(self.pos())
+            .ok()
+            .and_then(|pos| pos.checked_add(offset))
+            .ok_or(CursorError::Overflow)?;
+        let pos = cycle_pos(dst, self.len())?;
+        if pos as isize == dst {
+            self.try_jump_to_offset(offset)
+        } else {
+            self.try_jump(pos)
+        }
+    }
+    /// same with `next`, but tells why it couldn't move.
+    #[inline]
+    fn try_next(&mut self) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next().ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_prev(&mut self) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.prev().ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_pos(&mut self, pos: usize) -> Result<char, CursorError> {
+        check_bounds(pos, self.len())?;
+        self.next_to_pos(pos).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_offset(&mut self, offset: isize) -> Result<char, CursorError> {
+        let pos = self
+            .pos()
+            .checked_move_offset(offset)
+            .ok_or(CursorError::Overflow)?;
+        check_bounds(pos, self.len())?;
+        self.next_to_offset(offset).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_first(&mut self) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_first())
+    }
+    #[inline]
+    fn try_next_to_last(&mut self) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_last())
+    }
+    #[inline]
+    fn try_next_to_left(&mut self) -> Result<char, CursorError> {
+        self.head_to_left();
+        self.try_next()
+    }
+    #[inline]
+    fn try_next_to_right(&mut self) -> Result<char, CursorError> {
+        self.head_to_right();
+        self.try_next()
+    }
+    #[inline]
+    fn try_next_to_until(&mut self, f: fn(char) -> bool) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next_to_until(f).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_while(&mut self, f: fn(char) -> bool) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        self.next_to_while(f).ok_or(CursorError::EndOfInput)
+    }
+    #[inline]
+    fn try_next_to_load(&mut self) -> Result<char, CursorError> {
+        self.try_next_to_pos(self.saved().pos())
+    }
+    #[inline]
+    fn try_next_cycle(&mut self) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_cycle())
+    }
+    #[inline]
+    fn try_next_to_offset_cycle(&mut self, offset: isize) -> Result<char, CursorError> {
+        if self.is_empty() {
+            return Err(CursorError::EmptyInput);
+        }
+        Ok(self.next_to_offset_cycle(offset))
+    }
+}
+
+// ----------------
+
+/// a [`TextCursorTrait`](TextCursorTrait) over a `str`,
+/// with the byte and `&str` views on top of the units.
+pub trait StrCursorTrait<'s, E = NoneExtras<char>>:
+    TextCursorTrait<'s, E, Decoder = Utf8, Text = str, Info = StrCursorInfo<E>>
+where
+    Self: Iterator<Item = char>,
+    E: Extras<char>,
+{
+    fn pos_as_bytes(&self) -> usize;
+    fn char_start_pos(&self) -> usize;
+
+    #[inline]
+    fn len_as_bytes(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    // ------ BYTES ------
+
+    #[inline]
+    fn as_bytes(&self) -> &'s [u8] {
+        self.as_units()
+    }
+
+    /// saved pos to current pos.
+    #[inline]
+    fn as_bytes_loaded(&self) -> &'s [u8] {
+        let slice = self.as_bytes();
+        let pos = self.pos_as_bytes();
+        let saved_pos = self.saved().inner.pos;
+        match pos.cmp(&saved_pos) {
+            Ordering::Greater => &slice[saved_pos..pos.saturating_add(1)],
+            Ordering::Equal => &slice[pos..1],
+            Ordering::Less => &slice[pos..saved_pos.saturating_add(1)],
+        }
+    }
+
+    /// saved pos to current char start pos.
+    #[inline]
+    fn as_char_bytes_loaded(&self) -> &'s [u8] {
+        let slice = self.as_bytes();
+        let pos = self.char_start_pos();
+        let saved_pos = self.saved().inner.pos;
+        match pos.cmp(&saved_pos) {
+            Ordering::Greater => &slice[saved_pos..pos.saturating_add(1)],
+            Ordering::Equal => &slice[pos..1],
+            Ordering::Less => &slice[pos..saved_pos.saturating_add(1)],
+        }
+    }
+
+    #[inline]
+    fn as_preserved_bytes(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_right_side_bytes()
+        } else {
+            self.as_left_side_bytes()
+        }
+    }
+
+    #[inline]
+    fn as_preserved_bytes_include_current(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_right_side_bytes_include_current()
+        } else {
+            self.as_left_side_bytes_include_current()
+        }
+    }
+
+    #[inline]
+    fn current_byte(&self) -> u8 {
+        self.as_bytes()[self.pos_as_bytes()]
+    }
+
+    #[inline]
+    fn as_remaining_bytes(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_left_side_bytes()
+        } else {
+            self.as_right_side_bytes()
+        }
+    }
+
+    #[inline]
+    fn as_remaining_bytes_include_current(&self) -> &'s [u8] {
+        if self.backwards() {
+            self.as_left_side_bytes_include_current()
+        } else {
+            self.as_right_side_bytes_include_current()
+        }
+    }
+
+    #[inline]
+    fn as_left_side_bytes(&self) -> &'s [u8] {
+        &self.as_bytes()[..self.pos_as_bytes()]
+    }
+
+    #[inline]
+    fn as_right_side_bytes(&self) -> &'s [u8] {
+        &self.as_bytes()[self.pos_as_bytes().saturating_add(1)..]
+    }
+
+    #[inline]
+    fn as_left_side_bytes_include_current(&self) -> &'s [u8] {
+        &self.as_bytes()[..self.pos_as_bytes().saturating_add(1)]
+    }
+
+    #[inline]
+    fn as_right_side_bytes_include_current(&self) -> &'s [u8] {
+        &self.as_bytes()[self.pos_as_bytes()..]
+    }
+
+    // ------ STR ------
+
+    #[inline]
+    fn as_str(&self) -> &'s str {
+        self.as_text()
+    }
+
+    /// saved pos to current pos.
+    #[inline]
+    fn as_str_loaded(&self) -> &'s str {
+        str_between(self.as_bytes(), self.saved(), self)
+    }
+
+    /// checkpoint pos to current pos.
+    #[inline]
+    fn str_since(&self, checkpoint: &Checkpoint<StrCursorInfo<E>>) -> &'s str {
+        str_between(self.as_bytes(), checkpoint.info(), self)
+    }
+
+    /// the chars in `span`, no scanning.
+    #[inline]
+    fn str_span(&self, span: &Span) -> &'s str {
+        span.slice(self.as_str())
+    }
+
+    #[inline]
+    fn as_preserved_str(&self) -> &'s str {
+        if self.backwards() {
+            self.as_right_side_str()
+        } else {
+            self.as_left_side_str()
+        }
+    }
+
+    #[inline]
+    fn as_remaining_str(&self) -> &'s str {
+        if self.backwards() {
+            self.as_left_side_str()
+        } else {
+            self.as_right_side_str()
+        }
+    }
+
+    #[inline]
+    fn as_left_side_str(&self) -> &'s str {
+        let curr_pos = self.pos_as_bytes();
+        if let Some(n) = self.as_bytes()[..curr_pos.saturating_add(1)]
+            .iter()
+            .rev()
+            .position(|&byte| !utf::utf8_is_cont_byte(byte))
+        {
+            utf::from_utf8_unchecked(&self.as_bytes()[..curr_pos.saturating_sub(n)])
+        } else {
+            ""
+        }
+    }
+
+    #[inline]
+    fn as_right_side_str(&self) -> &'s str {
+        let curr_pos = self.pos_as_bytes();
+        if let Some(n) = self.as_bytes()[self.pos_as_bytes().saturating_add(1)..]
+            .iter()
+            .position(|&byte| !utf::utf8_is_cont_byte(byte))
+        {
+            utf::from_utf8_unchecked(&self.as_bytes()[curr_pos.saturating_add(n + 1)..])
+        } else {
+            ""
+        }
+    }
+}
+
+/// the item at `pos`, empty if there is none.
+#[inline]
+fn item_span(pos: usize, exists: bool) -> Span {
+    Span::of_items(pos..pos + exists as usize)
+}
+
+/// `from` state to the current state of the `cursor`.
+#[inline]
+fn str_between<'s, E, C>(bytes: &'s [u8], from: &StrCursorInfo<E>, cursor: &C) -> &'s str
+where
+    E: Extras<char>,
+    C: StrCursorTrait<'s, E> + ?Sized,
+{
+    let saved_backwards = from.inner.backwards;
+    let curr_backwards = cursor.backwards();
+    let saved_pos = from.inner.pos;
+    let curr_pos = cursor.pos_as_bytes();
+    let saved_char_start_pos = from.char_start_pos;
+    let curr_char_start_pos = cursor.char_start_pos();
+    match curr_pos.cmp(&saved_pos) {
+        Ordering::Greater => {
+            let saved_pos = if saved_backwards {
+                saved_pos
+            } else {
+                saved_char_start_pos
+            };
+            let curr_pos = if curr_backwards {
+                curr_char_start_pos
+            } else {
+                curr_pos
+            };
+            utf::from_utf8_unchecked(&bytes[saved_pos..curr_pos + 1])
+        }
+        Ordering::Equal => utf::from_utf8_unchecked(if curr_backwards {
+            &bytes[curr_pos..curr_char_start_pos + 1]
+        } else {
+            &bytes[curr_char_start_pos..curr_pos + 1]
+        }),
+        Ordering::Less => {
+            let saved_pos = if saved_backwards {
+                saved_char_start_pos
+            } else {
+                saved_pos
+            };
+            let curr_pos = if curr_backwards {
+                curr_pos
+            } else {
+                curr_char_start_pos
+            };
+            utf::from_utf8_unchecked(&bytes[curr_pos..saved_pos + 1])
+        }
+    }
+}
+
+/// units of the current char, the first one's before the first `next()`.
+#[inline]
+fn current_units_range<'s, E, C>(cursor: &C) -> Range<usize>
+where
+    E: Extras<char>,
+    C: TextCursorTrait<'s, E> + ?Sized,
+{
+    if cursor.is_init() {
+        cursor.span_of_current().bytes()
+    } else {
+        0..cursor.as_text().char_at(0).map_or(0, |(_, end)| end)
+    }
+}
+
+/// the slice that `next()`s heading `right` would walk through.
+#[inline]
+pub(crate) fn upcoming_slice<'s, T, E, C>(cursor: &C, right: bool) -> &'s [T]
+where
+    T: 's,
+    E: Extras<T>,
+    C: CursorTrait<'s, T, E> + ?Sized,
+{
+    let slice = cursor.as_slice();
+    let pos = cursor.pos().min(slice.len());
+    match (cursor.is_init(), right) {
+        // the first `next()` returns the current one.
+        (false, true) => &slice[pos..],
+        (false, false) => &slice[..(pos + 1).min(slice.len())],
+        (true, true) => &slice[(pos + 1).min(slice.len())..],
+        (true, false) => &slice[..pos],
+    }
+}
+
+/// the text that `next()`s heading `right` would walk through.
+#[inline]
+pub(crate) fn upcoming_text<'s, E, C>(cursor: &C, right: bool) -> &'s C::Text
+where
+    E: Extras<char>,
+    C: TextCursorTrait<'s, E> + ?Sized,
+{
+    let text = cursor.as_text();
+    let len = text.as_units().len();
+    let current = current_units_range(cursor);
+    text.slice(match (cursor.is_init(), right) {
+        // the first `next()` returns the first char.
+        (false, true) => 0..len,
+        (false, false) => 0..current.end,
+        (true, true) => current.end..len,
+        (true, false) => 0..current.start,
+    })
+}
+
+#[inline]
+fn collect_chars<I: Iterator<Item = char>, const N: usize>(mut chars: I) -> Option<[char; N]> {
+    let mut out = [EOF_CHAR; N];
+    for slot in out.iter_mut() {
+        *slot = chars.next()?;
+    }
+    Some(out)
+}
+
+#[inline]
+fn chars_start_with<I, P>(mut chars: I, pattern: P) -> bool
+where
+    I: Iterator<Item = char>,
+    P: IntoIterator<Item = char>,
+{
+    pattern.into_iter().all(|p| chars.next() == Some(p))
+}
+
+/// wraps `pos` around `len`.
+#[inline]
+pub(crate) fn cycle_pos(pos: isize, len: usize) -> Result<usize, CursorError> {
+    if len == 0 {
+        return Err(CursorError::EmptyInput);
+    }
+    let len = isize::try_from(len).map_err(|_| CursorError::Overflow)?;
+    Ok(pos.rem_euclid(len) as usize)
+}
+
+/// pos of the nearest `needle` in the slice that `next()`s heading `right` would walk through.
+#[inline]
+fn find_in_slice<'s, T, E, C>(cursor: &C, needle: &[T], right: bool) -> Option<usize>
+where
+    T: 's + Ord,
+    E: Extras<T>,
+    C: CursorTrait<'s, T, E> + ?Sized,
+{
+    if needle.is_empty() {
+        return None;
+    }
+    let haystack = upcoming_slice(cursor, right);
+    if right {
+        let first = upcoming_pos(cursor.pos(), cursor.is_init(), true);
+        Some(first + search::find(haystack, needle)?)
+    } else {
+        // heading left, it starts from `0`.
+        search::rfind(haystack, needle)
+    }
+}
+
+/// pos of the nearest `needle` in the text that `next()`s heading `right` would walk through.
+#[inline]
+fn find_in_text<'s, E, C>(cursor: &C, needle: &str, right: bool) -> Option<usize>
+where
+    E: Extras<char>,
+    C: TextCursorTrait<'s, E> + ?Sized,
+{
+    let (before, matched) = upcoming_text(cursor, right).find_str(needle, right)?;
+    let first = upcoming_pos(cursor.pos(), cursor.is_init(), right);
+    if right {
+        Some(first + before)
+    } else {
+        // heading left, it ends `before` chars before `first`.
+        Some(first + 1 - before - matched)
+    }
+}
+
+/// pos of what the next `next()` would return.
+#[inline]
+pub(crate) fn upcoming_pos(pos: usize, init: bool, right: bool) -> usize {
+    match (init, right) {
+        (false, _) => pos,
+        (true, true) => pos.saturating_add(1),
+        (true, false) => pos.saturating_sub(1),
+    }
+}
+
+#[inline]
+fn advance_by<I: Iterator + ?Sized>(cursor: &mut I, n: usize) {
+    for _ in 0..n {
+        if cursor.next().is_none() {
+            break;
+        }
+    }
 }
@@ -0,0 +1,96 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+const R: char = char::REPLACEMENT_CHARACTER;
+
+/// a log line with a cut sequence, a surrogate, an overlong `/`, stray
+/// continuations and a real U+FFFD.
+const BYTES: &[u8] = b"ok \xe2\x82 \xed\xa0\x80 \xc0\xaf \x80\x80 \xef\xbf\xbd \xf0\x9f\x98\x80";
+
+#[test]
+fn it_works() {
+    let lossy: Vec<_> = String::from_utf8_lossy(BYTES).chars().collect();
+    let mut cursor = BStrCursor::new(BYTES);
+    let forwards: Vec<_> = cursor.by_ref().collect();
+    assert_eq!(forwards, lossy);
+    assert_eq!(cursor.current(), '😀');
+
+    cursor.turnaround();
+    let mut backwards: Vec<_> = cursor.by_ref().collect();
+    backwards.insert(0, '😀');
+    backwards.reverse();
+    assert_eq!(backwards, lossy);
+    assert_eq!(cursor.len(), lossy.len());
+
+    // maximal subparts.
+    cursor.jump(3);
+    assert_eq!(
+        (cursor.current(), cursor.current_bytes()),
+        (R, &b"\xe2\x82"[..])
+    );
+    cursor.jump(5);
+    assert_eq!(cursor.current_bytes(), b"\xed");
+    cursor.jump(9);
+    assert_eq!(cursor.current_bytes(), b"\xc0");
+}
+
+#[test]
+fn strict_works() {
+    let mut cursor = BStrCursor::new(BYTES);
+    let strict: Vec<_> = cursor.strict().collect();
+    let errors: Vec<_> = strict
+        .iter()
+        .filter_map(|item| match item {
+            Err(CursorError::InvalidUtf8 { byte_pos }) => Some(*byte_pos),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(errors, [3, 6, 7, 8, 10, 11, 13, 14]);
+    // the one of the input is a char.
+    assert!(strict.contains(&Ok(R)));
+    assert_eq!(strict.last(), Some(&Ok('😀')));
+
+    // both ways.
+    assert_eq!(cursor.prev_strict(), Some(Ok(' ')));
+    assert_eq!(cursor.prev_strict(), Some(Ok(R)));
+    assert!(!cursor.is_invalid());
+    cursor.jump(14);
+    assert_eq!(
+        cursor.prev_strict(),
+        Some(Err(CursorError::InvalidUtf8 { byte_pos: 14 }))
+    );
+    assert!(cursor.is_invalid());
+}
+
+#[test]
+fn bytes_works() {
+    let mut cursor = BStrCursor::new(b"ab\xffcd");
+    cursor.jump(2);
+    assert_eq!(cursor.pos_as_bytes(), 2);
+    assert_eq!(cursor.as_left_side_bytes(), b"ab");
+    assert_eq!(cursor.as_right_side_bytes(), b"cd");
+    assert_eq!(cursor.as_left_side_bytes_include_current(), b"ab\xff");
+    assert_eq!(cursor.as_right_side_bytes_include_current(), b"\xffcd");
+    assert_eq!(cursor.as_preserved_bytes(), b"ab");
+    assert_eq!(cursor.as_remaining_bytes(), b"cd");
+    cursor.turnaround();
+    assert_eq!(cursor.as_preserved_bytes_include_current(), b"\xffcd");
+    assert_eq!(cursor.as_remaining_bytes(), b"ab");
+
+    cursor.jump(1);
+    cursor.save();
+    cursor.jump(4);
+    assert_eq!(cursor.as_bytes_loaded(), b"b\xffcd");
+    assert_eq!(
+        cursor.as_str_loaded(),
+        Err(CursorError::InvalidUtf8 { byte_pos: 2 })
+    );
+    cursor.jump(3);
+    let checkpoint = cursor.push_checkpoint();
+    cursor.jump(4);
+    assert_eq!(cursor.bytes_since(&checkpoint), b"cd");
+    cursor.load();
+    cursor.save();
+    assert_eq!(cursor.as_str_loaded(), Ok("b"));
+}
@@ -0,0 +1,136 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+const STRING: &str = "ab\r\n안녕 하세요\n😀x🇰🇷 ok é\u{301} 끝";
+
+#[test]
+fn it_works() {
+    let chars: Vec<_> = STRING.char_indices().collect();
+    for stride in [1, 2, 3, 7, 64] {
+        let index = CharIndex::with_stride(STRING, stride);
+        assert_eq!(index.len(), chars.len());
+        assert!(!index.is_ascii());
+        for (pos, &(byte, _)) in chars.iter().enumerate() {
+            assert_eq!(index.byte_pos(pos), Some(byte), "{} {}", stride, pos);
+            assert_eq!(index.char_pos(byte), Some(pos));
+        }
+        assert_eq!(index.byte_pos(chars.len()), None);
+        assert_eq!(index.char_pos(STRING.len()), Some(chars.len()));
+        assert_eq!(index.char_pos(STRING.len() + 1), None);
+    }
+    // inside of a char.
+    assert_eq!(CharIndex::new("a안").char_pos(2), Some(1));
+
+    let ascii = CharIndex::new("plain ascii");
+    assert!(ascii.is_ascii());
+    assert_eq!(ascii.byte_pos(6), Some(6));
+    assert_eq!(ascii.byte_pos(11), None);
+    assert!(CharIndex::new("").is_empty());
+}
+
+#[test]
+fn cursor_works() {
+    let chars: Vec<_> = STRING.char_indices().collect();
+    let mut plain = StrCursor::new_with_extras::<LineColExtras>(STRING);
+    let mut indexed = StrCursor::new_with_extras::<LineColExtras>(STRING).with_char_index(3);
+    assert!(indexed.is_len());
+    assert_eq!(indexed.len(), chars.len());
+
+    let mut rng = Rng::new(0x2545_f491);
+    for _ in 0..500 {
+        let seed = rng.next_u32();
+        let pos = seed as usize % (chars.len() + 2);
+        if seed.is_multiple_of(4) {
+            plain.turnaround();
+            indexed.turnaround();
+        }
+        let expected = plain.jump(pos);
+        assert_eq!(indexed.jump(pos), expected, "{}", pos);
+        assert_eq!(expected, chars.get(pos).map(|&(_, ch)| ch));
+        assert_eq!(indexed.pos(), plain.pos());
+        assert_eq!(indexed.pos_as_bytes(), plain.pos_as_bytes());
+        assert_eq!(indexed.span_of_current(), plain.span_of_current());
+        assert_eq!(indexed.extras().line_col(), plain.extras().line_col());
+        assert_eq!(indexed.next(), plain.next());
+    }
+
+    // built on demand.
+    let mut cursor = StrCursor::new(STRING);
+    assert_eq!(cursor, StrCursor::new(STRING).with_char_index(3));
+    assert!(!cursor.is_len());
+    assert_eq!(cursor.char_index().stride(), CharIndex::DEFAULT_STRIDE);
+    assert!(cursor.is_len());
+    assert_eq!(cursor.jump_to_last(), '끝');
+    assert_eq!(cursor.jump(chars.len()), None);
+    assert_eq!(cursor.jump(4), Some('안'));
+}
+
+#[test]
+fn ascii_works() {
+    let string = "a plain ascii line\nand another";
+    let mut cursor = StrCursor::new(string);
+    assert_eq!(cursor.len(), string.len());
+    for pos in [20, 3, 29, 0, 19, 18] {
+        assert_eq!(cursor.jump(pos), string[pos..].chars().next());
+        assert_eq!(cursor.pos_as_bytes(), pos);
+    }
+    assert_eq!(cursor.jump(string.len()), None);
+    cursor.turnaround();
+    assert_eq!(cursor.jump(5), Some('i'));
+    assert_eq!(cursor.next(), Some('a'));
+}
+
+#[test]
+fn send_works() {
+    fn send_sync<T: Send + Sync>(_: &T) {}
+
+    let mut cursor = StrCursor::new(STRING).with_char_index(4);
+    cursor.line_index();
+    send_sync(&cursor);
+    let index = cursor.char_index().clone();
+    assert_eq!(&index, cursor.char_index());
+}
+
+#[test]
+fn text_works() {
+    let units: Vec<u16> = STRING.encode_utf16().collect();
+    let text = Text::<Utf16>::new(&units);
+    let mut starts = Vec::new();
+    let mut at = 0;
+    for ch in STRING.chars() {
+        starts.push(at);
+        at += ch.len_utf16();
+    }
+    for stride in [1, 3, 64] {
+        let index = CharIndex::with_stride(text, stride);
+        assert_eq!(index.len(), starts.len());
+        for (pos, &unit) in starts.iter().enumerate() {
+            assert_eq!(index.byte_pos(pos), Some(unit), "{} {}", stride, pos);
+            assert_eq!(index.char_pos(unit), Some(pos));
+        }
+        assert_eq!(index.byte_pos(starts.len()), None);
+    }
+
+    let mut plain = TextCursor::<Utf16>::new_with_extras::<LineColExtras>(&units);
+    let mut indexed =
+        TextCursor::<Utf16>::new_with_extras::<LineColExtras>(&units).with_char_index(3);
+    assert!(indexed.is_len());
+    let mut rng = Rng::new(0x1f12_3bb5);
+    for _ in 0..500 {
+        let seed = rng.next_u32();
+        let pos = seed as usize % (starts.len() + 2);
+        if seed.is_multiple_of(4) {
+            plain.turnaround();
+            indexed.turnaround();
+        }
+        let expected = plain.jump(pos);
+        assert_eq!(indexed.jump(pos), expected, "{}", pos);
+        assert_eq!(indexed.pos_as_units(), plain.pos_as_units());
+        assert_eq!(indexed.extras().line_col(), plain.extras().line_col());
+        assert_eq!(indexed.next(), plain.next());
+    }
+}
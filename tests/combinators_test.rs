@@ -0,0 +1,144 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::{combinators::*, *};
+
+#[derive(Debug, Default)]
+struct SpaceCounter(pub usize);
+
+impl Extras<char> for SpaceCounter {
+    fn new() -> Self {
+        SpaceCounter::default()
+    }
+    fn clone(&self) -> Self {
+        SpaceCounter(self.0)
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+    fn change(&mut self, input: &char, _pos: usize) {
+        if *input == ' ' {
+            self.0 += 1;
+        }
+    }
+}
+
+#[test]
+fn leaves_works() {
+    let mut cursor = StrCursor::new("let x = 42;");
+
+    assert_eq!(tag("let")(&mut cursor), Ok("let"));
+    assert_eq!(char(' ')(&mut cursor), Ok(' '));
+    assert_eq!(
+        tag("var")(&mut cursor),
+        Err(ParseError::Backtrack(CursorError::Unexpected { pos: 4 }))
+    );
+    assert_eq!(one_of('a'..='z')(&mut cursor), Ok('x'));
+    assert_eq!(take_while(' ')(&mut cursor), Ok(" "));
+    assert_eq!(one_of(&['+', '='][..])(&mut cursor), Ok('='));
+    assert_eq!(take_until(';')(&mut cursor), Ok(" 42"));
+    assert_eq!(take_while(char::is_alphabetic)(&mut cursor), Ok(""));
+    assert_eq!(char(';')(&mut cursor), Ok(';'));
+    assert_eq!(
+        char(';')(&mut cursor),
+        Err(ParseError::Backtrack(CursorError::EndOfInput))
+    );
+
+    let mut cursor = Cursor::new(b"GET /index.html");
+    assert_eq!(tag(&b"GET"[..])(&mut cursor), Ok(&b"GET"[..]));
+    assert_eq!(one_of(b' ')(&mut cursor), Ok(&b' '));
+    assert_eq!(
+        take_until(b' ')(&mut cursor),
+        Err(ParseError::Backtrack(CursorError::EndOfInput))
+    );
+    assert_eq!(
+        take_while(|b: &u8| *b != b'.')(&mut cursor),
+        Ok(&b"/index"[..])
+    );
+    assert_eq!(tag(&b".html"[..])(&mut cursor), Ok(&b".html"[..]));
+}
+
+#[test]
+fn branches_works() {
+    let mut cursor = StrCursor::new("true false maybe");
+    let mut boolean = alt((map(tag("true"), |_| true), map(tag("false"), |_| false)));
+
+    assert_eq!(boolean(&mut cursor), Ok(true));
+    assert_eq!(char(' ')(&mut cursor), Ok(' '));
+    assert_eq!(boolean(&mut cursor), Ok(false));
+    assert_eq!(char(' ')(&mut cursor), Ok(' '));
+    assert_eq!(
+        boolean(&mut cursor),
+        Err(ParseError::Backtrack(CursorError::Unexpected { pos: 11 }))
+    );
+    assert_eq!(opt(tag("true"))(&mut cursor), Ok(None));
+    assert_eq!(tag("maybe")(&mut cursor), Ok("maybe"));
+
+    // seq rewinds everything if one backtracks.
+    let mut cursor = StrCursor::new("ab-c");
+    assert!(seq((char('a'), char('b'), char('c')))(&mut cursor).is_err());
+    assert_eq!(cursor.offset(), 0);
+    assert_eq!(
+        seq((char('a'), char('b'), char('-'), char('c')))(&mut cursor),
+        Ok(('a', 'b', '-', 'c'))
+    );
+
+    // cut stops alt from trying the others.
+    let mut cursor = StrCursor::new("(x");
+    let mut paren = seq((char('('), cut(char(')'))));
+    let mut other = alt((map(&mut paren, |_| "paren"), tag("(x")));
+    assert_eq!(
+        other(&mut cursor),
+        Err(ParseError::Cut(CursorError::Unexpected { pos: 1 }))
+    );
+    // and leaves the cursor where it failed.
+    assert_eq!(cursor.offset(), 1);
+}
+
+#[test]
+fn repetition_works() {
+    let mut cursor = StrCursor::new("aaab");
+    assert_eq!(many0(char('a'))(&mut cursor), Ok(vec!['a', 'a', 'a']));
+    assert_eq!(many0(char('a'))(&mut cursor), Ok(vec![]));
+    assert_eq!(
+        many1(char('a'))(&mut cursor),
+        Err(ParseError::Backtrack(CursorError::Unexpected { pos: 3 }))
+    );
+    // stops when nothing is consumed.
+    assert_eq!(many0(take_while('x'))(&mut cursor), Ok(vec![""]));
+
+    let mut cursor = StrCursor::new("1,22,333,");
+    let digits = map(many1(one_of('0'..='9')), |d| d.len());
+    assert_eq!(
+        separated_list(char(','), digits)(&mut cursor),
+        Ok(vec![1, 2, 3])
+    );
+    // the trailing separator is left.
+    assert_eq!(char(',')(&mut cursor), Ok(','));
+
+    let mut cursor = Cursor::new(&[1u8, 0, 2, 0, 3]);
+    assert_eq!(
+        separated_list(one_of(0u8), one_of(1..4))(&mut cursor),
+        Ok(vec![&1, &2, &3])
+    );
+}
+
+#[test]
+fn recognize_works() {
+    let mut cursor = StrCursor::new_with_extras::<SpaceCounter>("fn main ( ) {}");
+    let word = take_while(char::is_alphabetic);
+    let spaces = take_while(' ');
+
+    let (text, extras) =
+        recognize(seq((word, spaces, tag("main"), take_while(' '))))(&mut cursor).unwrap();
+    assert_eq!(text, "fn main ");
+    assert_eq!((extras.start.0, extras.end.0), (0, 2));
+
+    let (text, extras) =
+        recognize(seq((char('('), take_until(')'), char(')'))))(&mut cursor).unwrap();
+    assert_eq!(text, "( )");
+    assert_eq!((extras.start.0, extras.end.0), (2, 3));
+
+    // failed, nothing consumed.
+    assert!(recognize(tag("{ }"))(&mut cursor).is_err());
+    assert_eq!(cursor.rest(), " {}");
+}
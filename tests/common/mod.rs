@@ -0,0 +1,24 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! shared by the test files, each picks what it needs.
+#![allow(dead_code)]
+
+/// a xorshift32, the same numbers on every run.
+pub struct Rng(u32);
+
+impl Rng {
+    /// `seed` must not be `0`.
+    pub fn new(seed: u32) -> Self {
+        Rng(seed)
+    }
+    pub fn next_u32(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+    /// `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        self.next_u32() as usize % n
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+//! the UAX #29 break tests, `scripts/unicode.py` fetches them into `tests/data/`,
+//! the `conformance` CI job runs them.
+
+use cursor::*;
+
+/// (text, segments) of each line of `tests/data/<name>`.
+/// * skips the lines with surrogates, a `str` can't hold them.
+fn cases(name: &str) -> Vec<(String, Vec<String>)> {
+    let path = format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let data = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("{}: {}, run scripts/unicode.py first", path, e));
+    data.lines()
+        .filter_map(|line| {
+            let line = line.split('#').next()?.trim();
+            if line.is_empty() {
+                return None;
+            }
+            let (mut text, mut segments, mut segment) = (String::new(), Vec::new(), String::new());
+            for token in line.split_whitespace() {
+                match token {
+                    "÷" if !segment.is_empty() => segments.push(std::mem::take(&mut segment)),
+                    "÷" | "×" => {}
+                    code => {
+                        let c = char::from_u32(u32::from_str_radix(code, 16).ok()?)?;
+                        text.push(c);
+                        segment.push(c);
+                    }
+                }
+            }
+            Some((text, segments))
+        })
+        .collect()
+}
+
+#[test]
+#[ignore = "needs tests/data, run scripts/unicode.py"]
+fn grapheme_conformance_works() {
+    for (text, segments) in cases("GraphemeBreakTest.txt") {
+        let forwards: Vec<_> = grapheme::graphemes(&text).collect();
+        assert_eq!(forwards, segments, "{:?}", text);
+        let mut backwards: Vec<_> = grapheme::graphemes(&text).rev().collect();
+        backwards.reverse();
+        assert_eq!(backwards, segments, "{:?}", text);
+    }
+}
+
+#[test]
+#[ignore = "needs tests/data, run scripts/unicode.py"]
+fn word_conformance_works() {
+    for (text, segments) in cases("WordBreakTest.txt") {
+        let forwards: Vec<_> = words::segments(&text, WordMode::Unicode)
+            .map(|(_, segment)| segment)
+            .collect();
+        assert_eq!(forwards, segments, "{:?}", text);
+        let mut backwards: Vec<_> = words::segments(&text, WordMode::Unicode)
+            .rev()
+            .map(|(_, segment)| segment)
+            .collect();
+        backwards.reverse();
+        assert_eq!(backwards, segments, "{:?}", text);
+    }
+}
+
+#[test]
+#[ignore = "needs tests/data, run scripts/unicode.py"]
+fn sentence_conformance_works() {
+    for (text, segments) in cases("SentenceBreakTest.txt") {
+        let forwards: Vec<_> = sentences::segments(&text)
+            .map(|(_, segment)| segment)
+            .collect();
+        assert_eq!(forwards, segments, "{:?}", text);
+        let mut backwards: Vec<_> = sentences::segments(&text)
+            .rev()
+            .map(|(_, segment)| segment)
+            .collect();
+        backwards.reverse();
+        assert_eq!(backwards, segments, "{:?}", text);
+    }
+}
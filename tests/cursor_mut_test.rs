@@ -0,0 +1,133 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+const SLICE: &[u8] = &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+#[derive(Debug, Default)]
+struct WriteLog(pub Vec<(u8, usize)>);
+
+impl Extras<u8> for WriteLog {
+    fn new() -> Self {
+        WriteLog::default()
+    }
+    fn clone(&self) -> Self {
+        WriteLog(self.0.clone())
+    }
+    fn reset(&mut self) {
+        self.0.clear();
+    }
+    fn change(&mut self, input: &u8, pos: usize) {
+        self.0.push((*input, pos));
+    }
+}
+
+#[test]
+fn it_works() {
+    let mut buf = SLICE.to_vec();
+    let mut cursor = CursorMut::new(&mut buf);
+    let mut i = 0;
+    while let Some(&ch) = cursor.next() {
+        assert_eq!(ch, SLICE[i]);
+        i += 1;
+    }
+    assert_eq!(i, SLICE.len());
+}
+
+#[test]
+fn navigation_works() {
+    let mut buf = SLICE.to_vec();
+    let mut cursor = CursorMut::new(&mut buf);
+
+    assert_eq!(cursor.jump_to_offset(6), Some(&7));
+    assert_eq!(cursor.jump_to_offset(-6), Some(&1));
+    assert_eq!(cursor.jump_to_last(), &10);
+
+    cursor.save();
+    cursor.turnaround();
+    assert_eq!(cursor.next(), Some(&9));
+    assert_eq!(cursor.prev(), Some(&10));
+    assert_eq!(cursor.next_to_pos(7), Some(&8));
+    assert_eq!(cursor.as_slice_loaded(), &[8, 9, 10]);
+
+    cursor.load();
+    assert_eq!(cursor.pos(), 9);
+    assert!(!cursor.backwards());
+    cursor.head_to_left();
+    assert_eq!(cursor.next_to_until(|&i| i == 3), Some(&3));
+}
+
+#[test]
+fn write_works() {
+    let mut buf = SLICE.to_vec();
+    {
+        let mut cursor = CursorMut::new(&mut buf);
+        cursor += 2;
+        assert_eq!(cursor.set(30), 3);
+        *cursor.current_mut() += 1;
+        assert_eq!(cursor.current(), &31);
+
+        assert_eq!(cursor.swap_with_offset(-2), Some(&1));
+        assert_eq!(cursor.swap_with_offset(10), None);
+
+        assert_eq!(cursor.fill_range(7..10, 0), Some(&mut [0, 0, 0][..]));
+        assert_eq!(cursor.fill_range(7..11, 0), None);
+
+        cursor.as_remaining_slice_mut()[0] = 40;
+        assert_eq!(cursor.as_remaining_slice(), &[40, 5, 6, 7, 0, 0, 0]);
+    }
+    assert_eq!(buf, [31, 2, 1, 40, 5, 6, 7, 0, 0, 0]);
+}
+
+#[test]
+fn extras_works() {
+    let mut buf = SLICE.to_vec();
+    let mut cursor = CursorMut::new_with_extras::<WriteLog>(&mut buf);
+    cursor.next();
+    cursor.next();
+    assert_eq!(cursor.extras().0, [(2, 1)]);
+
+    cursor.extras_mut().reset();
+    cursor.set(20);
+    cursor.swap_with_offset(1);
+    assert_eq!(cursor.extras().0, [(20, 1), (20, 2), (3, 1)]);
+
+    cursor.extras_mut().reset();
+    {
+        // read only
+        let current = cursor.current_mut();
+        assert_eq!(*current, 3);
+    }
+    assert!(cursor.extras().0.is_empty());
+    *cursor.current_mut() = 0;
+    assert_eq!(cursor.extras().0, [(0, 1)]);
+
+    cursor.extras_mut().reset();
+    cursor.noeffects_on();
+    cursor.fill_range(0..3, 7);
+    cursor.next();
+    cursor.noeffects_off();
+    cursor.fill_range(3..5, 8);
+    assert_eq!(cursor.into_extras().0, [(8, 3), (8, 4)]);
+}
+
+#[test]
+fn into_cursor_works() {
+    let mut buf = SLICE.to_vec();
+    let mut cursor = CursorMut::new(&mut buf);
+    cursor += 4;
+    cursor.set(50);
+    cursor.turnaround();
+
+    let mut cursor = cursor.into_cursor();
+    assert_eq!(cursor.current(), &50);
+    assert_eq!(cursor.next(), Some(&4));
+}
+
+#[test]
+fn empty_works() {
+    let mut empty: [u8; 0] = [];
+    let mut cursor = CursorMut::new(&mut empty);
+    assert_eq!(cursor.next(), None);
+    assert!(!cursor.is_init());
+}
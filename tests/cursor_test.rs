@@ -24,7 +24,7 @@ impl Extras<u8> for EvenCounter {
         self._reset();
     }
     fn change(&mut self, input: &u8, _pos: usize) {
-        if input % 2 == 0 {
+        if input.is_multiple_of(2) {
             self.0 += 1;
         }
     }
@@ -219,3 +219,268 @@ fn next_to_while() {
     let c = cursor.next_to_while(|&i| i <= 10);
     assert_eq!(c, None);
 }
+
+#[test]
+fn checkpoint_works() {
+    let mut cursor = Cursor::new_with_extras::<EvenCounter>(SLICE);
+    cursor.next();
+    let outer = cursor.push_checkpoint();
+    cursor.next_to_offset(3);
+    cursor.save();
+
+    let inner = cursor.push_checkpoint();
+    cursor.next_to_offset(2);
+    assert_eq!(cursor.slice_since(&inner), &[4, 5, 6]);
+    assert_eq!(cursor.slice_since(&outer), &[1, 2, 3, 4, 5, 6]);
+    cursor.restore(inner);
+    assert_eq!(*cursor.current(), 4);
+    assert_eq!(cursor.to_extras().0, 2);
+
+    let inner = cursor.push_checkpoint();
+    cursor.turnaround();
+    cursor.next();
+    cursor.commit(inner);
+    assert_eq!(*cursor.current(), 3);
+    assert!(cursor.backwards());
+
+    cursor.restore(outer);
+    assert_eq!(*cursor.current(), 1);
+    assert!(!cursor.backwards());
+    assert_eq!(cursor.to_extras().0, 0);
+    // untouched
+    assert_eq!(cursor.saved().pos, 3);
+}
+
+/// a cursor of its own, with the default checkpoints.
+struct Outer<'s>(Cursor<'s, u8, EvenCounter>);
+impl<'s> Iterator for Outer<'s> {
+    type Item = &'s u8;
+    fn next(&mut self) -> Option<&'s u8> {
+        self.0.next()
+    }
+}
+impl<'s> CursorTrait<'s, u8, EvenCounter> for Outer<'s> {
+    fn is_init(&self) -> bool {
+        self.0.is_init()
+    }
+    fn noeffects(&self) -> bool {
+        self.0.noeffects()
+    }
+    fn noeffects_mut(&mut self) -> &mut bool {
+        self.0.noeffects_mut()
+    }
+    fn backwards(&self) -> bool {
+        self.0.backwards()
+    }
+    fn backwards_mut(&mut self) -> &mut bool {
+        self.0.backwards_mut()
+    }
+    fn pos(&self) -> usize {
+        self.0.pos()
+    }
+    fn extras(&self) -> &EvenCounter {
+        self.0.extras()
+    }
+    fn extras_mut(&mut self) -> &mut EvenCounter {
+        self.0.extras_mut()
+    }
+    fn reset(&mut self) {
+        self.0.reset()
+    }
+    fn save(&mut self) {
+        self.0.save()
+    }
+    fn saved(&self) -> &CursorInfo<u8, EvenCounter> {
+        self.0.saved()
+    }
+    fn load(&mut self) {
+        self.0.load()
+    }
+    fn as_slice(&self) -> &'s [u8] {
+        self.0.as_slice()
+    }
+    fn jump(&mut self, pos: usize) -> Option<&'s u8> {
+        self.0.jump(pos)
+    }
+}
+
+#[test]
+fn default_checkpoint_works() {
+    let mut cursor = Outer(Cursor::new_with_extras::<EvenCounter>(SLICE));
+    let first = cursor.push_checkpoint();
+    cursor.next_to_offset(3);
+    let outer = cursor.push_checkpoint();
+    cursor.turnaround();
+    cursor.noeffects_on();
+    cursor.next_to_offset(-2);
+    // in any order.
+    cursor.restore(outer.clone());
+    assert_eq!((*cursor.current(), cursor.extras().0), (3, 1));
+    assert!(!cursor.backwards() && !cursor.noeffects());
+    cursor.restore(first);
+    assert!(!cursor.is_init());
+    assert_eq!(cursor.next(), Some(&1));
+    cursor.restore(outer);
+    assert_eq!(cursor.pos(), 2);
+}
+
+#[test]
+fn attempt_works() {
+    let mut cursor = Cursor::new_with_extras::<EvenCounter>(SLICE);
+    cursor.next();
+    cursor.save();
+
+    let out = cursor.attempt(|c| {
+        c.next_to_offset(3)?;
+        c.noeffects_on();
+        c.turnaround();
+        // nested
+        let inner: Result<(), ()> = c.try_attempt(|c| {
+            c.next_to_offset(-2);
+            Err(())
+        });
+        assert!(inner.is_err());
+        assert_eq!(*c.current(), 4);
+        c.jump(20).copied()
+    });
+    assert_eq!(out, None);
+    assert_eq!(*cursor.current(), 1);
+    assert!(!cursor.backwards());
+    assert!(!cursor.noeffects());
+    assert_eq!(cursor.to_extras().0, 0);
+
+    let out = cursor.attempt(|c| {
+        c.save();
+        c.next_to_offset(3).copied()
+    });
+    assert_eq!(out, Some(4));
+    assert_eq!(cursor.to_extras().0, 2);
+    assert_eq!(cursor.saved().pos, 0);
+}
+
+#[test]
+fn peek_works() {
+    let mut cursor = Cursor::new_with_extras::<EvenCounter>(SLICE);
+    assert_eq!(cursor.peek(), Some(&1));
+    assert_eq!(cursor.peek_array::<3>(), Some([&1, &2, &3]));
+
+    cursor.jump(4);
+    assert_eq!(cursor.peek(), Some(&6));
+    assert_eq!(cursor.peek_nth(4), Some(&10));
+    assert_eq!(cursor.peek_nth(5), None);
+    assert_eq!(cursor.peek_back_nth(0), Some(&4));
+    assert_eq!(cursor.at_offset(-2), Some(&3));
+    assert_eq!(cursor.at_offset(0), Some(&5));
+    assert!(cursor.starts_with(&[6, 7, 8]));
+    assert!(!cursor.starts_with(&[6, 8]));
+
+    cursor.turnaround();
+    assert_eq!(cursor.peek(), Some(&4));
+    assert_eq!(cursor.at_offset(-2), Some(&7));
+    assert_eq!(cursor.peek_array::<4>(), Some([&4, &3, &2, &1]));
+    assert_eq!(cursor.peek_array::<5>(), None);
+    assert!(cursor.starts_with(&[4, 3]));
+
+    // no moves, no effects
+    assert_eq!(*cursor.current(), 5);
+    assert_eq!(cursor.to_extras().0, 0);
+
+    let cursor = Cursor::new(b"<!-- -->");
+    assert!(cursor.starts_with_str("<!--"));
+    assert!(!cursor.starts_with_str("-->"));
+}
+
+#[test]
+fn try_works() {
+    let mut cursor = Cursor::new(SLICE);
+    assert_eq!(
+        cursor.try_jump(10),
+        Err(CursorError::OutOfBounds {
+            requested: 10,
+            len: 10
+        })
+    );
+    assert_eq!(cursor.try_jump_to_offset(-1), Err(CursorError::Overflow));
+    assert_eq!(cursor.try_jump_to_last(), Ok(&10));
+    assert_eq!(cursor.try_next(), Err(CursorError::EndOfInput));
+    assert_eq!(cursor.try_next_to_offset(-3), Ok(&7));
+    assert_eq!(cursor.try_jump_cycle(-1), Ok(&10));
+    assert_eq!(cursor.try_jump_to_offset_cycle(2), Ok(&2));
+    assert_eq!(
+        cursor.try_next_to_until(|&x| x > 10),
+        Err(CursorError::EndOfInput)
+    );
+
+    let mut empty = Cursor::new(&[] as &[u8]);
+    assert_eq!(empty.try_next(), Err(CursorError::EmptyInput));
+    assert_eq!(empty.try_jump_to_first(), Err(CursorError::EmptyInput));
+    assert_eq!(empty.next(), None);
+    assert_eq!(
+        CursorError::OutOfBounds {
+            requested: 3,
+            len: 2
+        }
+        .to_string(),
+        "position 3 is out of bounds (len: 2)"
+    );
+}
+
+#[test]
+fn pattern_works() {
+    let mut cursor = Cursor::new_with_extras::<EvenCounter>(SLICE);
+    assert_eq!(cursor.skip_matching(1..4), 3);
+    assert_eq!(cursor.current(), &3);
+    assert!(cursor.skip_until(&[6, 7]));
+    assert_eq!(cursor.current(), &5);
+    assert!(cursor.eat(&[6, 7]));
+    assert!(!cursor.eat(|x: &u8| *x > 8));
+    assert_eq!(cursor.expect(8), Ok(()));
+    assert_eq!(
+        cursor.expect(ByteSet::new(&[1, 2])),
+        Err(CursorError::Unexpected { pos: 8 })
+    );
+    assert_eq!(cursor.to_extras().0, 4);
+
+    cursor.turnaround();
+    assert!(cursor.advance_past(&[3, 4]));
+    assert_eq!(cursor.current(), &3);
+    assert!(!cursor.skip_until(0u8));
+    assert_eq!(cursor.skip_matching(|_: &u8| true), 2);
+    assert_eq!(cursor.expect(1), Err(CursorError::EndOfInput));
+
+    let mut cursor = Cursor::new(b"/* comment */ code");
+    assert!(cursor.eat("/*"));
+    assert!(cursor.advance_past("*/"));
+    assert_eq!(cursor.as_remaining_slice(), b" code");
+}
+
+#[test]
+fn find_works() {
+    let html = b"<!-- a --> b --> c";
+    let mut cursor = Cursor::new(html);
+    assert_eq!(cursor.find_next(b"-->"), Some(7));
+    assert_eq!(cursor.find_prev(b"-->"), None);
+    assert_eq!(cursor.jump_to_next(b"-->"), Some(&b'-'));
+    assert_eq!(cursor.pos(), 7);
+    assert_eq!(cursor.find_next(b"-->"), Some(13));
+    assert_eq!(cursor.jump_to_next(b"-->"), Some(&b'-'));
+    assert_eq!(cursor.jump_to_next(b"-->"), None);
+    assert_eq!(cursor.pos(), 13);
+
+    cursor.turnaround();
+    assert_eq!(cursor.find_next(b"-->"), Some(7));
+    assert_eq!(cursor.find_prev(b"c"), Some(17));
+    assert_eq!(cursor.jump_to_next(b"<!"), Some(&b'<'));
+    assert!(cursor.backwards());
+    assert_eq!(cursor.jump_to_prev(b" c"), Some(&b' '));
+    assert_eq!(cursor.pos(), 16);
+    assert!(cursor.backwards());
+
+    // every item on the way
+    let mut cursor = Cursor::new_with_extras::<EvenCounter>(SLICE);
+    assert_eq!(cursor.jump_to_next(&[7, 8]), Some(&7));
+    assert_eq!(cursor.to_extras().0, 3);
+    cursor.noeffects_on();
+    assert_eq!(cursor.jump_to_prev(&[1]), Some(&1));
+    assert_eq!(cursor.to_extras().0, 3);
+}
@@ -0,0 +1,313 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+const STRING: &str = "a\r\né안😀\nz🇰🇷";
+
+fn utf16_le(string: &str) -> Vec<u8> {
+    string.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+fn utf16_be(string: &str) -> Vec<u8> {
+    string.encode_utf16().flat_map(u16::to_be_bytes).collect()
+}
+
+/// every char forwards then backwards, with its units.
+fn walk<D: Decoder>(units: &[D::Unit], expected: &[char], widths: &[usize]) {
+    let mut cursor = TextCursor::<D>::new(units);
+    let mut at = 0;
+    for (pos, (&ch, &width)) in expected.iter().zip(widths).enumerate() {
+        assert_eq!(cursor.next(), Some(ch));
+        assert_eq!((cursor.pos(), cursor.pos_as_units()), (pos, at));
+        assert_eq!(cursor.current_units(), &units[at..at + width]);
+        at += width;
+    }
+    assert_eq!(cursor.next(), None);
+    assert_eq!(cursor.len(), expected.len());
+
+    cursor.turnaround();
+    let mut backwards: Vec<_> = cursor.by_ref().collect();
+    backwards.insert(0, *expected.last().unwrap());
+    backwards.reverse();
+    assert_eq!(backwards, expected);
+    assert_eq!((cursor.pos(), cursor.pos_as_units()), (0, 0));
+
+    assert_eq!(D::chars(units).collect::<Vec<_>>(), expected);
+    assert_eq!(D::chars(units).rev().count(), expected.len());
+    assert_eq!(D::count_chars(units), expected.len());
+}
+
+#[test]
+fn it_works() {
+    let chars: Vec<_> = STRING.chars().collect();
+
+    let utf8: Vec<_> = chars.iter().map(|c| c.len_utf8()).collect();
+    walk::<Utf8>(STRING.as_bytes(), &chars, &utf8);
+
+    let utf16: Vec<_> = chars.iter().map(|c| c.len_utf16()).collect();
+    let units: Vec<u16> = STRING.encode_utf16().collect();
+    walk::<Utf16>(&units, &chars, &utf16);
+
+    let bytes: Vec<_> = utf16.iter().map(|n| n * 2).collect();
+    walk::<Utf16Le>(&utf16_le(STRING), &chars, &bytes);
+    walk::<Utf16Be>(&utf16_be(STRING), &chars, &bytes);
+
+    let latin1 = b"caf\xe9 \xff";
+    let chars: Vec<_> = "café ÿ".chars().collect();
+    walk::<Latin1>(latin1, &chars, &[1; 6]);
+
+    let chars: Vec<_> = "caf\u{fffd} \u{fffd}".chars().collect();
+    walk::<Ascii>(latin1, &chars, &[1; 6]);
+}
+
+#[test]
+fn lossy_works() {
+    const R: char = char::REPLACEMENT_CHARACTER;
+    // a lone surrogate either way, and a lone high one before a pair.
+    let units = [0xdc00, 0x61, 0xd800, 0xd83d, 0xde00, 0xd800];
+    walk::<Utf16>(&units, &[R, 'a', R, '😀', R], &[1, 1, 1, 2, 1]);
+
+    // an odd trailing byte.
+    let mut bytes = utf16_le("a😀");
+    bytes.push(0x62);
+    walk::<Utf16Le>(&bytes, &['a', '😀', R], &[2, 4, 1]);
+
+    // a cut sequence, a stray continuation and an overlong one.
+    let bytes = b"\xe2\x82a\x80\xc0\xaf\xf0\x9f\x98\x80\x80";
+    walk::<Utf8>(bytes, &[R, 'a', R, R, R, '😀', R], &[2, 1, 1, 1, 1, 4, 1]);
+}
+
+#[test]
+fn jump_works() {
+    let units: Vec<u16> = STRING.encode_utf16().collect();
+    let chars: Vec<_> = STRING.chars().collect();
+    let mut cursor = TextCursor::<Utf16>::new(&units);
+
+    assert_eq!(cursor.jump(6), Some('\n'));
+    assert_eq!(cursor.pos_as_units(), 7);
+    assert_eq!(cursor.jump(2), Some('\n'));
+    assert_eq!(cursor.jump_to_last(), '🇷');
+    assert_eq!(cursor.jump(100), None);
+    assert_eq!(cursor.pos(), chars.len() - 1);
+    assert_eq!(cursor.jump_to_first(), 'a');
+    assert_eq!(cursor.jump_to_offset(5), Some('😀'));
+
+    // no moves.
+    cursor.head_to_left();
+    assert_eq!(cursor.peek(), Some('안'));
+    assert_eq!(cursor.peek_back_nth(1), Some('z'));
+    assert_eq!(cursor.current(), '😀');
+
+    cursor.save();
+    cursor.jump(1);
+    assert_eq!(cursor.as_units_loaded(), &units[1..7]);
+    assert_eq!(cursor.span_since_save(), Span::new(1..6, 1..7));
+    cursor.load();
+    assert_eq!(cursor.pos(), 5);
+
+    assert_eq!(
+        cursor.attempt(|cursor| {
+            cursor.next();
+            cursor.next();
+            None::<()>
+        }),
+        None
+    );
+    assert_eq!((cursor.pos(), cursor.pos_as_units()), (5, 5));
+
+    // over a `Cursor` of units.
+    let mut inner = Cursor::new(&units);
+    inner.jump(5);
+    assert_eq!(Utf16::next_char(&mut inner), Some('😀'));
+    assert_eq!(inner.pos(), 6);
+    assert_eq!(Utf16::next_back_char(&mut inner), Some('😀'));
+    assert_eq!(inner.pos(), 5);
+}
+
+#[test]
+fn extras_works() {
+    let units: Vec<u16> = STRING.encode_utf16().collect();
+    let mut cursor = TextCursor::<Utf16>::new_with_extras::<LineColExtras>(&units);
+    let mut lines = StrCursor::new_with_extras::<LineColExtras>(STRING);
+    for pos in [7, 2, 8, 0, 9, 4] {
+        cursor.jump(pos);
+        lines.jump(pos);
+        assert_eq!(cursor.extras().line_col(), lines.extras().line_col());
+    }
+    while let Some(ch) = cursor.prev() {
+        assert_eq!(lines.prev(), Some(ch));
+        assert_eq!(cursor.extras().line_col(), lines.extras().line_col());
+    }
+}
+
+#[test]
+fn pattern_works() {
+    let units: Vec<u16> = "let x1 = 42;  // 😀 done".encode_utf16().collect();
+    let mut cursor = TextCursor::<Utf16>::new(&units);
+
+    assert!(cursor.eat("let"));
+    assert_eq!(cursor.skip_matching(' '), 1);
+    assert!(cursor.eat('a'..='z'));
+    assert!(cursor.starts_with(&['1', ' ']));
+    assert!(cursor.starts_with_str("1 = "));
+    assert_eq!(cursor.peek_array(), Some(['1', ' ', '=']));
+    assert_eq!(cursor.expect('_'), Err(CursorError::Unexpected { pos: 5 }));
+    // stops before the match.
+    assert!(cursor.skip_until(ByteSet::new(b"0123456789").with(b';')));
+    assert_eq!(cursor.current(), 'x');
+    assert!(cursor.advance_past('='));
+    assert!(cursor.skip_until(|c: char| c.is_ascii_digit()));
+    assert_eq!(cursor.skip_matching(|c: char| c.is_ascii_digit()), 2);
+    assert_eq!(cursor.current(), '2');
+    assert!(cursor.advance_past("//"));
+    assert_eq!(cursor.at_offset(2), Some('😀'));
+
+    assert_eq!(cursor.find_next("done"), Some(19));
+    assert_eq!(cursor.find_prev("x1"), Some(4));
+    assert_eq!(cursor.find_next("let"), None);
+    assert_eq!(cursor.jump_to_next("done"), Some('d'));
+    assert_eq!(cursor.jump_to_prev("😀"), Some('😀'));
+    assert_eq!(cursor.units_span(&cursor.span_of_current()), &units[17..19]);
+
+    // heading left.
+    cursor.head_to_left();
+    assert!(cursor.eat(' '));
+    assert!(cursor.eat("//"));
+    assert_eq!(cursor.current(), '/');
+    assert_eq!(cursor.find_next("x1"), Some(4));
+    assert!(cursor.starts_with_str("  ;"));
+
+    let mut empty = TextCursor::<Utf16>::new(&[]);
+    assert_eq!(empty.expect('a'), Err(CursorError::EmptyInput));
+    assert_eq!(empty.skip_matching(' '), 0);
+}
+
+#[test]
+fn try_works() {
+    let bytes = utf16_le("a😀b");
+    let mut cursor = TextCursor::<Utf16Le>::new(&bytes);
+
+    assert_eq!(
+        cursor.try_jump(3),
+        Err(CursorError::OutOfBounds {
+            requested: 3,
+            len: 3
+        })
+    );
+    assert_eq!(cursor.try_jump_cycle(-1), Ok('b'));
+    assert_eq!(cursor.try_jump_to_offset_cycle(1), Ok('a'));
+    assert_eq!(cursor.try_next_to_pos(2), Ok('b'));
+    assert_eq!(cursor.try_next(), Err(CursorError::EndOfInput));
+    assert_eq!(cursor.next_cycle(), 'b');
+    assert_eq!(cursor.next_cycle(), '😀');
+    assert_eq!(cursor.try_next_to_offset(-1), Ok('a'));
+    assert_eq!(cursor.try_jump_to_last(), Ok('b'));
+    assert_eq!(cursor.next_to_first(), 'a');
+
+    assert_eq!(
+        cursor.try_attempt(|cursor| {
+            cursor.try_next_to_right()?;
+            cursor.try_jump(7)
+        }),
+        Err(CursorError::OutOfBounds {
+            requested: 7,
+            len: 3
+        })
+    );
+    assert_eq!((cursor.pos(), cursor.backwards()), (0, true));
+
+    let mut empty = TextCursor::<Utf16Le>::new(&[]);
+    assert_eq!(empty.try_next(), Err(CursorError::EmptyInput));
+    assert_eq!(empty.try_jump_cycle(0), Err(CursorError::EmptyInput));
+    assert_eq!(empty.next_cycle(), EOF_CHAR);
+}
+
+#[test]
+fn regex_works() {
+    let units: Vec<u16> = "id: 😀x_42 end".encode_utf16().collect();
+    let mut cursor = TextCursor::<Utf16>::new_with_extras::<LineColExtras>(&units);
+    let ident = regexp::Regex::new(r"\b\w+\b").unwrap();
+
+    assert_eq!(cursor.match_regex(&ident), Some(Text::new(&units[..2])));
+    assert_eq!(cursor.current(), 'd');
+    cursor.jump(5);
+    assert_eq!(cursor.match_regex(&ident), Some(Text::new(&units[6..10])));
+    assert_eq!((cursor.pos(), cursor.extras().line_col()), (8, (0, 8)));
+    // `\b` sees the char before.
+    cursor.jump(6);
+    assert_eq!(cursor.match_regex(&ident), None);
+    assert_eq!(cursor.pos(), 6);
+    // heading left, the match ends with the current char.
+    cursor.jump(8);
+    cursor.turnaround();
+    assert_eq!(cursor.match_regex(&ident), Some(Text::new(&units[6..10])));
+    assert_eq!(cursor.current(), 'x');
+    assert!(cursor.backwards());
+}
+
+#[test]
+fn reset_works() {
+    let units: Vec<u16> = "a\nb".encode_utf16().collect();
+    let mut cursor = TextCursor::<Utf16>::new_with_extras::<Vec<LineColExtras>>(&units);
+    cursor.extras_mut().push(LineColExtras::default());
+    cursor.next_to_last();
+    assert_eq!(cursor.extras()[0].line_col(), (1, 0));
+    cursor.reset();
+    assert_eq!(cursor.extras().len(), 1);
+    assert_eq!(cursor.extras()[0].line_col(), (0, 0));
+    assert_eq!((cursor.is_init(), cursor.current()), (false, EOF_CHAR));
+    assert_eq!(cursor.next(), Some('a'));
+}
+
+/// the same calls through `TextCursorTrait` on a `StrCursor` and a `TextCursor<Utf8>`.
+#[test]
+fn str_cursor_agrees() {
+    let string = "ab\r\n안녕 하세요\n😀x🇰🇷 ok é\u{301} 끝";
+    let mut text = TextCursor::<Utf8>::new_with_extras::<LineColExtras>(string.as_bytes());
+    let mut str = StrCursor::new_with_extras::<LineColExtras>(string);
+    let len = string.chars().count();
+    let ident = regexp::Regex::new(r"\w+").unwrap();
+
+    let mut rng = Rng::new(0x6b8b_4567);
+    for i in 0..2000 {
+        let n = rng.below(len + 2);
+        let (a, b) = match rng.below(12) {
+            0 => (text.next(), str.next()),
+            1 => (text.prev(), str.prev()),
+            2 => (text.jump(n), str.jump(n)),
+            3 => {
+                text.turnaround();
+                str.turnaround();
+                (text.peek(), str.peek())
+            }
+            4 => (text.peek_back_nth(n % 3), str.peek_back_nth(n % 3)),
+            5 => (text.at_offset(2), str.at_offset(2)),
+            6 => (text.jump_to_next("하"), str.jump_to_next("하")),
+            7 => (text.jump_to_prev("\n"), str.jump_to_prev("\n")),
+            8 => {
+                let skipped = (text.skip_matching(' '), str.skip_matching(' '));
+                assert_eq!(skipped.0, skipped.1);
+                (text.next_to_offset(-1), str.next_to_offset(-1))
+            }
+            9 => {
+                let matched = (text.match_regex(&ident), str.match_regex(&ident));
+                assert_eq!(matched.0.map(Text::as_units), matched.1.map(str::as_bytes));
+                (Some(text.current()), Some(str.current()))
+            }
+            10 => (
+                text.try_jump_to_offset(-2).ok(),
+                str.try_jump_to_offset(-2).ok(),
+            ),
+            _ => (Some(text.next_cycle()), Some(str.next_cycle())),
+        };
+        assert_eq!(a, b, "{}", i);
+        assert_eq!(text.pos(), str.pos(), "{}", i);
+        assert_eq!(text.backwards(), str.backwards());
+        assert_eq!(text.span_of_current(), str.span_of_current(), "{}", i);
+        assert_eq!(text.as_remaining_units(), str.as_remaining_units());
+        assert_eq!(text.find_next("o"), str.find_next("o"));
+        assert_eq!(text.extras().line_col(), str.extras().line_col(), "{}", i);
+    }
+}
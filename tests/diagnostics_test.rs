@@ -0,0 +1,186 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::{diagnostics::*, *};
+
+const SOURCE: &str = "fn main() {\n\tlet 안녕 = foo(1, 2);\n    bar\n}\n";
+
+/// the span of the first `s` in `SOURCE`.
+fn span_of(s: &str) -> Span {
+    let byte = SOURCE.find(s).unwrap();
+    let pos = SOURCE[..byte].chars().count();
+    Span::new(pos..pos + s.chars().count(), byte..byte + s.len())
+}
+
+#[test]
+fn it_works() {
+    let rendered = Diagnostic::error("mismatched types")
+        .with_origin("src/main.rs")
+        .with_label(span_of("foo(1, 2)"), "expected `u32`")
+        .with_secondary_label(span_of("안녕"), "declared here")
+        .with_note("a note")
+        .render(SOURCE);
+    // the tab is 4 columns, `안녕` is 4 columns.
+    assert_eq!(
+        rendered,
+        "\
+error: mismatched types
+ --> src/main.rs:2:11
+  |
+2 |     let 안녕 = foo(1, 2);
+  |         ----   ^^^^^^^^^ expected `u32`
+  |         |
+  |         declared here
+  |
+  = note: a note
+"
+    );
+
+    let rendered = Renderer::plain().with_tab_width(2).render(
+        &Diagnostic::warning("unused").with_label(span_of("="), ""),
+        SOURCE,
+    );
+    assert_eq!(
+        rendered,
+        "\
+warning: unused
+ --> 2:9
+  |
+2 |   let 안녕 = foo(1, 2);
+  |            ^
+"
+    );
+}
+
+#[test]
+fn overlapping_works() {
+    let rendered = Diagnostic::error("bad call")
+        .with_label(span_of("foo(1, 2)"), "this call")
+        .with_secondary_label(span_of("1"), "this argument")
+        .render(SOURCE);
+    assert_eq!(
+        rendered,
+        "\
+error: bad call
+ --> 2:11
+  |
+2 |     let 안녕 = foo(1, 2);
+  |                ^^^^^^^^^
+  |                |   |
+  |                |   this argument
+  |                this call
+"
+    );
+}
+
+#[test]
+fn multiline_works() {
+    let body = span_of("{\n\tlet 안녕 = foo(1, 2);\n    bar\n}");
+    let rendered = Diagnostic::warning("unclosed")
+        .with_origin("a.rs")
+        .with_label(body, "this block")
+        .with_secondary_label(span_of("bar"), "here")
+        .render(SOURCE);
+    assert_eq!(
+        rendered,
+        "\
+warning: unclosed
+ --> a.rs:1:11
+  |
+1 |   fn main() {
+  |  ___________^
+2 | |     let 안녕 = foo(1, 2);
+3 | |     bar
+  | |     --- here
+4 | | }
+  | |_^ this block
+"
+    );
+
+    // long ones are cut.
+    let source = "a {\n1\n2\n3\n4\n5\n6\n} b\n";
+    let (start, end) = (source.find('{').unwrap(), source.find('}').unwrap());
+    let rendered = Diagnostic::error("long")
+        .with_label(Span::new(0..0, start..end + 1), "body")
+        .render(source);
+    assert_eq!(
+        rendered,
+        "\
+error: long
+ --> 1:3
+  |
+1 |   a {
+  |  ___^
+2 | | 1
+...
+7 | | 6
+8 | | } b
+  | |_^ body
+"
+    );
+}
+
+#[test]
+fn width_works() {
+    assert_eq!(char_width('a'), 1);
+    assert_eq!(char_width('안'), 2);
+    assert_eq!(char_width('中'), 2);
+    assert_eq!(char_width('ｱ'), 1);
+    assert_eq!(char_width('Ａ'), 2);
+    assert_eq!(char_width('\u{301}'), 0);
+    assert_eq!(char_width('\u{7}'), 0);
+    assert_eq!(str_width("e\u{301}안녕🦀"), 7);
+    // soft hyphen, Devanagari and Bengali signs, Hangul vowel jamo.
+    for c in [
+        '\u{ad}', '\u{951}', '\u{963}', '\u{981}', '\u{1161}', '\u{200d}',
+    ] {
+        assert_eq!(char_width(c), 0, "{:?}", c);
+    }
+    // Khitan, Kana extended, emoji presentation, the Hangul filler, unassigned CJK.
+    for c in [
+        '\u{18b00}',
+        '\u{1aff0}',
+        '\u{1f6dc}',
+        '\u{115f}',
+        '\u{2fffd}',
+    ] {
+        assert_eq!(char_width(c), 2, "{:?}", c);
+    }
+    assert_eq!(str_width("\u{1100}\u{1161}\u{11a8}"), 2);
+}
+
+#[test]
+fn mid_char_span_works() {
+    // a span ending inside `안` covers it.
+    let rendered = Diagnostic::error("x")
+        .with_label(Span::new(1..2, 1..2), "here")
+        .render("\t안녕 x");
+    assert_eq!(
+        rendered,
+        "\
+error: x
+ --> 1:2
+  |
+1 |     안녕 x
+  |     ^^ here
+"
+    );
+}
+
+#[test]
+fn ansi_works() {
+    let diagnostic = Diagnostic::error("x").with_label(Span::new(0..1, 0..1), "here");
+    let rendered = diagnostic.render_ansi("ab");
+    assert!(rendered.starts_with("\x1b[1;31merror\x1b[0m"));
+    assert!(rendered.contains("\x1b[1;31m^\x1b[0m \x1b[1;31mhere\x1b[0m"));
+    // same text without the escapes.
+    let mut plain = String::new();
+    let mut chars = rendered.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            plain.push(c);
+        }
+    }
+    assert_eq!(plain, diagnostic.render("ab"));
+}
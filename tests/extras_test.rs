@@ -0,0 +1,114 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+/// number of `change`s.
+#[derive(Debug, PartialEq, Eq)]
+struct Seen(usize);
+impl<T> Extras<T> for Seen {
+    fn new() -> Self {
+        Seen(0)
+    }
+    fn clone(&self) -> Self {
+        Seen(self.0)
+    }
+    fn change(&mut self, _: &T, _: usize) {
+        self.0 += 1;
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+/// nesting depth of `(`.
+#[derive(Debug, PartialEq, Eq)]
+struct Depth(isize);
+impl Extras<char> for Depth {
+    fn new() -> Self {
+        Depth(0)
+    }
+    fn clone(&self) -> Self {
+        Depth(self.0)
+    }
+    fn change(&mut self, input: &char, _: usize) {
+        match input {
+            '(' => self.0 += 1,
+            ')' => self.0 -= 1,
+            _ => {}
+        }
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+}
+
+fn stepwise<T, E: Extras<T>>() -> bool {
+    E::STEPWISE
+}
+
+#[test]
+fn it_works() {
+    assert!(!stepwise::<char, (Seen, Depth)>());
+    assert!(stepwise::<char, (Seen, LineColExtras)>());
+    assert!(stepwise::<
+        char,
+        (Seen, Seen, Seen, Seen, Seen, Seen, Seen, LineColExtras),
+    >());
+    assert!(stepwise::<char, Option<LineColExtras>>());
+    assert!(!stepwise::<u8, [Seen; 3]>());
+
+    let mut cursor = StrCursor::new_with_extras::<(Seen, Depth)>("f(g(x))");
+    cursor.next_to_until(|c| c == 'x');
+    assert_eq!(cursor.extras().0, Seen(5));
+    assert_eq!(cursor.extras().1, Depth(2));
+    cursor.save();
+    cursor.next_to_last();
+    let range = cursor
+        .to_range_extras()
+        .map_extras(|(seen, depth)| (seen.0, depth.0));
+    assert_eq!(range, (5, 2)..(7, 0));
+
+    let (seen, depth) = cursor.into_extras();
+    assert_eq!((seen, depth), (Seen(7), Depth(0)));
+
+    // a stepwise member makes the others see the items on the way too.
+    let mut cursor = StrCursor::new_with_extras::<(Seen, LineColExtras)>("ab\ncd");
+    cursor.jump(4);
+    assert_eq!(cursor.extras().0, Seen(5));
+    assert_eq!(cursor.extras().1.line_col(), (1, 1));
+    let span = Span::from(
+        cursor
+            .to_range_extras()
+            .map_extras(|(_, line_col)| line_col),
+    );
+    assert_eq!(span, Span::new(0..5, 0..5));
+    cursor.reset();
+    assert_eq!(cursor.extras().0, Seen(0));
+}
+
+#[test]
+fn option_works() {
+    let mut cursor = Cursor::new_with_extras::<(Option<Seen>, Seen)>(&[1u8, 2, 3, 4]);
+    cursor.next();
+    *cursor.extras_mut() = (None, Seen(1));
+    cursor.next_to_last();
+    assert_eq!(cursor.extras(), &(None, Seen(4)));
+    cursor.reset();
+    assert_eq!(cursor.extras(), &(None, Seen(0)));
+}
+
+#[test]
+fn array_works() {
+    let mut cursor = StrCursor::new_with_extras::<[Seen; 3]>("abc");
+    cursor.next_to_last();
+    assert_eq!(cursor.extras(), &[Seen(3), Seen(3), Seen(3)]);
+    Extras::<char>::reset(&mut cursor.extras_mut()[1]);
+    let [first, second, _] = cursor.into_extras();
+    assert_eq!((first, second), (Seen(3), Seen(0)));
+
+    let mut cursor = StrCursor::new_with_extras::<Vec<Depth>>("((");
+    cursor.extras_mut().extend([Depth(0), Depth(10)]);
+    cursor.next_to_last();
+    assert_eq!(cursor.extras(), &[Depth(2), Depth(12)]);
+    assert_eq!(cursor.to_extras(), [Depth(2), Depth(12)]);
+}
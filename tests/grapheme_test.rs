@@ -0,0 +1,133 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+/// (text, clusters)
+const CASES: &[(&str, &[&str])] = &[
+    ("", &[]),
+    ("abc", &["a", "b", "c"]),
+    ("e\u{301}x", &["e\u{301}", "x"]),
+    ("\r\n\n\r", &["\r\n", "\n", "\r"]),
+    // L V T jamos, then a syllable with a T.
+    (
+        "\u{1100}\u{1161}\u{11a8}각",
+        &["\u{1100}\u{1161}\u{11a8}", "각"],
+    ),
+    ("🇰🇷🇯🇵🇺", &["🇰🇷", "🇯🇵", "🇺"]),
+    ("👨‍👩‍👧!", &["👨‍👩‍👧", "!"]),
+    ("👍🏽👍", &["👍🏽", "👍"]),
+    // no pictograph before the ZWJ.
+    ("a\u{200d}👍", &["a\u{200d}", "👍"]),
+    // prepend, spacing mark.
+    ("\u{600}a\u{903}", &["\u{600}a\u{903}"]),
+    // conjuncts.
+    ("क्षि", &["क्षि"]),
+    ("क\u{94d}\u{200d}ष", &["क\u{94d}\u{200d}ष"]),
+    ("क\u{93c}ष", &["क\u{93c}", "ष"]),
+    ("\u{7}\u{301}", &["\u{7}", "\u{301}"]),
+];
+
+#[test]
+fn it_works() {
+    for &(text, clusters) in CASES {
+        let forwards: Vec<_> = grapheme::graphemes(text).collect();
+        assert_eq!(forwards, clusters, "{:?}", text);
+        let mut backwards: Vec<_> = grapheme::graphemes(text).rev().collect();
+        backwards.reverse();
+        assert_eq!(backwards, clusters, "{:?}", text);
+    }
+    assert!(grapheme::is_boundary("e\u{301}", 0));
+    assert!(!grapheme::is_boundary("e\u{301}", 1));
+    assert!(!grapheme::is_boundary("e\u{301}", 2));
+    assert!(grapheme::is_boundary("e\u{301}", 3));
+    assert_eq!(grapheme::next_boundary("🇰🇷🇯🇵", 0), 8);
+    assert_eq!(grapheme::prev_boundary("🇰🇷🇯🇵", 16), 8);
+}
+
+#[test]
+fn consistency_works() {
+    const POOL: &[char] = &[
+        'a', '\r', '\n', '\u{7}', '\u{301}', '\u{200c}', '\u{200d}', '\u{903}', '\u{600}',
+        '\u{1100}', '\u{1161}', '\u{11a8}', '가', '각', '🇰', '🇷', '👍', '🏽', 'क', '\u{94d}',
+    ];
+    let mut rng = Rng::new(0x2545_f491);
+    for _ in 0..2000 {
+        let mut text = String::new();
+        for _ in 0..rng.below(9) {
+            text.push(POOL[rng.below(POOL.len())]);
+        }
+        let forwards: Vec<_> = grapheme::graphemes(&text).collect();
+        let mut backwards: Vec<_> = grapheme::graphemes(&text).rev().collect();
+        backwards.reverse();
+        assert_eq!(forwards, backwards, "{:?}", text);
+        assert_eq!(forwards.concat(), text);
+    }
+}
+
+#[test]
+fn cursor_works() {
+    let text = "e\u{301}🇰🇷\r\nक्षि!";
+    let mut cursor = GraphemeCursor::new(text);
+    assert_eq!(cursor.current(), "");
+    let mut seen = Vec::new();
+    while let Some(cluster) = cursor.next() {
+        seen.push((
+            cluster,
+            cursor.pos(),
+            cursor.char_pos(),
+            cursor.pos_as_bytes(),
+        ));
+    }
+    assert_eq!(
+        seen,
+        [
+            ("e\u{301}", 0, 0, 0),
+            ("🇰🇷", 1, 2, 3),
+            ("\r\n", 2, 4, 11),
+            ("क्षि", 3, 6, 13),
+            ("!", 4, 10, 25),
+        ]
+    );
+    assert_eq!(cursor.len(), 5);
+
+    // all the way back.
+    cursor.turnaround();
+    let mut back = Vec::new();
+    while let Some(cluster) = cursor.next() {
+        back.push((
+            cluster,
+            cursor.pos(),
+            cursor.char_pos(),
+            cursor.pos_as_bytes(),
+        ));
+    }
+    seen.pop();
+    seen.reverse();
+    assert_eq!(back, seen);
+
+    assert_eq!(cursor.jump(3), Some("क्षि"));
+    assert_eq!(cursor.span_of_current(), Span::new(6..10, 13..25));
+    assert_eq!(cursor.as_preserved_str(), "e\u{301}🇰🇷\r\n");
+    assert_eq!(cursor.as_remaining_str(), "!");
+    assert_eq!(cursor.jump(9), None);
+    assert_eq!(cursor.pos(), 3);
+    assert_eq!(cursor.jump_to_char(3), Some("🇰🇷"));
+    assert_eq!(cursor.jump_to_last(), Some("!"));
+
+    cursor.jump(1);
+    cursor.save();
+    cursor.jump_to_first();
+    cursor.load();
+    assert_eq!(cursor.current(), "🇰🇷");
+
+    // from and to a StrCursor.
+    let mut chars = cursor.to_str_cursor();
+    assert_eq!((chars.pos(), chars.current()), (2, '🇰'));
+    chars.next();
+    let mut clusters = chars.graphemes();
+    assert_eq!((clusters.pos(), clusters.current()), (1, "🇰🇷"));
+    assert_eq!(clusters.next(), Some("\r\n"));
+}
@@ -0,0 +1,205 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::{lexer::*, regexp::Regex, *};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Kind {
+    Print,
+    Ident,
+    Number,
+    String,
+    Plus,
+    PlusPlus,
+    Semicolon,
+    Newline,
+    Error,
+}
+
+#[derive(Debug, Default)]
+struct LineCounter(pub usize);
+
+impl Extras<char> for LineCounter {
+    fn new() -> Self {
+        LineCounter::default()
+    }
+    fn clone(&self) -> Self {
+        LineCounter(self.0)
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+    fn change(&mut self, input: &char, _pos: usize) {
+        if *input == '\n' {
+            self.0 += 1;
+        }
+    }
+}
+
+fn string(rest: &str) -> Option<usize> {
+    let body = rest.strip_prefix('"')?;
+    Some(body.find('"')? + 2)
+}
+
+fn kinds<K: Clone, E: Extras<char>>(lexer: Lexer<'_, K, E>) -> Vec<(K, &str)> {
+    lexer.map(|t| (t.kind, t.text)).collect()
+}
+
+#[test]
+fn it_works() {
+    let source = "print \"hi\" + 12;\nprintx ++ 3";
+    let lexer = Lexer::new(source, Kind::Error)
+        .literal(Kind::Print, "print")
+        .literal(Kind::Plus, "+")
+        .literal(Kind::PlusPlus, "++")
+        .literal(Kind::Semicolon, ";")
+        .predicate(Kind::Ident, |c| c.is_ascii_alphanumeric())
+        .regex(Kind::Number, Regex::new(r"\d+").unwrap())
+        .priority(2)
+        .custom(Kind::String, string)
+        .skip_whitespace();
+
+    assert_eq!(
+        kinds(lexer),
+        [
+            (Kind::Print, "print"),
+            (Kind::String, "\"hi\""),
+            (Kind::Plus, "+"),
+            // same length as the ident, but a higher priority.
+            (Kind::Number, "12"),
+            (Kind::Semicolon, ";"),
+            // longer than the literal.
+            (Kind::Ident, "printx"),
+            (Kind::PlusPlus, "++"),
+            (Kind::Number, "3"),
+        ]
+    );
+}
+
+#[test]
+fn spans_works() {
+    let source = "안녕 12";
+    let tokens: Vec<_> = Lexer::new(source, Kind::Error)
+        .predicate(Kind::Ident, char::is_alphabetic)
+        .pattern(Kind::Number, '0'..='9')
+        .skip_whitespace()
+        .collect();
+
+    assert_eq!(
+        tokens,
+        [
+            Token {
+                kind: Kind::Ident,
+                span: Span::new(0..2, 0..6),
+                text: "안녕"
+            },
+            Token {
+                kind: Kind::Number,
+                span: Span::new(3..4, 7..8),
+                text: "1"
+            },
+            Token {
+                kind: Kind::Number,
+                span: Span::new(4..5, 8..9),
+                text: "2"
+            },
+        ]
+    );
+    for token in tokens {
+        assert_eq!(token.span.slice(source), token.text);
+    }
+}
+
+#[test]
+fn trivia_works() {
+    let source = "a // one\n/* two */ b\n";
+    let mut lexer = Lexer::new_with_extras::<LineCounter>(source, Kind::Error)
+        .predicate(Kind::Ident, char::is_alphabetic)
+        .literal(Kind::Newline, "\n")
+        .trivia(|c| c == ' ')
+        .trivia_custom(|rest| Some(rest.strip_prefix("//")?.find('\n')? + 2))
+        .trivia_custom(|rest| Some(rest.strip_prefix("/*")?.find("*/")? + 4));
+
+    assert_eq!(lexer.next().map(|t| t.kind), Some(Kind::Ident));
+    assert_eq!(lexer.next().map(|t| t.kind), Some(Kind::Newline));
+    assert_eq!(lexer.extras().0, 1);
+    assert_eq!(lexer.rest(), "/* two */ b\n");
+    assert_eq!(lexer.next().map(|t| t.text), Some("b"));
+    assert_eq!(lexer.next().map(|t| t.kind), Some(Kind::Newline));
+    assert_eq!(lexer.next(), None);
+    assert_eq!(lexer.extras().0, 2);
+}
+
+#[test]
+fn error_works() {
+    let lexer = Lexer::new("ab @#$ cd ~", Kind::Error)
+        .predicate(Kind::Ident, char::is_alphabetic)
+        .skip_whitespace();
+    assert_eq!(
+        kinds(lexer),
+        [
+            (Kind::Ident, "ab"),
+            // up to where a rule matches again.
+            (Kind::Error, "@#$"),
+            (Kind::Ident, "cd"),
+            (Kind::Error, "~"),
+        ]
+    );
+
+    // no rules, all of it.
+    let lexer = Lexer::new("안녕", Kind::Error);
+    assert_eq!(kinds(lexer), [(Kind::Error, "안녕")]);
+
+    // from the middle.
+    let mut cursor = StrCursor::new("skip this;");
+    cursor.next_to_offset(5);
+    let lexer = Lexer::from_cursor(cursor, Kind::Error).literal(Kind::Semicolon, ";");
+    assert_eq!(
+        kinds(lexer),
+        [(Kind::Error, "this"), (Kind::Semicolon, ";")]
+    );
+}
+
+#[test]
+fn error_run_works() {
+    // each kind of rule ends the run where it first matches.
+    let lexer = Lexer::new("@@;@@12@@\"s\"@@", Kind::Error)
+        .literal(Kind::Semicolon, ";")
+        .regex(Kind::Number, Regex::new(r"\d+").unwrap())
+        .custom(Kind::String, string);
+    assert_eq!(
+        kinds(lexer),
+        [
+            (Kind::Error, "@@"),
+            (Kind::Semicolon, ";"),
+            (Kind::Error, "@@"),
+            (Kind::Number, "12"),
+            (Kind::Error, "@@"),
+            (Kind::String, "\"s\""),
+            (Kind::Error, "@@"),
+        ]
+    );
+
+    // a long run is one token.
+    let source = "@".repeat(100_000) + "x";
+    let lexer = Lexer::new(&source, Kind::Error).predicate(Kind::Ident, char::is_alphabetic);
+    assert_eq!(
+        kinds(lexer),
+        [(Kind::Error, &source[..100_000]), (Kind::Ident, "x")]
+    );
+}
+
+#[test]
+fn error_search_works() {
+    // a rule that never matches is searched once, not once per error run.
+    let calls = std::cell::Cell::new(0);
+    let source = "a@".repeat(1000);
+    let lexer = Lexer::new(&source, Kind::Error)
+        .predicate(Kind::Ident, char::is_alphabetic)
+        .custom(Kind::String, |rest| {
+            calls.set(calls.get() + 1);
+            rest.starts_with("/*").then_some(2)
+        });
+    assert_eq!(lexer.count(), 2000);
+    // once per token start, once per char of the first search.
+    assert!(calls.get() <= 2000 + 2000, "{}", calls.get());
+}
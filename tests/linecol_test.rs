@@ -0,0 +1,218 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+const STRING: &str = "ab\r\n안녕\n\nc\rd\r\r\nlast 줄";
+
+/// (line, column, byte offset, char offset) of every char.
+fn naive(string: &str) -> Vec<(usize, usize, usize, usize)> {
+    let (mut line, mut column) = (0, 0);
+    let mut out = Vec::new();
+    let chars: Vec<_> = string.char_indices().collect();
+    for (i, &(byte, ch)) in chars.iter().enumerate() {
+        out.push((line, column, byte, i));
+        let next = chars.get(i + 1).map(|&(_, c)| c);
+        if ch == '\n' || (ch == '\r' && next != Some('\n')) {
+            line += 1;
+            column = 0;
+        } else {
+            column += 1;
+        }
+    }
+    out
+}
+
+fn state<T: LineColItem>(extras: &LineColExtras<T>) -> (usize, usize, usize, usize) {
+    (
+        extras.line(),
+        extras.column(),
+        extras.byte_offset(),
+        extras.char_offset(),
+    )
+}
+
+#[test]
+fn it_works() {
+    let expected = naive(STRING);
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(STRING);
+
+    // forwards.
+    while cursor.next().is_some() {
+        assert_eq!(state(cursor.extras()), expected[cursor.pos()]);
+    }
+    assert_eq!(cursor.extras().line_col(), (6, 5));
+    // all the way back.
+    while cursor.prev().is_some() {
+        assert_eq!(state(cursor.extras()), expected[cursor.pos()]);
+    }
+    assert_eq!(state(cursor.extras()), (0, 0, 0, 0));
+}
+
+#[test]
+fn jump_works() {
+    let expected = naive(STRING);
+    let len = expected.len();
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(STRING);
+
+    cursor.jump(9);
+    assert_eq!(state(cursor.extras()), expected[9]);
+
+    let mut rng = Rng::new(0x9e37_79b9);
+    for _ in 0..300 {
+        let seed = rng.next_u32();
+        let pos = seed as usize % len;
+        match seed % 3 {
+            0 => {
+                cursor.jump(pos);
+            }
+            1 => {
+                cursor.next();
+            }
+            _ => {
+                cursor.prev();
+            }
+        }
+        assert_eq!(state(cursor.extras()), expected[cursor.pos()]);
+    }
+
+    // a checkpoint keeps its own position.
+    cursor.jump(3);
+    let checkpoint = cursor.push_checkpoint();
+    cursor.jump_to_last();
+    cursor.restore(checkpoint);
+    assert_eq!(state(cursor.extras()), expected[3]);
+    cursor.jump_to_first();
+    assert_eq!(state(cursor.extras()), expected[0]);
+}
+
+#[test]
+fn bytes_works() {
+    let expected = naive(STRING);
+    // (line, column, char offset) of every byte.
+    let bytes: Vec<_> = STRING
+        .char_indices()
+        .zip(&expected)
+        .flat_map(|((_, ch), &(line, column, _, i))| {
+            (0..ch.len_utf8()).map(move |_| (line, column, i))
+        })
+        .collect();
+    let check = |extras: &LineColExtras<u8>, pos: usize| {
+        let (line, column, char_offset) = bytes[pos];
+        assert_eq!(state(extras), (line, column, pos, char_offset));
+    };
+
+    let mut cursor = Cursor::new_with_extras::<LineColExtras<u8>>(STRING.as_bytes());
+    while cursor.next().is_some() {
+        check(cursor.extras(), cursor.pos());
+    }
+    while cursor.prev().is_some() {
+        check(cursor.extras(), cursor.pos());
+    }
+    cursor.jump(20);
+    check(cursor.extras(), 20);
+    cursor.jump(5);
+    check(cursor.extras(), 5);
+
+    // jumps straight from the start.
+    let mut cursor = Cursor::new_with_extras::<LineColExtras<u8>>(STRING.as_bytes());
+    cursor.jump(bytes.len() - 1);
+    check(cursor.extras(), bytes.len() - 1);
+
+    let mut cursor = StreamCursor::new_with_extras::<LineColExtras<u8>>(STRING.bytes(), 64);
+    cursor.jump(14).unwrap();
+    check(cursor.extras(), 14);
+    cursor.jump(2).unwrap();
+    check(cursor.extras(), 2);
+}
+
+#[test]
+fn clone_works() {
+    fn send<T: Send + Sync>(_: &T) {}
+
+    let expected = naive(STRING);
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(STRING);
+    send(&cursor);
+    cursor.jump(3);
+    let mut other = cursor.clone();
+    // each finds the lines on its own walk.
+    cursor.jump_to_last();
+    other.jump(12);
+    assert_eq!(state(other.extras()), expected[12]);
+    other.jump(1);
+    assert_eq!(state(other.extras()), expected[1]);
+    assert_eq!(state(cursor.extras()), expected[expected.len() - 1]);
+    cursor.jump(7);
+    assert_eq!(state(cursor.extras()), expected[7]);
+}
+
+#[test]
+fn noeffects_works() {
+    // the extras catch up on the moves made with `noeffects` on.
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>("ab\ncd\nef");
+    cursor.next();
+    cursor.noeffects_on();
+    for _ in 0..3 {
+        cursor.next();
+    }
+    cursor.noeffects_off();
+    cursor.next();
+    assert_eq!(state(cursor.extras()), (1, 1, 4, 4));
+    cursor.noeffects_on();
+    cursor.jump(7);
+    cursor.jump(1);
+    cursor.noeffects_off();
+    cursor.prev();
+    assert_eq!(state(cursor.extras()), (0, 0, 0, 0));
+
+    let expected = naive(STRING);
+    let mut rng = Rng::new(14);
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(STRING);
+    let units: Vec<u16> = STRING.encode_utf16().collect();
+    let mut text = TextCursor::<Utf16>::new_with_extras::<LineColExtras>(&units);
+    let mut bytes = Cursor::new_with_extras::<LineColExtras<u8>>(STRING.as_bytes());
+    for _ in 0..200 {
+        let noeffects = rng.below(2) == 0;
+        *cursor.noeffects_mut() = noeffects;
+        *text.noeffects_mut() = noeffects;
+        *bytes.noeffects_mut() = noeffects;
+        let (from, pos) = (cursor.pos(), rng.below(expected.len()));
+        if rng.below(2) == 0 {
+            cursor.jump(pos);
+            text.jump(pos);
+        } else {
+            cursor.next();
+            text.next();
+        }
+        let byte_from = bytes.pos();
+        bytes.jump(expected[pos].2);
+        // caught up on the next move they see.
+        if !noeffects && cursor.pos() != from {
+            assert_eq!(state(cursor.extras()), expected[cursor.pos()]);
+            assert_eq!(text.extras().line_col(), cursor.extras().line_col());
+        }
+        if !noeffects && bytes.pos() != byte_from {
+            assert_eq!(bytes.extras().line(), expected[pos].0);
+        }
+    }
+
+    let mut cursor = ReadStrCursor::new_with_extras::<LineColExtras>(STRING.as_bytes());
+    cursor.next();
+    cursor.save();
+    cursor.noeffects_on();
+    cursor.by_ref().take(5).count();
+    cursor.noeffects_off();
+    cursor.next();
+    assert_eq!(state(cursor.extras()), expected[6]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "missed the moves from 0 to 5")]
+fn missed_moves_panic() {
+    let mut extras = LineColExtras::<char>::new();
+    extras.change(&'a', 0);
+    extras.change(&'b', 5);
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+const STRING: &str = "ab\r\n안녕\n\nc\rd\r\r\nlast 줄";
+
+/// (byte, line, column) of every char, then of the end.
+fn naive(string: &str) -> Vec<(usize, usize, usize)> {
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(string);
+    let mut out = Vec::new();
+    while cursor.next().is_some() {
+        let extras = cursor.extras();
+        out.push((extras.byte_offset(), extras.line(), extras.column()));
+    }
+    let &(byte, line, column) = out.last().unwrap();
+    let last = string[byte..].chars().next().unwrap();
+    out.push(match last {
+        '\n' | '\r' => (string.len(), line + 1, 0),
+        _ => (string.len(), line, column + 1),
+    });
+    out
+}
+
+#[test]
+fn it_works() {
+    let index = LineIndex::new(STRING);
+    assert_eq!(index.line_count(), 7);
+    assert_eq!(index.len(), STRING.chars().count());
+
+    for (pos, &(byte, line, column)) in naive(STRING).iter().enumerate() {
+        assert_eq!(index.line_col(byte), Some((line, column)));
+        assert_eq!(index.line_col_of_char(pos), Some((line, column)));
+        assert_eq!(index.offset(line, column), Some(byte));
+        assert_eq!(index.char_pos(line, column), Some(pos));
+    }
+    // inside of `안`.
+    assert_eq!(index.line_col(5), Some((1, 0)));
+    assert_eq!(index.line_col(STRING.len() + 1), None);
+
+    let lines: Vec<_> = (0..index.line_count())
+        .map(|line| index.line_str(line).unwrap())
+        .collect();
+    assert_eq!(lines, ["ab", "안녕", "", "c", "d", "", "last 줄"]);
+    assert_eq!(index.line_range(1), Some(4..10));
+    assert_eq!(index.line_range(7), None);
+
+    // past the line ending.
+    assert_eq!(index.offset(0, 3), Some(3));
+    assert_eq!(index.offset(0, 4), None);
+    assert_eq!(index.offset(6, 6), Some(STRING.len()));
+    assert_eq!(index.offset(6, 7), None);
+    assert_eq!(index.offset(7, 0), None);
+
+    let empty = LineIndex::new("");
+    assert_eq!(empty.line_count(), 1);
+    assert_eq!(empty.line_col(0), Some((0, 0)));
+    assert_eq!(LineIndex::new("a\n").line_count(), 2);
+}
+
+#[test]
+fn cursor_works() {
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>(STRING);
+    assert_eq!(cursor.line_index().line_count(), 7);
+
+    assert_eq!(cursor.jump_to_line_col(1, 1), Some('녕'));
+    assert_eq!(cursor.extras().line_col(), (1, 1));
+    assert_eq!(cursor.jump_to_line_col(6, 5), Some('줄'));
+    assert_eq!(cursor.extras().line_col(), (6, 5));
+    // the line ending.
+    assert_eq!(cursor.jump_to_line_col(0, 2), Some('\r'));
+    assert_eq!(cursor.extras().line_col(), (0, 2));
+    assert_eq!(cursor.jump_to_line_col(4, 1), Some('\r'));
+
+    assert_eq!(cursor.jump_to_line_col(6, 6), None);
+    assert_eq!(cursor.jump_to_line_col(0, 4), None);
+    assert_eq!(cursor.jump_to_line_col(9, 0), None);
+    assert_eq!(cursor.current(), '\r');
+}
+
+#[test]
+fn cache_works() {
+    let mut cursor = StrCursor::new(STRING);
+    let plain = cursor.clone();
+    assert_eq!(cursor.line_index().line_count(), 7);
+    assert_eq!(cursor, plain);
+
+    let index = cursor.line_index().clone();
+    assert_eq!(&index, cursor.line_index());
+    assert_eq!(index.line_str(6), LineIndex::new(STRING).line_str(6));
+}
@@ -0,0 +1,142 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+fn words(text: &str, mode: WordMode) -> Vec<&str> {
+    words::segments(text, mode)
+        .map(|(_, segment)| segment)
+        .filter(|segment| words::is_word(segment, mode))
+        .collect()
+}
+
+#[test]
+fn words_works() {
+    let text = "can't stop 3.14, e.g. 안녕하세요 🇰🇷🇯🇵 foo_bar";
+    assert_eq!(
+        words(text, WordMode::Unicode),
+        ["can't", "stop", "3.14", "e.g", "안녕하세요", "foo_bar"]
+    );
+    let segments: Vec<_> = words::segments("a  b\r\n🇰🇷🇯🇵", WordMode::Unicode)
+        .map(|(_, segment)| segment)
+        .collect();
+    assert_eq!(segments, ["a", "  ", "b", "\r\n", "🇰🇷", "🇯🇵"]);
+
+    let mut back: Vec<_> = words::segments(text, WordMode::Unicode).rev().collect();
+    back.reverse();
+    assert_eq!(
+        back,
+        words::segments(text, WordMode::Unicode).collect::<Vec<_>>()
+    );
+
+    assert!(words::is_boundary(text, 5, WordMode::Unicode));
+    assert!(!words::is_boundary(text, 3, WordMode::Unicode));
+}
+
+#[test]
+fn programmer_works() {
+    let code = "parseHTTPServer_v2Api(foo.bar, __init__, 3.14)";
+    assert_eq!(
+        words(code, WordMode::Programmer),
+        ["parse", "HTTP", "Server", "v2", "Api", "foo", "bar", "init", "3.14"]
+    );
+    assert_eq!(
+        words(code, WordMode::Unicode),
+        ["parseHTTPServer_v2Api", "foo.bar", "__init__", "3.14"]
+    );
+}
+
+#[test]
+fn sentences_works() {
+    let text = "He said \"Hi!\" Then left.\n\nNew para? 3.14 is pi. (Really.) 世界。你好";
+    let sentences: Vec<_> = sentences::segments(text).map(|(_, s)| s).collect();
+    assert_eq!(
+        sentences,
+        [
+            "He said \"Hi!\" ",
+            "Then left.\n",
+            "\n",
+            "New para? ",
+            "3.14 is pi. ",
+            "(Really.) ",
+            "世界。",
+            "你好"
+        ]
+    );
+    let mut back: Vec<_> = sentences::segments(text).rev().map(|(_, s)| s).collect();
+    back.reverse();
+    assert_eq!(back, sentences);
+    // lowercase after a period doesn't start one.
+    assert_eq!(sentences::segments("e.g. this one. Yes").count(), 2);
+}
+
+#[test]
+fn cursor_works() {
+    let text = "let fooBar = 안녕(x_y);";
+    let mut cursor = StrCursor::new(text);
+
+    // from the start.
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('l'));
+    assert_eq!(cursor.pos(), 0);
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('f'));
+    assert_eq!(cursor.pos(), 4);
+    assert_eq!(cursor.next_word_start(WordMode::Programmer), Some('B'));
+    assert_eq!(cursor.next_word_end(WordMode::Unicode), Some('r'));
+    assert_eq!(cursor.pos(), 9);
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('안'));
+    assert_eq!(cursor.pos(), 13);
+    assert_eq!(
+        cursor.str_span(&cursor.select_word_at_cursor(WordMode::Unicode).unwrap()),
+        "안녕"
+    );
+
+    cursor.next_word_start(WordMode::Unicode);
+    assert_eq!(cursor.pos(), 16);
+    let span = cursor.select_word_at_cursor(WordMode::Programmer).unwrap();
+    assert_eq!(span, Span::new(16..17, 20..21));
+    let span = cursor.select_word_at_cursor(WordMode::Unicode).unwrap();
+    assert_eq!(cursor.str_span(&span), "x_y");
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), None);
+    assert_eq!(cursor.pos(), 16);
+    assert_eq!(cursor.next_word_start(WordMode::Programmer), Some('y'));
+
+    // the other way.
+    assert_eq!(cursor.prev_word_start(WordMode::Unicode), Some('x'));
+    assert_eq!(cursor.prev_word_end(WordMode::Unicode), Some('녕'));
+    cursor.turnaround();
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('안'));
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('f'));
+    assert_eq!(cursor.next_word_end(WordMode::Unicode), Some('t'));
+    assert!(cursor.backwards());
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), Some('l'));
+    assert_eq!(cursor.next_word_start(WordMode::Unicode), None);
+
+    cursor.jump(3);
+    assert_eq!(cursor.select_word_at_cursor(WordMode::Unicode), None);
+}
+
+#[test]
+fn cursor_sentences_works() {
+    let text = "  First one.  Second? Third!";
+    let mut cursor = StrCursor::new(text);
+    assert_eq!(cursor.next_sentence_start(), Some('F'));
+    assert_eq!(cursor.next_sentence_end(), Some('.'));
+    assert_eq!(cursor.pos(), 11);
+    assert_eq!(cursor.next_sentence_start(), Some('S'));
+    assert_eq!(
+        cursor.str_span(&cursor.select_sentence_at_cursor().unwrap()),
+        "Second?"
+    );
+    assert_eq!(cursor.next_sentence_end(), Some('?'));
+    assert_eq!(cursor.prev_sentence_start(), Some('S'));
+    assert_eq!(cursor.prev_sentence_end(), Some('.'));
+    assert_eq!(cursor.prev_sentence_start(), Some('F'));
+    assert_eq!(cursor.prev_sentence_start(), None);
+
+    cursor.jump(12);
+    assert_eq!(cursor.select_sentence_at_cursor(), None);
+    cursor.jump_to_last();
+    assert_eq!(
+        cursor.select_sentence_at_cursor(),
+        Some(Span::of_items(22..28))
+    );
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+use std::sync::Arc;
+use std::thread;
+
+const STRING: &str = "this is test. 안녕하세요. 이것은 #&*@( 테스트입니다. ^^ thanks!";
+
+#[derive(Debug, Default)]
+struct SpaceCounter(pub usize);
+
+impl Extras<char> for SpaceCounter {
+    fn new() -> Self {
+        SpaceCounter::default()
+    }
+    fn clone(&self) -> Self {
+        SpaceCounter(self.0)
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+    fn change(&mut self, input: &char, _pos: usize) {
+        if *input == ' ' {
+            self.0 += 1;
+        }
+    }
+}
+
+struct Loaded {
+    cursor: OwnedStrCursor,
+}
+
+fn load() -> Loaded {
+    Loaded {
+        cursor: STRING.to_string().into_str_cursor(),
+    }
+}
+
+#[test]
+fn it_works() {
+    let mut cursor = vec![1u8, 2, 3, 4, 5].into_cursor();
+    assert_eq!(cursor.cursor().next(), Some(&1));
+    assert_eq!(cursor.cursor().next(), Some(&2));
+    {
+        let mut c = cursor.cursor();
+        c.next_to_last();
+        c.turnaround();
+    }
+    assert_eq!(cursor.pos(), 4);
+    assert!(cursor.backwards());
+    assert_eq!(cursor.cursor().next(), Some(&4));
+    assert_eq!(cursor.into_source(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn sources_work() {
+    let mut boxed = OwnedCursor::new(vec![1u8, 2, 3].into_boxed_slice());
+    assert_eq!(boxed.cursor().jump_to_last(), &3);
+
+    let mut shared = Arc::<[u8]>::from(&[1u8, 2, 3][..]).into_cursor();
+    assert_eq!(shared.cursor().jump(1), Some(&2));
+
+    let mut array = [1u8, 2, 3].into_cursor();
+    assert_eq!(array.cursor().as_remaining_slice(), &[2, 3]);
+
+    let mut s = Box::<str>::from("한글").into_str_cursor();
+    assert_eq!(s.cursor().jump_to_last(), '글');
+}
+
+#[test]
+fn save_load_works() {
+    let mut loaded = load();
+    {
+        let mut c = loaded.cursor.cursor();
+        *c += 4;
+        c.save();
+        *c += 11;
+    }
+    assert_eq!(loaded.cursor.current(), '녕');
+    {
+        let mut c = loaded.cursor.cursor();
+        assert_eq!(c.as_str_loaded(), " is test. 안녕");
+        c.load();
+        assert_eq!(c.current(), ' ');
+    }
+    assert_eq!(loaded.cursor.pos(), 4);
+}
+
+#[test]
+fn send_works() {
+    let mut cursor = OwnedStrCursor::new_with_extras::<SpaceCounter>(Arc::<str>::from(STRING));
+    cursor.cursor().next_to_offset(5);
+
+    let cursor = thread::spawn(move || {
+        cursor.cursor().next_to_last();
+        cursor
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(cursor.extras().0, 8);
+    assert_eq!(cursor.into_extras().0, 8);
+}
+
+#[test]
+fn lends_keep_state() {
+    let mut owned = OwnedStrCursor::new(String::from("ab\n안녕\ncd"));
+    {
+        let mut c = owned.cursor();
+        assert_eq!(c.line_index().line_count(), 3);
+        assert_eq!(c.char_index().len(), 8);
+        c.jump(3);
+        c.save();
+    }
+    let saved = owned.saved().clone();
+    {
+        let mut c = owned.cursor();
+        assert!(c.saved() == &saved);
+        assert_eq!(c.jump_to_line_col(2, 1), Some('d'));
+        assert_eq!(c.as_str_loaded(), "안녕\ncd");
+        c.load();
+        assert_eq!(c.current(), '안');
+    }
+    let mut c = owned.cursor();
+    assert_eq!(c.current(), '안');
+    assert_eq!(c.line_index().line_str(1), Some("안녕"));
+    assert!(c.is_len());
+    assert_eq!(c.next(), Some('녕'));
+}
@@ -0,0 +1,198 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+use std::io::{self, Read};
+
+const STRING: &str = "this is test. 안녕하세요. 이것은 #&*@( 테스트입니다. ^^ thanks!";
+
+/// hands out at most `step` bytes per read.
+struct Trickle<'s> {
+    bytes: &'s [u8],
+    step: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.step.min(buf.len()).min(self.bytes.len());
+        buf[..n].copy_from_slice(&self.bytes[..n]);
+        self.bytes = &self.bytes[n..];
+        Ok(n)
+    }
+}
+
+fn trickle(s: &str, step: usize) -> Trickle<'_> {
+    Trickle {
+        bytes: s.as_bytes(),
+        step,
+    }
+}
+
+#[test]
+fn it_works() {
+    let mut cursor = ReadCursor::new(trickle(STRING, 3));
+    cursor.set_chunk_size(4);
+    let bytes = cursor.by_ref().collect::<Vec<u8>>();
+    assert_eq!(bytes, STRING.as_bytes());
+    assert!(cursor.is_eof());
+    assert!(cursor.as_buffered_bytes().len() < 8);
+}
+
+#[test]
+fn pinned_works() {
+    let mut cursor = ReadCursor::new(trickle(STRING, 2));
+    cursor.set_chunk_size(2);
+    cursor.jump(5).unwrap();
+    cursor.save();
+    cursor.jump(11).unwrap();
+    assert_eq!(cursor.as_bytes_loaded(), b"is test");
+    assert_eq!(cursor.oldest_pos(), 5);
+
+    assert_eq!(cursor.prev(), Some(b's'));
+    assert!(cursor.load().is_ok());
+    assert_eq!(cursor.current(), Some(b'i'));
+    assert!(matches!(
+        cursor.jump(2),
+        Err(ReadError::Stream(StreamError::Forgotten {
+            requested: 2,
+            oldest: 5
+        }))
+    ));
+}
+
+#[test]
+fn str_works() {
+    for step in 1..5 {
+        let mut cursor = ReadStrCursor::new(trickle(STRING, step));
+        cursor.set_chunk_size(step);
+        let s = cursor.by_ref().collect::<String>();
+        assert_eq!(s, STRING);
+        assert_eq!(cursor.current(), '!');
+    }
+}
+
+#[test]
+fn str_loaded_works() {
+    let mut cursor = ReadStrCursor::new(trickle(STRING, 1));
+    cursor.set_chunk_size(1);
+    cursor.next_to_until(|c| c == '안');
+    cursor.save();
+    cursor.next_to_until(|c| c == '것');
+    assert_eq!(cursor.as_str_loaded().unwrap(), "안녕하세요. 이것");
+
+    cursor.turnaround();
+    assert_eq!(cursor.next(), Some('이'));
+    assert_eq!(cursor.next(), Some(' '));
+    assert_eq!(cursor.next(), Some('.'));
+    assert_eq!(cursor.next(), Some('요'));
+    assert_eq!(cursor.as_str_loaded().unwrap(), "안녕하세요");
+
+    assert!(cursor.load().is_ok());
+    assert_eq!(cursor.current(), '안');
+    assert_eq!(cursor.pos(), 14);
+}
+
+#[test]
+fn invalid_utf8_works() {
+    let bytes: &[u8] = b"ab\xED\x95";
+    let mut cursor = ReadStrCursor::new(bytes);
+    assert_eq!(cursor.next(), Some('a'));
+    assert_eq!(cursor.next(), Some('b'));
+    assert!(matches!(
+        cursor.try_next(),
+        Err(ReadError::InvalidUtf8 { byte_pos: 2 })
+    ));
+    // reported once, moved onto as U+FFFD.
+    assert_eq!(cursor.current(), char::REPLACEMENT_CHARACTER);
+    assert_eq!((cursor.pos(), cursor.char_start_pos()), (2, 2));
+    assert!(matches!(
+        cursor.try_next(),
+        Err(ReadError::Stream(StreamError::EndOfInput))
+    ));
+
+    // one U+FFFD per maximal invalid subpart, either way, across refills.
+    let bytes: &[u8] = b"a\xF0\x9F\x98b\xFF\x80c\xE2\x82";
+    let lossy = String::from_utf8_lossy(bytes);
+    for chunk_size in [1, 2, 8] {
+        let mut cursor = ReadStrCursor::new(bytes);
+        cursor.set_chunk_size(chunk_size);
+        cursor.next();
+        cursor.save();
+        let mut chars = vec![cursor.current()];
+        chars.extend(cursor.by_ref());
+        assert_eq!(chars.into_iter().collect::<String>(), lossy);
+        assert!(matches!(
+            cursor.as_str_loaded(),
+            Err(ReadError::InvalidUtf8 { byte_pos: 1 })
+        ));
+
+        cursor.turnaround();
+        let mut back = vec![cursor.current()];
+        back.extend(cursor.by_ref());
+        back.reverse();
+        assert_eq!(back.into_iter().collect::<String>(), lossy);
+        assert_eq!(cursor.pos(), 0);
+    }
+
+    let mut cursor = ReadStrCursor::new(&b"\xFFa"[..]);
+    assert!(cursor.try_next().is_err());
+    cursor.save();
+    assert_eq!(cursor.next(), Some('a'));
+    cursor.save();
+    assert_eq!(cursor.as_str_loaded().unwrap(), "a");
+}
+
+#[test]
+fn long_jump_works() {
+    let mut cursor = ReadCursor::new(io::repeat(b'a').take(50_000_000));
+    cursor.next();
+    assert_eq!(cursor.jump(40_000_000).unwrap(), b'a');
+    assert!(cursor.as_buffered_bytes().len() <= READ_CHUNK_SIZE + 1);
+    assert_eq!(cursor.prev(), Some(b'a'));
+
+    // the current byte is still there, but nothing behind it.
+    assert!(matches!(
+        cursor.jump(60_000_000),
+        Err(ReadError::Stream(StreamError::EndOfInput))
+    ));
+    assert!(cursor.as_buffered_bytes().len() <= READ_CHUNK_SIZE);
+    assert_eq!((cursor.pos(), cursor.current()), (39_999_999, Some(b'a')));
+    assert!(matches!(
+        cursor.try_next(),
+        Err(ReadError::Stream(StreamError::Forgotten { .. }))
+    ));
+
+    // the pinned bytes stay.
+    let mut cursor = ReadCursor::new(io::repeat(b'a').take(1_000_000));
+    cursor.jump(10).unwrap();
+    cursor.save();
+    cursor.jump(500_000).unwrap();
+    assert_eq!(cursor.oldest_pos(), 10);
+    assert_eq!(cursor.as_bytes_loaded().len(), 499_991);
+
+    // stepwise extras see each byte before it's forgotten.
+    let text = "ab\ncd\n".repeat(1000);
+    let mut cursor = ReadCursor::new_with_extras::<LineColExtras<u8>>(trickle(&text, 3));
+    cursor.set_chunk_size(4);
+    cursor.next();
+    cursor.jump(5000).unwrap();
+    assert!(cursor.as_buffered_bytes().len() <= 5);
+    let mut expected = Cursor::new_with_extras::<LineColExtras<u8>>(text.as_bytes());
+    expected.jump(5000);
+    assert_eq!(cursor.extras().line_col(), expected.extras().line_col());
+
+    // and nothing when it runs out first.
+    let mut cursor = ReadCursor::new_with_extras::<LineColExtras<u8>>(trickle(&text, 3));
+    cursor.set_chunk_size(4);
+    cursor.jump(10).unwrap();
+    let line_col = cursor.extras().line_col();
+    assert!(cursor.jump(10_000).is_err());
+    assert_eq!(cursor.extras().line_col(), line_col);
+}
+
+#[test]
+fn str_long_walk_works() {
+    let text = "안녕 ".repeat(100_000);
+    let mut cursor = ReadStrCursor::new(text.as_bytes());
+    assert_eq!(cursor.by_ref().count(), 300_000);
+    assert!(cursor.as_buffered_bytes().len() <= READ_CHUNK_SIZE + 4);
+}
@@ -0,0 +1,236 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::regexp::*;
+use cursor::*;
+
+#[derive(Debug, Default)]
+struct CharCounter(pub usize);
+
+impl Extras<char> for CharCounter {
+    fn new() -> Self {
+        CharCounter::default()
+    }
+    fn clone(&self) -> Self {
+        CharCounter(self.0)
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+    fn change(&mut self, _input: &char, _pos: usize) {
+        self.0 += 1;
+    }
+}
+
+fn end_of(pattern: &str, text: &str) -> Option<usize> {
+    Regex::new(pattern).unwrap().match_at(text, 0)
+}
+
+#[test]
+fn it_works() {
+    let float = r"[+-]?(?:\d+\.\d*|\.\d+|\d+)(?:[eE][+-]?\d+)?";
+    assert_eq!(end_of(float, "3.14e-10 + 1"), Some(8));
+    assert_eq!(end_of(float, ".5;"), Some(2));
+    assert_eq!(end_of(float, "e10"), None);
+
+    let date = r"\d{4}-\d{2}-\d{2}\b";
+    assert_eq!(end_of(date, "2022-01-06 ..."), Some(10));
+    assert_eq!(end_of(date, "2022-01-065"), None);
+
+    let uuid = r"[0-9a-f]{8}(?:-[0-9a-f]{4}){3}-[0-9a-f]{12}";
+    assert_eq!(
+        end_of(uuid, "123e4567-e89b-12d3-a456-426614174000!"),
+        Some(36)
+    );
+
+    // leftmost-first, like Perl.
+    assert_eq!(end_of("a|ab", "ab"), Some(1));
+    assert_eq!(end_of("ab|a", "ab"), Some(2));
+    assert_eq!(end_of("a+?", "aaa"), Some(1));
+    assert_eq!(end_of("a{2,}?b", "aaab"), Some(4));
+    assert_eq!(end_of("(?:a*)*b", "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaac"), None);
+    assert_eq!(end_of("[^\\s\\]]+", "ab]c"), Some(2));
+    assert_eq!(end_of("[a-]+", "a-a-b"), Some(4));
+    assert_eq!(end_of("안녕.요", "안녕하세요"), None);
+    assert_eq!(end_of("안녕..요$", "안녕하세요"), Some(15));
+    assert_eq!(end_of("x{", "x{"), Some(2));
+
+    let re = Regex::new(r"^\w+").unwrap();
+    assert_eq!(re.match_at("ab\ncd", 3), Some(5));
+    assert_eq!(re.match_at("ab cd", 3), None);
+    assert!(Regex::new("a|b").unwrap().is_full_match("b"));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        Regex::new("(ab").unwrap_err(),
+        RegexError::Unclosed { pos: 0 }
+    );
+    assert_eq!(
+        Regex::new("a)").unwrap_err(),
+        RegexError::Unexpected { pos: 1 }
+    );
+    assert_eq!(
+        Regex::new("[a-").unwrap_err(),
+        RegexError::Unclosed { pos: 0 }
+    );
+    assert_eq!(
+        Regex::new("*a").unwrap_err(),
+        RegexError::NothingToRepeat { pos: 0 }
+    );
+    assert_eq!(
+        Regex::new("a{3,2}").unwrap_err(),
+        RegexError::BadRepetition { pos: 1 }
+    );
+    assert_eq!(
+        Regex::new(r"\q").unwrap_err(),
+        RegexError::BadEscape { pos: 0 }
+    );
+    assert_eq!(Regex::new("a\\").unwrap_err(), RegexError::UnexpectedEnd);
+}
+
+#[test]
+fn match_regex_works() {
+    let number = Regex::new(r"\d+(?:\.\d+)?").unwrap();
+    let ident = Regex::new(r"[a-z_]\w*").unwrap();
+
+    let mut cursor = StrCursor::new_with_extras::<CharCounter>("pi = 3.14;");
+    assert_eq!(cursor.match_regex(&number), None);
+    assert!(!cursor.is_init());
+    assert_eq!(cursor.match_regex(&ident), Some("pi"));
+    assert_eq!(cursor.current(), 'i');
+    assert_eq!(cursor.to_extras().0, 2);
+
+    cursor.next_to_offset(4);
+    assert_eq!(cursor.match_regex(&number), Some("3.14"));
+    assert_eq!(cursor.current(), '4');
+    assert_eq!(cursor.to_extras().0, 9);
+    assert_eq!(cursor.next(), Some(';'));
+
+    cursor.jump(0);
+    cursor.noeffects_on();
+    assert_eq!(cursor.match_regex(&ident), Some("pi"));
+    assert_eq!(cursor.pos(), 1);
+    assert_eq!(cursor.next(), Some(' '));
+
+    // `\b` sees the chars before, no match in the middle of a word.
+    let word = Regex::new(r"\b\w+").unwrap();
+    let mut cursor = StrCursor::new("let x1=y");
+    assert!(cursor.eat("let "));
+    assert_eq!(cursor.next(), Some('x'));
+    assert_eq!(cursor.match_regex(&word), Some("x1"));
+    assert_eq!(cursor.current(), '1');
+    assert!(cursor.eat('='));
+    cursor.next();
+    assert_eq!(cursor.match_regex(&word), Some("y"));
+    cursor.jump(5);
+    assert_eq!(cursor.match_regex(&word), None);
+    assert_eq!(cursor.pos(), 5);
+}
+
+#[test]
+fn match_regex_backwards_works() {
+    let number = Regex::new(r"\d+(?:\.\d+)?").unwrap();
+
+    // heading left like `eat`, the match ends with the current char.
+    let mut cursor = StrCursor::new_with_extras::<CharCounter>("x = 3.14;");
+    cursor.jump(7);
+    cursor.turnaround();
+    let seen = cursor.to_extras().0;
+    assert_eq!(cursor.match_regex(&number), Some("3.14"));
+    assert_eq!(cursor.current(), '3');
+    assert_eq!(cursor.to_extras().0 - seen, 3);
+    assert!(cursor.backwards());
+    assert_eq!(cursor.match_regex(&number), Some("3"));
+    assert_eq!(cursor.pos(), 4);
+    assert!(cursor.eat(" = "));
+    assert_eq!(cursor.match_regex(&number), None);
+    assert_eq!(cursor.pos(), 1);
+
+    // both ways after `eat`s.
+    let letters = Regex::new("[a-z]+").unwrap();
+    let mut cursor = StrCursor::new("ab12cd");
+    cursor.jump(4);
+    cursor.turnaround();
+    assert!(cursor.eat("12"));
+    assert_eq!(cursor.next(), Some('b'));
+    assert_eq!(cursor.match_regex(&letters), Some("ab"));
+    assert_eq!(cursor.pos(), 0);
+    cursor.turnaround();
+    assert_eq!(cursor.match_regex(&letters), Some("ab"));
+    assert_eq!(cursor.pos(), 1);
+
+    // the leftmost start that matches up to the current char from the left too,
+    // and `\b` sees the chars after it.
+    let text = "ab ab abc";
+    let word = Regex::new(r"\b\w+\b").unwrap();
+    assert_eq!(word.match_back_at(text, 5), Some(3));
+    assert_eq!(word.match_back_at(text, 8), None);
+    assert_eq!(Regex::new("a|ab").unwrap().match_back_at("ab", 2), None);
+    assert_eq!(Regex::new("ab|a").unwrap().match_back_at("ab", 2), Some(0));
+    assert_eq!(Regex::new("안.").unwrap().match_back_at("안녕", 6), Some(0));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_crate_works() {
+    use regex_automata::meta;
+
+    let number = meta::Regex::new(r"\d+").unwrap();
+    let mut cursor = StrCursor::new("12ab34");
+    assert_eq!(cursor.match_regex(&number), Some("12"));
+    assert_eq!(cursor.next(), Some('a'));
+    assert_eq!(cursor.match_regex(&number), None);
+    assert_eq!(cursor.next_to_pos(4), Some('3'));
+    assert_eq!(cursor.match_regex(&number), Some("34"));
+
+    // `\b` sees the char before, no match in the middle of a word.
+    let word = meta::Regex::new(r"\b\w+").unwrap();
+    let mut cursor = StrCursor::new("ab cd");
+    cursor.jump(1);
+    assert_eq!(cursor.match_regex(&word), None);
+    assert_eq!(cursor.pos(), 1);
+    assert_eq!(cursor.next_to_pos(3), Some('c'));
+    assert_eq!(cursor.match_regex(&word), Some("cd"));
+
+    // heading left, same with the built-in one.
+    let word = meta::Regex::new(r"\b\w+\b").unwrap();
+    cursor.turnaround();
+    assert_eq!(cursor.match_regex(&word), Some("cd"));
+    assert_eq!(cursor.pos(), 3);
+    cursor.jump(0);
+    assert_eq!(cursor.match_regex(&word), None);
+    assert_eq!(word.match_back_at("ab ab abc", 5), Some(3));
+    assert_eq!(word.match_back_at("ab ab abc", 8), None);
+    let a_ab = meta::Regex::new("a|ab").unwrap();
+    assert_eq!(a_ab.match_back_at("ab", 2), None);
+
+    // the first non-empty match, same with the default one.
+    let digits = meta::Regex::new(r"\d*").unwrap();
+    assert_eq!(digits.find_from("ab12", 0), Some((2, 4)));
+    let digits = Regex::new(r"\d*").unwrap();
+    assert_eq!(AnchoredMatch::find_from(&digits, "ab12", 0), Some((2, 4)));
+    assert_eq!(AnchoredMatch::find_from(&digits, "ab12", 3), Some((3, 4)));
+    assert_eq!(AnchoredMatch::find_from(&digits, "ab", 0), None);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_crate_anchored_works() {
+    // a search past `start` would make this quadratic, minutes instead of well under a second.
+    let number = regex_automata::meta::Regex::new(r"[0-9]+").unwrap();
+    let text = "abc ".repeat(100_000) + "1";
+    let started = std::time::Instant::now();
+    for start in 0..text.len() - 1 {
+        assert_eq!(number.match_at(text.as_str(), start), None);
+    }
+    assert_eq!(
+        number.match_at(text.as_str(), text.len() - 1),
+        Some(text.len())
+    );
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(10),
+        "{:?}",
+        started.elapsed()
+    );
+}
@@ -0,0 +1,57 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+fn naive_find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    (0..=haystack.len().checked_sub(needle.len())?).find(|&i| haystack[i..].starts_with(needle))
+}
+
+fn naive_rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    (0..=haystack.len().checked_sub(needle.len())?)
+        .rev()
+        .find(|&i| haystack[i..].starts_with(needle))
+}
+
+#[test]
+fn it_works() {
+    assert_eq!(search::find(b"<!-- a --> b -->", b"-->"), Some(7));
+    assert_eq!(search::rfind(b"<!-- a --> b -->", b"-->"), Some(13));
+    assert_eq!(search::find(b"abc", b""), Some(0));
+    assert_eq!(search::rfind(b"abc", b""), Some(3));
+    assert_eq!(search::find(b"ab", b"abc"), None);
+    assert_eq!(search::find(b"aaaaab", b"aab"), Some(3));
+    assert_eq!(search::rfind(b"baaaaa", b"baa"), Some(0));
+}
+
+#[test]
+fn same_as_naive() {
+    // small alphabets hit the periodic cases.
+    let mut rng = Rng::new(0x2545_f491);
+    let mut rand = move |n: u32| rng.below(n as usize) as u32;
+    for _ in 0..3000 {
+        let alphabet = 1 + rand(3) as u8;
+        let haystack = (0..rand(40))
+            .map(|_| b'a' + rand(alphabet as u32) as u8)
+            .collect::<Vec<u8>>();
+        let needle = (0..rand(6))
+            .map(|_| b'a' + rand(alphabet as u32) as u8)
+            .collect::<Vec<u8>>();
+        assert_eq!(
+            search::find(&haystack, &needle),
+            naive_find(&haystack, &needle),
+            "{:?} {:?}",
+            haystack,
+            needle
+        );
+        assert_eq!(
+            search::rfind(&haystack, &needle),
+            naive_rfind(&haystack, &needle),
+            "{:?} {:?}",
+            haystack,
+            needle
+        );
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+const STRING: &str = "let 안녕 = \"하세요\";";
+
+#[test]
+fn it_works() {
+    let a = Span::new(4..6, 4..10);
+    let b = Span::new(9..14, 13..24);
+
+    assert_eq!(a.len(), 2);
+    assert_eq!(a.len_as_bytes(), 6);
+    assert_eq!(a.slice(STRING), "안녕");
+    assert_eq!(b.slice(STRING), "\"하세요\"");
+
+    let joined = a.join(b);
+    assert_eq!(joined, Span::new(4..14, 4..24));
+    assert_eq!(joined.slice(STRING), "안녕 = \"하세요\"");
+    assert!(joined.contains(&a) && joined.contains(&b));
+    assert!(!a.contains(&joined));
+    assert!(joined.contains_pos(9) && !joined.contains_pos(14));
+
+    assert!(a.intersects(&joined));
+    assert!(!a.intersects(&b));
+    assert!(!a.intersects(&Span::new(6..9, 10..13)));
+    assert!(!Span::empty_at(5, 7).intersects(&a));
+
+    let inner = b.shrink(STRING, 1, 1).unwrap();
+    assert_eq!(inner, Span::new(10..13, 14..23));
+    assert_eq!(inner.slice(STRING), "하세요");
+    assert_eq!(inner.shrink(STRING, 2, 1).unwrap().slice(STRING), "");
+    assert_eq!(inner.shrink(STRING, 2, 2), None);
+
+    let items = Span::from(1..3);
+    assert_eq!(items.slice(&[1, 2, 3, 4][..]), [2, 3]);
+    assert_eq!(
+        items.shrink(&[1, 2, 3, 4][..], 1, 0),
+        Some(Span::from(2..3))
+    );
+}
+
+#[test]
+fn cursor_works() {
+    let mut cursor = Cursor::new(b"hello world");
+    assert_eq!(cursor.span_of_current(), Span::from(0..1));
+
+    cursor.jump(6);
+    cursor.save();
+    cursor.jump(10);
+    assert_eq!(cursor.span_of_current(), Span::from(10..11));
+    let span = cursor.span_since_save();
+    assert_eq!(cursor.slice_span(&span), b"world");
+    assert_eq!(cursor.slice_span(&span), cursor.as_slice_loaded());
+
+    // backwards too.
+    let checkpoint = cursor.push_checkpoint();
+    cursor.jump(4);
+    assert_eq!(
+        cursor.slice_span(&cursor.span_since(&checkpoint)),
+        b"o world"
+    );
+    cursor.restore(checkpoint);
+
+    assert_eq!(Cursor::new(b"").span_of_current(), Span::from(0..0));
+}
+
+#[test]
+fn str_cursor_works() {
+    let mut cursor = StrCursor::new(STRING);
+    assert_eq!(cursor.span_of_current(), Span::empty_at(0, 0));
+
+    cursor.jump(4);
+    assert_eq!(cursor.span_of_current(), Span::new(4..5, 4..7));
+    cursor.save();
+    cursor.next();
+    assert_eq!(cursor.str_span(&cursor.span_since_save()), "안녕");
+
+    cursor.jump(10);
+    cursor.save();
+    cursor.jump_to_last();
+    cursor.turnaround();
+    cursor.next();
+    cursor.next();
+    let span = cursor.span_since_save();
+    assert_eq!(span, Span::new(10..13, 14..23));
+    assert_eq!(cursor.str_span(&span), "하세요");
+    assert_eq!(cursor.str_span(&span), cursor.as_str_loaded());
+
+    let checkpoint = cursor.push_checkpoint();
+    cursor.jump(4);
+    let span = cursor.span_since(&checkpoint);
+    assert_eq!(cursor.str_span(&span), "안녕 = \"하세요");
+    assert_eq!(span.len(), 9);
+}
+
+#[test]
+fn extras_works() {
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>("a\n안녕");
+    cursor.next();
+    cursor.save();
+    cursor.jump(3);
+    let span = Span::from(cursor.to_range_extras());
+    assert_eq!(span, cursor.span_since_save());
+    assert_eq!(cursor.str_span(&span), "a\n안녕");
+
+    let mut cursor = Cursor::new_with_extras::<LineColExtras<u8>>(b"a\nbc");
+    cursor.jump(3);
+    cursor.save();
+    cursor.jump(1);
+    assert_eq!(Span::from(cursor.to_range_extras()), Span::from(1..4));
+}
@@ -220,3 +220,238 @@ fn next_to_while() {
     cursor.next_to_while(|c| c != '!');
     assert_eq!(cursor.next(), None);
 }
+
+#[test]
+fn checkpoint_works() {
+    let mut cursor = StrCursor::new(STRING);
+    cursor.next_to_until(|c| c == '안');
+    let outer = cursor.push_checkpoint();
+    cursor.next_to_until(|c| c == '.');
+
+    let inner = cursor.push_checkpoint();
+    cursor.next_to_until(|c| c == '것');
+    assert_eq!(cursor.str_since(&inner), ". 이것");
+    assert_eq!(cursor.str_since(&outer), "안녕하세요. 이것");
+
+    cursor.turnaround();
+    cursor.next();
+    assert_eq!(cursor.str_since(&inner), ". 이");
+    cursor.restore(inner);
+    assert_eq!(cursor.current(), '.');
+    assert!(!cursor.backwards());
+    assert_eq!(cursor.next(), Some(' '));
+
+    cursor.restore(outer);
+    assert_eq!(cursor.current(), '안');
+    assert_eq!(cursor.next(), Some('녕'));
+}
+
+#[test]
+fn load_restores_bytes() {
+    let mut cursor = StrCursor::new("한글테스트^^");
+    cursor.next();
+    cursor.save();
+    cursor.next_to_offset(3);
+    cursor.turnaround();
+    cursor.load();
+    assert_eq!(cursor.current(), '한');
+    assert!(!cursor.backwards());
+    assert_eq!(cursor.next(), Some('글'));
+}
+
+#[test]
+fn attempt_works() {
+    let mut cursor = StrCursor::new_with_extras::<SpaceCounter>(STRING);
+    cursor.next_to_until(|c| c == '안');
+
+    let keyword = |c: &mut StrCursor<'_, SpaceCounter>, word: &str| {
+        c.try_attempt(|c| {
+            for expected in word.chars() {
+                match c.next() {
+                    Some(ch) if ch == expected => {}
+                    _ => return Err(c.pos()),
+                }
+            }
+            Ok(())
+        })
+    };
+    assert_eq!(keyword(&mut cursor, "녕하세요. 저것"), Err(21));
+    assert_eq!(cursor.current(), '안');
+    assert_eq!(cursor.to_extras().0, 3);
+
+    assert_eq!(keyword(&mut cursor, "녕하세요. 이것"), Ok(()));
+    assert_eq!(cursor.current(), '것');
+    assert_eq!(cursor.next(), Some('은'));
+    assert_eq!(cursor.to_extras().0, 4);
+}
+
+#[test]
+fn peek_works() {
+    let mut cursor = StrCursor::new_with_extras::<SpaceCounter>(STRING);
+    assert_eq!(cursor.peek(), Some('t'));
+    assert!(cursor.starts_with_str("this is"));
+
+    cursor.next_to_until(|c| c == '안');
+    assert_eq!(cursor.peek(), Some('녕'));
+    assert_eq!(cursor.peek_nth(3), Some('요'));
+    assert_eq!(cursor.peek_back_nth(0), Some(' '));
+    assert_eq!(cursor.at_offset(-2), Some('.'));
+    assert_eq!(cursor.peek_array::<4>(), Some(['녕', '하', '세', '요']));
+    assert!(cursor.starts_with_str("녕하세요. "));
+    assert!(cursor.starts_with(&['녕', '하']));
+
+    cursor.turnaround();
+    assert_eq!(cursor.peek(), Some(' '));
+    assert_eq!(cursor.at_offset(-1), Some('녕'));
+    assert!(cursor.starts_with_str(" .tset"));
+
+    cursor.turnaround();
+    cursor.next();
+    cursor.turnaround();
+    assert_eq!(cursor.current(), '녕');
+    assert_eq!(cursor.peek(), Some('안'));
+    assert_eq!(cursor.peek_back_nth(0), Some('하'));
+
+    // no moves, no effects
+    assert_eq!(cursor.to_extras().0, 3);
+    assert_eq!(cursor.next(), Some('안'));
+
+    cursor.jump_to_last();
+    assert_eq!(cursor.peek(), Some('s'));
+    cursor.turnaround();
+    assert_eq!(cursor.peek(), None);
+    assert_eq!(cursor.peek_array::<1>(), None);
+}
+
+#[test]
+fn try_works() {
+    let mut cursor = StrCursor::new("한글테스트^^");
+    assert_eq!(
+        cursor.try_jump(7),
+        Err(CursorError::OutOfBounds {
+            requested: 7,
+            len: 7
+        })
+    );
+    assert_eq!(cursor.try_jump_to_offset(-1), Err(CursorError::Overflow));
+    assert_eq!(cursor.try_jump(3), Ok('스'));
+    assert_eq!(cursor.try_jump_to_last(), Ok('^'));
+    assert_eq!(cursor.try_next(), Err(CursorError::EndOfInput));
+    assert_eq!(cursor.try_jump_cycle(-7), Ok('한'));
+    assert_eq!(cursor.try_next_to_pos(2), Ok('테'));
+    assert_eq!(cursor.try_jump_to_offset_cycle(-3), Ok('^'));
+
+    let mut empty = StrCursor::new("");
+    assert_eq!(empty.try_next(), Err(CursorError::EmptyInput));
+    assert_eq!(empty.try_jump_cycle(3), Err(CursorError::EmptyInput));
+}
+
+#[test]
+fn jump_out_of_range() {
+    let mut cursor = StrCursor::new("abcd");
+    cursor.next();
+    assert_eq!(cursor.jump(10), None);
+    assert_eq!(cursor.current(), 'a');
+    assert_eq!(cursor.jump(3), Some('d'));
+    assert_eq!(cursor.len(), 4);
+    assert_eq!(cursor.jump(2), Some('c'));
+}
+
+#[test]
+fn failed_jump_keeps_state() {
+    let mut cursor = StrCursor::new("aa");
+    assert_eq!(cursor.jump(2), None);
+    assert!(!cursor.is_init());
+    assert_eq!(cursor.next(), Some('a'));
+    assert_eq!(cursor.next(), Some('a'));
+    assert_eq!(cursor.pos(), 1);
+    assert_eq!(cursor.jump(5), None);
+    assert_eq!(cursor.prev(), Some('a'));
+    assert_eq!((cursor.pos(), cursor.pos_as_bytes()), (0, 0));
+
+    let mut cursor = StrCursor::new_with_extras::<LineColExtras>("a\nb");
+    assert_eq!(cursor.jump(5), None);
+    assert_eq!(cursor.next(), Some('a'));
+    assert_eq!(cursor.next(), Some('\n'));
+    assert_eq!(cursor.next(), Some('b'));
+    assert_eq!((cursor.pos(), cursor.current()), (2, 'b'));
+    assert_eq!(cursor.extras().line_col(), (1, 0));
+    assert_eq!(cursor.jump(7), None);
+    assert_eq!(cursor.prev(), Some('\n'));
+    assert_eq!(cursor.extras().line_col(), (0, 1));
+
+    let mut cursor = StrCursor::new("안녕");
+    cursor.next();
+    assert_eq!(cursor.jump(2), None);
+    assert_eq!(cursor.next(), Some('녕'));
+    assert_eq!(cursor.next(), None);
+
+    let mut empty = StrCursor::new("");
+    assert_eq!(empty.jump(0), None);
+    assert_eq!(empty.jump_to_first(), EOF_CHAR);
+    assert_eq!(empty.jump_to_last(), EOF_CHAR);
+    assert_eq!(empty.next(), None);
+    assert_eq!(empty.try_jump(0), Err(CursorError::EmptyInput));
+}
+
+#[test]
+fn pattern_works() {
+    let mut cursor = StrCursor::new_with_extras::<SpaceCounter>(STRING);
+    assert!(cursor.eat("this"));
+    assert_eq!(cursor.skip_matching(&[' ', 'i', 's'][..]), 4);
+    assert_eq!(cursor.current(), ' ');
+    assert!(cursor.skip_until('가'..='힣'));
+    assert_eq!(cursor.current(), ' ');
+    assert_eq!(cursor.skip_matching('가'..='힣'), 5);
+    assert_eq!(cursor.expect('.'), Ok(()));
+    assert_eq!(
+        cursor.expect("이것"),
+        Err(CursorError::Unexpected { pos: 20 })
+    );
+
+    let mut depth = 0;
+    assert_eq!(
+        cursor.skip_matching(|_: char| {
+            depth += 1;
+            depth <= 3
+        }),
+        3
+    );
+    assert_eq!(cursor.current(), '것');
+    assert!(cursor.advance_past("( "));
+    assert_eq!(cursor.current(), ' ');
+    assert!(!cursor.eat(ByteSet::new(b"abc")));
+    assert!(!cursor.skip_until("없음"));
+    assert_eq!(cursor.to_extras().0, 6);
+
+    cursor.turnaround();
+    assert!(cursor.advance_past("#&"));
+    assert_eq!(cursor.current(), '#');
+    assert!(cursor.eat(' '));
+    assert_eq!(cursor.expect("이것은"), Ok(()));
+    assert_eq!(cursor.current(), '이');
+}
+
+#[test]
+fn find_works() {
+    let mut cursor = StrCursor::new_with_extras::<SpaceCounter>(STRING);
+    assert_eq!(cursor.find_next("."), Some(12));
+    assert_eq!(cursor.find_next("세요"), Some(17));
+    assert_eq!(cursor.jump_to_next("세요"), Some('세'));
+    assert_eq!(cursor.pos(), 17);
+    assert_eq!(cursor.to_extras().0, 3);
+    assert_eq!(cursor.find_prev("is"), Some(5));
+    assert_eq!(cursor.jump_to_next("없음"), None);
+
+    assert_eq!(cursor.jump_to_next("^^"), Some('^'));
+    assert_eq!(cursor.as_remaining_str(), "^ thanks!");
+    assert_eq!(cursor.to_extras().0, 7);
+
+    cursor.turnaround();
+    assert_eq!(cursor.jump_to_next("테스트"), Some('테'));
+    assert_eq!(cursor.current(), '테');
+    assert_eq!(cursor.next(), Some(' '));
+    assert_eq!(cursor.jump_to_prev("."), Some('.'));
+    assert!(cursor.backwards());
+    assert_eq!(cursor.as_preserved_str(), " ^^ thanks!");
+}
@@ -0,0 +1,129 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+use cursor::*;
+
+#[derive(Debug, Default)]
+struct EvenCounter(pub usize);
+
+impl Extras<u32> for EvenCounter {
+    fn new() -> Self {
+        EvenCounter::default()
+    }
+    fn clone(&self) -> Self {
+        EvenCounter(self.0)
+    }
+    fn reset(&mut self) {
+        self.0 = 0;
+    }
+    fn change(&mut self, input: &u32, _pos: usize) {
+        if input.is_multiple_of(2) {
+            self.0 += 1;
+        }
+    }
+}
+
+#[test]
+fn it_works() {
+    let mut cursor = StreamCursor::new(1..=10u32, 2);
+    assert_eq!(cursor.current(), None);
+    let mut i = 1;
+    while let Some(&n) = cursor.next() {
+        assert_eq!(n, i);
+        assert_eq!(cursor.pos(), (i - 1) as usize);
+        i += 1;
+    }
+    assert_eq!(i, 11);
+    assert_eq!(cursor.try_next(), Err(StreamError::EndOfInput));
+    assert_eq!(cursor.retained().copied().collect::<Vec<_>>(), [8, 9, 10]);
+}
+
+#[test]
+fn lookback_works() {
+    let mut cursor = StreamCursor::new("abcdefgh".chars(), 2);
+    cursor.jump(4).unwrap();
+    assert_eq!(cursor.current(), Some(&'e'));
+    assert_eq!(cursor.oldest_pos(), 2);
+
+    cursor.turnaround();
+    assert_eq!(cursor.next(), Some(&'d'));
+    assert_eq!(cursor.next(), Some(&'c'));
+    assert_eq!(
+        cursor.try_next(),
+        Err(StreamError::Forgotten {
+            requested: 1,
+            oldest: 2
+        })
+    );
+    assert_eq!(cursor.pos(), 2);
+
+    assert_eq!(cursor.prev(), Some(&'d'));
+    assert!(cursor.backwards());
+    assert_eq!(cursor.jump_to_offset(4), Ok(&'h'));
+    assert_eq!(cursor.jump_to_offset(1), Err(StreamError::EndOfInput));
+    assert_eq!(cursor.jump_to_offset(-2), Ok(&'f'));
+}
+
+#[test]
+fn save_load_works() {
+    let mut cursor = StreamCursor::new(0..100u32, 3);
+    cursor.jump(10).unwrap();
+    cursor.save();
+
+    cursor.jump(13).unwrap();
+    assert_eq!(cursor.load(), Ok(()));
+    assert_eq!(cursor.current(), Some(&10));
+
+    cursor.jump(20).unwrap();
+    assert_eq!(
+        cursor.load(),
+        Err(StreamError::Forgotten {
+            requested: 10,
+            oldest: 17
+        })
+    );
+    assert_eq!(cursor.current(), Some(&20));
+}
+
+#[test]
+fn extras_works() {
+    let mut cursor = StreamCursor::new_with_extras::<EvenCounter>(1..=10u32, 1);
+    cursor.next_to_last();
+    assert_eq!(cursor.extras().0, 5);
+
+    cursor.save();
+    cursor.turnaround();
+    cursor.next();
+    assert_eq!(cursor.to_range_extras().end.0, 5);
+    assert_eq!(cursor.next_to_while(|&n| n > 5), None);
+    assert_eq!(cursor.into_extras().0, 5);
+}
+
+#[test]
+fn long_jump_works() {
+    let mut cursor = StreamCursor::new(0..100_000u32, 3);
+    cursor.next();
+    assert_eq!(cursor.jump(50_000), Ok(&50_000));
+    assert_eq!(cursor.retained().count(), 4);
+    assert_eq!(cursor.oldest_pos(), 49_997);
+
+    assert_eq!(cursor.jump(1_000_000), Err(StreamError::EndOfInput));
+    assert_eq!(
+        cursor.retained().copied().collect::<Vec<_>>(),
+        [99_996, 99_997, 99_998, 99_999]
+    );
+    assert_eq!(cursor.pos(), 50_000);
+    assert_eq!(cursor.current(), Some(&50_000));
+    assert_eq!(
+        cursor.try_prev(),
+        Err(StreamError::Forgotten {
+            requested: 49_999,
+            oldest: 99_996
+        })
+    );
+
+    let mut cursor = StreamCursor::new_with_extras::<EvenCounter>(1..=10u32, 1);
+    cursor.next();
+    assert_eq!(cursor.jump(20), Err(StreamError::EndOfInput));
+    assert_eq!(cursor.extras().0, 0);
+    assert_eq!(cursor.current(), Some(&1));
+}
@@ -0,0 +1,96 @@
+// Copyright 2021 Hwakyeom Kim(=just-do-halee)
+
+mod common;
+
+use common::Rng;
+use cursor::*;
+
+/// random bytes leaning on the interesting ones, of every length up to 80,
+/// sliced at every start so the chunks fall everywhere.
+fn samples() -> Vec<Vec<u8>> {
+    let mut rng = Rng::new(0x6c07_8965);
+    let mut out = vec![];
+    for len in 0..80 {
+        let bytes: Vec<u8> = (0..len)
+            .map(|_| {
+                let seed = rng.next_u32();
+                match seed % 6 {
+                    0 => b'\n',
+                    1 => 0x80 | (seed >> 8) as u8 & 0x3f,
+                    2 => 0xc0 | (seed >> 8) as u8 & 0x3f,
+                    3 => (seed >> 8) as u8,
+                    _ => (seed >> 8) as u8 & 0x7f,
+                }
+            })
+            .collect();
+        out.push(bytes);
+    }
+    let text = "ascii\n안녕 하세요\n😀🇰🇷 é\u{301}\n".repeat(5);
+    out.push(text.into_bytes());
+    out.push(vec![b'a'; 100]);
+    out
+}
+
+#[test]
+fn it_works() {
+    for bytes in samples() {
+        for start in 0..bytes.len().min(17) {
+            let bytes = &bytes[start..];
+            let conts = bytes.iter().filter(|&&b| (b as i8) < -64).count();
+            assert_eq!(utf::count_cont_bytes(bytes), conts, "{:x?}", bytes);
+            assert_eq!(utf::count_chars(bytes), bytes.len() - conts);
+            let newlines = bytes.iter().filter(|&&b| b == b'\n').count();
+            assert_eq!(utf::count_newlines(bytes), newlines);
+            assert_eq!(utf::is_ascii(bytes), bytes.is_ascii());
+        }
+    }
+    // a non-ascii byte at every place.
+    for i in 0..40 {
+        let mut bytes = [b'a'; 40];
+        bytes[i] = 0xff;
+        assert!(!utf::is_ascii(&bytes));
+        assert!(utf::is_ascii(&bytes[i + 1..]));
+    }
+}
+
+#[test]
+fn nth_works() {
+    for bytes in samples() {
+        let leads: Vec<_> = (0..bytes.len())
+            .filter(|&i| (bytes[i] as i8) >= -64)
+            .collect();
+        for n in 0..=leads.len() {
+            assert_eq!(utf::nth_char_start(&bytes, n), leads.get(n).copied());
+            let back = leads.len().checked_sub(n + 1).map(|i| leads[i]);
+            assert_eq!(utf::nth_char_start_back(&bytes, n), back);
+        }
+    }
+}
+
+#[test]
+fn next_char_works() {
+    let bytes = "a안😀".as_bytes();
+    let mut cursor = Cursor::new(bytes);
+    cursor.jump(1);
+    assert_eq!(utf::next_char(&mut cursor), Some('안'));
+    assert_eq!(cursor.pos(), 3);
+    cursor.jump(7);
+    cursor.turnaround();
+    assert_eq!(utf::next_back_char(&mut cursor), Some('😀'));
+    assert_eq!((cursor.pos(), cursor.backwards()), (4, true));
+
+    // checked, no moves.
+    for bytes in [
+        &[0xf7, 0xbf, 0xbf, 0xbf][..],
+        &[0xed, 0xa0, 0x80],
+        &[0xe2, 0x82],
+    ] {
+        let mut cursor = Cursor::new(bytes);
+        cursor.jump(0);
+        assert_eq!(utf::next_char(&mut cursor), None);
+        assert_eq!(cursor.pos(), 0);
+        cursor.jump(bytes.len() - 1);
+        assert_eq!(utf::next_back_char(&mut cursor), None);
+        assert_eq!(cursor.pos(), bytes.len() - 1);
+    }
+}